@@ -22,12 +22,12 @@
 
 use algs4_rs::ResizingBag as Bag;
 use algs4_rs::StdIn;
-use std::time::Instant;
+use algs4_rs::Stopwatch;
 
 fn main() -> std::io::Result<()> {
     let mut bag = Bag::new();
     let mut stdin = StdIn::new();
-    let now = Instant::now();
+    let stopwatch = Stopwatch::new();
     while !stdin.is_empty() {
         let item = stdin.read_string()?;
         bag.add(item);
@@ -37,7 +37,7 @@ fn main() -> std::io::Result<()> {
     for s in bag.iter() {
         println!("{s}");
     }
-    println!("elapsed time = {:.3}s", now.elapsed().as_secs_f64());
+    println!("elapsed time = {:.3}s", stopwatch.elapsed_seconds());
 
     Ok(())
 }
@@ -2,10 +2,10 @@
 //! a command-line argument; reads in integers from standard input;
 //! prints to standard output those integers that do **not** appear in the file.
 
+use algs4_rs::Stopwatch;
 use algs4_rs::index_of_i32_seq;
 use algs4_rs::{FileIn, StdIn};
 use std::env;
-use std::time::Instant;
 
 fn main() -> std::io::Result<()> {
     let args: Vec<String> = env::args().collect();
@@ -17,14 +17,14 @@ fn main() -> std::io::Result<()> {
 
     let mut stdin = StdIn::new();
 
-    let now = Instant::now();
+    let stopwatch = Stopwatch::new();
     while !stdin.is_empty() {
         let key: i32 = stdin.read_int()?;
         if index_of_i32_seq(&allowlist, &key) == -1 {
             println!("{}", key);
         }
     }
-    println!("elapsed time = {:.3}s", now.elapsed().as_secs_f64());
+    println!("elapsed time = {:.3}s", stopwatch.elapsed_seconds());
 
     Ok(())
 }
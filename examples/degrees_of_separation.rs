@@ -0,0 +1,57 @@
+/*!
+ *  Data files:   https://algs4.cs.princeton.edu/41graph/routes.txt
+ *                https://algs4.cs.princeton.edu/41graph/movies.txt
+ *
+ *  Reads in a data file specifying a symbol graph, a delimiter, and the name of a source vertex.
+ *  Then reads names from standard input, one per line, and prints a shortest path from the
+ *  source to each of them by breadth-first search.
+ *
+ *  $ cargo run --example degrees_of_separation -- routes.txt " " JFK
+ *  LAS
+ *     JFK
+ *     ORD
+ *     PHX
+ *     LAS
+ *  DFW
+ *     JFK
+ *     ORD
+ *     DFW
+ *  EWR
+ *     Not in database.
+ */
+
+use algs4_rs::Algs4Error;
+use algs4_rs::{BreadthFirstPaths, FileIn, SymbolGraph};
+use std::env;
+use std::io;
+
+fn main() -> Result<(), Algs4Error> {
+    let args: Vec<String> = env::args().collect();
+    if args.len() != 4 {
+        return Err(Algs4Error::InvalidArgument(
+            "usage: degrees_of_separation <file> <delimiter> <source>".to_string(),
+        ));
+    }
+    let (file_path, delimiter, source) = (&args[1], &args[2], &args[3]);
+    let mut input = FileIn::new(file_path)?;
+    let sg = SymbolGraph::new(&mut input, delimiter)?;
+    let s = sg.index_of(source).ok_or_else(|| {
+        Algs4Error::InvalidArgument(format!("source {} is not in database", source))
+    })?;
+    let bfs = BreadthFirstPaths::new(sg.graph(), s)?;
+
+    for line in io::stdin().lines() {
+        let sink = line?;
+        println!("{}", sink);
+        match sg.index_of(&sink) {
+            Some(t) if bfs.has_path_to(t)? => {
+                for v in bfs.path_to(t)? {
+                    println!("   {}", sg.name_of(v)?);
+                }
+            }
+            Some(_) => println!("   Not connected"),
+            None => println!("   Not in database."),
+        }
+    }
+    Ok(())
+}
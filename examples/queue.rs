@@ -8,12 +8,12 @@
 
 use algs4_rs::LinkedQueue as Queue;
 use algs4_rs::StdIn;
-use std::time::Instant;
+use algs4_rs::Stopwatch;
 
 fn main() -> std::io::Result<()> {
     let mut qu = Queue::new();
     let mut stdin = StdIn::new();
-    let now = Instant::now();
+    let stopwatch = Stopwatch::new();
     while !stdin.is_empty() {
         let item = stdin.read_string()?;
         if item != "-" {
@@ -23,6 +23,6 @@ fn main() -> std::io::Result<()> {
         }
     }
     println!("({} left on queue)", qu.len());
-    println!("elapsed time = {:.3}s", now.elapsed().as_secs_f64());
+    println!("elapsed time = {:.3}s", stopwatch.elapsed_seconds());
     Ok(())
 }
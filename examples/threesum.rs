@@ -1,7 +1,7 @@
 use algs4_rs::FileIn;
+use algs4_rs::Stopwatch;
 use algs4_rs::threesum;
 use std::env;
-use std::time::Instant;
 
 fn main() -> std::io::Result<()> {
     let args: Vec<String> = env::args().collect();
@@ -9,9 +9,9 @@ fn main() -> std::io::Result<()> {
     let mut input: FileIn = FileIn::new(file_path)?;
     let a: Vec<i32> = input.read_all_ints()?;
 
-    let now = Instant::now();
+    let stopwatch = Stopwatch::new();
     let count = threesum::count(&a);
-    println!("elapsed time = {:.3}s", now.elapsed().as_secs_f64());
+    println!("elapsed time = {:.3}s", stopwatch.elapsed_seconds());
     println!("{}", count);
 
     Ok(())
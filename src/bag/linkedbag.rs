@@ -1,3 +1,5 @@
+use std::fmt;
+
 /// A bag (or multiset) of generic items.
 ///
 /// It supports insertion and iterating over the items in arbitrary order.
@@ -64,6 +66,28 @@ impl<T> LinkedBag<T> {
         self.n += 1;
     }
 
+    /// Removes one occurrence of the item from this bag.  Returns true if the item was in this bag,
+    /// returns false otherwise.
+    ///
+    /// It takes time proportional to the number of items in the worst case.
+    pub fn remove(&mut self, item: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        let mut link = &mut self.first;
+        while link.as_ref().is_some_and(|node| node.item != *item) {
+            link = &mut link.as_mut().unwrap().next;
+        }
+        match link.take() {
+            None => false,
+            Some(node) => {
+                *link = node.next;
+                self.n -= 1;
+                true
+            }
+        }
+    }
+
     /// Returns an iterator that iterates over the items in this bag in arbitrary order.
     pub fn iter(&self) -> LinkedBagIter<'_, T> {
         LinkedBagIter {
@@ -106,3 +130,61 @@ impl<'a, T> Iterator for LinkedBagIter<'a, T> {
         })
     }
 }
+
+/// Implementing `std::fmt::Display` will automatically implement the `ToString` trait for
+/// `LinkedBag<T>`, allowing the usage of the `.to_string()` method.
+impl<T: fmt::Display> fmt::Display for LinkedBag<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut s = String::new();
+        for x in self.iter() {
+            s.push_str(&x.to_string());
+            s.push(' ');
+        }
+        write!(f, "{}", s)
+    }
+}
+
+pub struct LinkedBagIntoIter<T> {
+    moved_bag: LinkedBag<T>,
+}
+
+/// Consumes the bag, yielding the items in arbitrary order.
+impl<T> IntoIterator for LinkedBag<T> {
+    type Item = T;
+    type IntoIter = LinkedBagIntoIter<T>;
+    fn into_iter(self) -> Self::IntoIter {
+        LinkedBagIntoIter { moved_bag: self }
+    }
+}
+
+impl<T> Iterator for LinkedBagIntoIter<T> {
+    type Item = T;
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = *self.moved_bag.first.take()?;
+        self.moved_bag.first = first.next;
+        self.moved_bag.n -= 1;
+        Some(first.item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.moved_bag.n, Some(self.moved_bag.n))
+    }
+}
+
+/// Adds the items to the bag.
+impl<T> FromIterator<T> for LinkedBag<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut bag = LinkedBag::new();
+        bag.extend(iter);
+        bag
+    }
+}
+
+/// Adds the items to the bag.
+impl<T> Extend<T> for LinkedBag<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.add(item);
+        }
+    }
+}
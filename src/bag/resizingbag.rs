@@ -1,4 +1,5 @@
-use crate::vec::SVec;
+use crate::vec::{SVec, SVecIntoIter};
+use std::fmt;
 
 /// A bag (or multiset) of generic items.
 ///
@@ -71,3 +72,60 @@ impl<'a, T> Iterator for ResizingBagIter<'a, T> {
         }
     }
 }
+
+/// Implementing `std::fmt::Display` will automatically implement the `ToString` trait for
+/// `ResizingBag<T>`, allowing the usage of the `.to_string()` method.
+impl<T: fmt::Display> fmt::Display for ResizingBag<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut s = String::new();
+        for x in self.iter() {
+            s.push_str(&x.to_string());
+            s.push(' ');
+        }
+        write!(f, "{}", s)
+    }
+}
+
+pub struct ResizingBagIntoIter<T> {
+    iter: SVecIntoIter<T>,
+}
+
+/// Consumes the bag, yielding the items in arbitrary order.
+impl<T> IntoIterator for ResizingBag<T> {
+    type Item = T;
+    type IntoIter = ResizingBagIntoIter<T>;
+    fn into_iter(self) -> Self::IntoIter {
+        ResizingBagIntoIter {
+            iter: self.data.into_iter(),
+        }
+    }
+}
+
+impl<T> Iterator for ResizingBagIntoIter<T> {
+    type Item = T;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// Adds the items to the bag.
+impl<T> FromIterator<T> for ResizingBag<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut bag = ResizingBag::new();
+        bag.extend(iter);
+        bag
+    }
+}
+
+/// Adds the items to the bag.
+impl<T> Extend<T> for ResizingBag<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.add(item);
+        }
+    }
+}
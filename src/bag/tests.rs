@@ -14,7 +14,7 @@ fn linked_bag_of_str() {
 
     assert_eq!(bag.len(), 14);
     assert_eq!(
-        bag.iter().map(|s| *s).collect::<HashSet<&str>>(),
+        bag.iter().copied().collect::<HashSet<&str>>(),
         HashSet::from(list)
     );
 
@@ -22,7 +22,7 @@ fn linked_bag_of_str() {
     let bag2 = bag.clone();
     assert_eq!(bag2.len(), bag.len());
     assert_eq!(
-        bag2.iter().map(|s| *s).collect::<HashSet<&str>>(),
+        bag2.iter().copied().collect::<HashSet<&str>>(),
         HashSet::from(list)
     );
 }
@@ -39,7 +39,7 @@ fn resizing_bag_of_str() {
 
     assert_eq!(bag.len(), 14);
     assert_eq!(
-        bag.iter().map(|s| *s).collect::<HashSet<&str>>(),
+        bag.iter().copied().collect::<HashSet<&str>>(),
         HashSet::from(list)
     );
 
@@ -47,7 +47,89 @@ fn resizing_bag_of_str() {
     let bag2 = bag.clone();
     assert_eq!(bag2.len(), bag.len());
     assert_eq!(
-        bag2.iter().map(|s| *s).collect::<HashSet<&str>>(),
+        bag2.iter().copied().collect::<HashSet<&str>>(),
         HashSet::from(list)
     );
 }
+
+#[test]
+fn linked_bag_remove() {
+    let mut bag = LinkedBag::new();
+    assert!(!bag.remove(&1));
+    for x in [1, 2, 3, 2, 4] {
+        bag.add(x);
+    }
+    assert!(bag.remove(&2));
+    assert_eq!(bag.len(), 4);
+    assert_eq!(bag.iter().filter(|&&x| x == 2).count(), 1);
+    assert!(bag.remove(&4)); // the first node
+    assert!(bag.remove(&1)); // the last node
+    assert!(!bag.remove(&5));
+    assert_eq!(bag.len(), 2);
+    let mut items: Vec<i32> = bag.iter().cloned().collect();
+    items.sort();
+    assert_eq!(items, [2, 3]);
+    assert!(bag.remove(&2));
+    assert!(bag.remove(&3));
+    assert!(bag.is_empty());
+}
+
+#[test]
+fn linked_bag_into_iter_from_iter() {
+    let list = ["to", "be", "or", "not", "to", "be"].map(String::from);
+    let mut bag: LinkedBag<String> = list.clone().into_iter().collect();
+    assert_eq!(bag.len(), 6);
+    bag.extend(["that".to_string()]);
+
+    let mut iter = bag.into_iter();
+    assert_eq!(iter.size_hint(), (7, Some(7)));
+    // the multiset round-trips, in arbitrary order
+    let mut items: Vec<String> = iter.by_ref().collect();
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+    items.sort();
+    let mut expected = list.to_vec();
+    expected.push("that".to_string());
+    expected.sort();
+    assert_eq!(items, expected);
+}
+
+#[test]
+fn resizing_bag_into_iter_from_iter() {
+    let list = ["to", "be", "or", "not", "to", "be"].map(String::from);
+    let mut bag: ResizingBag<String> = list.clone().into_iter().collect();
+    assert_eq!(bag.len(), 6);
+    bag.extend(["that".to_string()]);
+
+    let mut iter = bag.into_iter();
+    assert_eq!(iter.size_hint(), (7, Some(7)));
+    // the multiset round-trips, in arbitrary order
+    let mut items: Vec<String> = iter.by_ref().collect();
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+    items.sort();
+    let mut expected = list.to_vec();
+    expected.push("that".to_string());
+    expected.sort();
+    assert_eq!(items, expected);
+}
+
+#[test]
+fn bag_clone_and_display() {
+    let mut bag = ResizingBag::new();
+    assert_eq!(bag.to_string(), "");
+    for s in ["to", "be", "or"] {
+        bag.add(s);
+    }
+    let bag2 = bag.clone();
+    bag.add("not");
+    assert_eq!(bag2.to_string(), "to be or ");
+    assert_eq!(bag.to_string(), "to be or not ");
+
+    let mut bag = LinkedBag::new();
+    for s in ["to", "be", "or"] {
+        bag.add(s);
+    }
+    let bag2 = bag.clone();
+    bag.add("not");
+    assert_eq!(bag2.to_string(), "or be to ");
+    assert_eq!(bag.to_string(), "not or be to ");
+}
@@ -1,57 +1,91 @@
-//! Binary search for a sorted sequence without duplicates.
+//! Binary search for a sorted sequence.
+//!
+//! The exact-match searches, such as [`search`] and [`index_of`], are meant for a sequence without
+//! duplicates.  The bounds, [`lower_bound`] and [`upper_bound`], are well defined with duplicates.
 
 use std::cmp::Ordering;
 
-/// Returns the index of the specified key in the specified sequence,
-/// or -1 if not found.
-pub fn index_of<T: Ord>(a: &[T], key: &T) -> i64 {
-    let (mut lo, mut hi) = (0, a.len() as i64 - 1);
-    while lo <= hi {
+/// Returns the index of the specified key in the specified sorted sequence, or `None` if not
+/// found.
+///
+/// If there are duplicated keys in the sequence, the index of any one of them is returned.
+pub fn search<T: Ord>(a: &[T], key: &T) -> Option<usize> {
+    search_by_key(a, &key, |x| x)
+}
+
+/// Returns the index of an element whose key, extracted by `f`, equals the specified key, or
+/// `None` if not found.
+///
+/// The sequence must be sorted by the extracted key.  If there are duplicated keys in the
+/// sequence, the index of any one of them is returned.
+pub fn search_by_key<'a, T, K, F>(a: &'a [T], key: &K, f: F) -> Option<usize>
+where
+    K: Ord,
+    F: Fn(&'a T) -> K,
+{
+    // the key is in a[lo..hi] if it is present
+    let (mut lo, mut hi) = (0, a.len());
+    while lo < hi {
         let mid = lo + (hi - lo) / 2;
-        let cmp = key.cmp(&a[mid as usize]);
-        if cmp == Ordering::Less {
-            hi = mid - 1;
-        } else if cmp == Ordering::Greater {
-            lo = mid + 1;
-        } else {
-            return mid;
+        match key.cmp(&f(&a[mid])) {
+            Ordering::Less => hi = mid,
+            Ordering::Greater => lo = mid + 1,
+            Ordering::Equal => return Some(mid),
         }
     }
-    -1
+    None
+}
+
+/// Returns the index of the first element that is not less than the specified key in the
+/// specified sorted sequence, or the length of the sequence if there is no such element.
+///
+/// It is the insertion point of the key that keeps the sequence sorted, before any equal keys.
+pub fn lower_bound<T: Ord>(a: &[T], key: &T) -> usize {
+    a.partition_point(|x| x < key)
+}
+
+/// Returns the index of the first element that is greater than the specified key in the specified
+/// sorted sequence, or the length of the sequence if there is no such element.
+///
+/// It is the insertion point of the key that keeps the sequence sorted, after any equal keys.
+pub fn upper_bound<T: Ord>(a: &[T], key: &T) -> usize {
+    a.partition_point(|x| x <= key)
+}
+
+/// Returns the number of elements that are strictly less than the specified key in the specified
+/// sorted sequence.  It is the same as [`lower_bound`].
+pub fn rank<T: Ord>(a: &[T], key: &T) -> usize {
+    lower_bound(a, key)
+}
+
+/// Returns the number of elements that are equal to the specified key in the specified sorted
+/// sequence.
+pub fn count<T: Ord>(a: &[T], key: &T) -> usize {
+    upper_bound(a, key) - lower_bound(a, key)
+}
+
+/// Returns the index of the specified key in the specified sequence,
+/// or -1 if not found.
+///
+/// See [`search`], which returns an `Option<usize>` instead.
+pub fn index_of<T: Ord>(a: &[T], key: &T) -> i64 {
+    search(a, key).map_or(-1, |i| i as i64)
 }
 
 /// Returns the index of the specified key in the specified sequence,
 /// or -1 if not found.
+///
+/// See [`search`], which returns an `Option<usize>` instead.
 pub fn index_of_i32_seq(a: &[i32], key: &i32) -> i64 {
-    let (mut lo, mut hi) = (0, a.len() as i64 - 1);
-    while lo <= hi {
-        let mid = lo + (hi - lo) / 2;
-        if *key < a[mid as usize] {
-            hi = mid - 1;
-        } else if *key > a[mid as usize] {
-            lo = mid + 1;
-        } else {
-            return mid;
-        }
-    }
-    -1
+    search(a, key).map_or(-1, |i| i as i64)
 }
 
 /// Returns the index of the specified key in the specified sequence,
 /// or -1 if not found.
+///
+/// See [`search`], which returns an `Option<usize>` instead.
 pub fn index_of_i64_seq(a: &[i64], key: &i64) -> i64 {
-    let (mut lo, mut hi) = (0, a.len() as i64 - 1);
-    while lo <= hi {
-        let mid = lo + (hi - lo) / 2;
-        if *key < a[mid as usize] {
-            hi = mid - 1;
-        } else if *key > a[mid as usize] {
-            lo = mid + 1;
-        } else {
-            return mid;
-        }
-    }
-    -1
+    search(a, key).map_or(-1, |i| i as i64)
 }
 
 #[cfg(test)]
@@ -77,10 +111,7 @@ mod tests {
         // the result is undefined.
         let a = ["1", "3", "5", "5", "5", "7", "9"];
         let i = index_of(&a, &"5");
-        assert!(match i {
-            2..=4 => true,
-            _ => false,
-        });
+        assert!(matches!(i, 2..=4));
     }
 
     #[test]
@@ -108,9 +139,65 @@ mod tests {
         // the result is undefined.
         let b = [1, 3, 5, 5, 5, 7, 9];
         let i = index_of_i32_seq(&b, &5);
-        assert!(match i {
-            2..=4 => true,
-            _ => false,
-        });
+        assert!(matches!(i, 2..=4));
+    }
+
+    #[test]
+    fn test_search() {
+        let a = [0, 1, 2, 3, 5, 8, 13, 21, 34, 55];
+        for (i, x) in a.iter().enumerate() {
+            assert_eq!(search(&a, x), Some(i));
+        }
+        assert_eq!(search(&a, &-1), None);
+        assert_eq!(search(&a, &4), None);
+        assert_eq!(search(&a, &100), None);
+        assert_eq!(search(&[], &5), None);
+        assert_eq!(index_of_i64_seq(&[1, 2, 3], &3), 2);
+        assert_eq!(index_of_i64_seq(&[1, 2, 3], &4), -1);
+    }
+
+    #[test]
+    fn test_search_by_key() {
+        struct Student {
+            name: &'static str,
+            id: u32,
+        }
+        let students = [
+            ("Harris", 12),
+            ("Anderson", 27),
+            ("Martin", 31),
+            ("Jones", 58),
+        ]
+        .map(|(name, id)| Student { name, id });
+        let i = search_by_key(&students, &31, |s| s.id).unwrap();
+        assert_eq!(students[i].name, "Martin");
+        assert_eq!(search_by_key(&students, &30, |s| s.id), None);
+
+        // borrowed keys
+        let pairs = [("a", 1), ("c", 2), ("e", 3)];
+        assert_eq!(search_by_key(&pairs, &"e", |p| p.0), Some(2));
+        assert_eq!(search_by_key(&pairs, &"d", |p| p.0), None);
+    }
+
+    #[test]
+    fn test_bounds() {
+        let a = [1, 3, 5, 5, 5, 7, 9];
+        assert_eq!(lower_bound(&a, &5), 2);
+        assert_eq!(upper_bound(&a, &5), 5);
+        assert_eq!(rank(&a, &5), 2);
+        assert_eq!(count(&a, &5), 3);
+
+        assert_eq!(lower_bound(&a, &4), 2);
+        assert_eq!(upper_bound(&a, &4), 2);
+        assert_eq!(count(&a, &4), 0);
+        assert_eq!(lower_bound(&a, &0), 0);
+        assert_eq!(upper_bound(&a, &9), 7);
+        assert_eq!(rank(&a, &10), 7);
+        assert_eq!(count(&a, &1), 1);
+
+        let empty: [i32; 0] = [];
+        assert_eq!(lower_bound(&empty, &5), 0);
+        assert_eq!(upper_bound(&empty, &5), 0);
+        assert_eq!(count(&empty, &5), 0);
     }
 }
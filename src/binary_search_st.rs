@@ -0,0 +1,311 @@
+use crate::SVec;
+use crate::error::InvalidArgument;
+use std::cmp::Ordering;
+
+/// An ordered symbol table of generic key-value pairs, implemented with a sorted array.
+///
+/// It supports the usual `put`, `get`, `contains`, `delete`, `size`, and `is-empty` methods.  It
+/// also provides ordered methods for finding the `minimum`, `maximum`, `floor`, `select`,
+/// `ceiling`.  It also provides a `keys` method for iterating over all of the keys.
+///
+/// A symbol table implements the *associative array* abstraction: when associating a value with a
+/// key that is already in the symbol table, the convention is to replace the old value with the new
+/// value.
+///
+/// This implementation uses a *sorted array*: parallel arrays of the keys and the values, where
+/// the keys are kept in ascending order, and a key is located by binary search.
+///
+/// The `put` and `delete` operations take &Theta;(<em>n</em>) time in the worst case, where `n` is
+/// the number of key-value pairs, because the arrays are shifted.  The `contains`, `get`,
+/// `ceiling`, `floor`, and `rank` operations take &Theta;(log <em>n</em>) time in the worst case.
+///
+/// The `size`, `is-empty`, `minimum`, `maximum`, and `select` operations take &Theta;(1) time.
+///
+/// Construction takes &Theta;(1) time.
+///
+/// For an alternative implementation of the ordered symbol table API, see [`crate::BST`].  For
+/// additional documentation, see <a href="https://algs4.cs.princeton.edu/31elementary">Section
+/// 3.1</a> of <i>Algorithms, 4th Edition</i> by Robert Sedgewick and Kevin Wayne.
+#[derive(Debug, Clone)]
+pub struct BinarySearchST<K, V> {
+    keys: SVec<K>, // keys[i] is the (i+1)st smallest key
+    vals: SVec<V>, // vals[i] is the value associated with keys[i]
+}
+
+impl<K, V> BinarySearchST<K, V>
+where
+    K: Ord,
+{
+    /// Initialize an empty symbol table.
+    pub fn new() -> Self {
+        BinarySearchST {
+            keys: SVec::new(),
+            vals: SVec::new(),
+        }
+    }
+
+    /// Returns true if this symbol table is empty, returns false otherwise.
+    pub fn is_empty(&self) -> bool {
+        self.size() == 0
+    }
+
+    /// Returns the number of key-value pairs in this symbol table.
+    pub fn size(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Does this symbol table contain the given key?
+    pub fn contains(&self, key: &K) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Returns the value associated with the given key.
+    pub fn get(&self, key: &K) -> Option<&V> {
+        let i = self.rank(key);
+        if i < self.size() && self.keys[i] == *key {
+            Some(&self.vals[i])
+        } else {
+            None
+        }
+    }
+
+    /// Inserts the specified key-value pair into the symbol table, overwriting the old value with
+    /// the new value if the symbol table already contains the specified key.
+    pub fn put(&mut self, key: K, val: V) {
+        let i = self.rank(&key);
+
+        // key is already in table
+        if i < self.size() && self.keys[i] == key {
+            self.vals[i] = val;
+            return;
+        }
+
+        // insert new key-value pair
+        self.keys.insert(i, key);
+        self.vals.insert(i, val);
+        debug_assert!(self.check());
+    }
+
+    /// Removes the specified key and its associated value from this symbol table (if the key is in
+    /// this symbol table).
+    pub fn delete(&mut self, key: &K) {
+        let i = self.rank(key);
+
+        // key not in table
+        if i == self.size() || self.keys[i] != *key {
+            return;
+        }
+
+        self.keys.remove(i);
+        self.vals.remove(i);
+        debug_assert!(self.check());
+    }
+
+    /// Removes the smallest key and associated value from the symbol table.
+    pub fn delete_min(&mut self) -> Result<(), InvalidArgument> {
+        if self.is_empty() {
+            return Err(InvalidArgument("symbol table underflow".to_string()));
+        }
+        self.keys.remove(0);
+        self.vals.remove(0);
+        debug_assert!(self.check());
+        Ok(())
+    }
+
+    /// Removes the largest key and associated value from the symbol table.
+    pub fn delete_max(&mut self) -> Result<(), InvalidArgument> {
+        if self.is_empty() {
+            return Err(InvalidArgument("symbol table underflow".to_string()));
+        }
+        self.keys.pop();
+        self.vals.pop();
+        debug_assert!(self.check());
+        Ok(())
+    }
+
+    /// Returns the smallest key in the symbol table.
+    pub fn min(&self) -> Option<&K> {
+        self.keys.first()
+    }
+
+    /// Returns the largest key in the symbol table.
+    pub fn max(&self) -> Option<&K> {
+        self.keys.last()
+    }
+
+    /// Returns the largest key in the symbol table less than or equal to `key`.
+    pub fn floor(&self, key: &K) -> Option<&K> {
+        let i = self.rank(key);
+        if i < self.size() && self.keys[i] == *key {
+            Some(&self.keys[i])
+        } else if i == 0 {
+            None
+        } else {
+            Some(&self.keys[i - 1])
+        }
+    }
+
+    /// Returns the smallest key in the symbol table greater than or equal to `key`.
+    pub fn ceiling(&self, key: &K) -> Option<&K> {
+        self.keys.get(self.rank(key))
+    }
+
+    /// Returns the key in the symbol table of a given `rank`.
+    ///
+    /// This key has the property that there are `rank` keys in the symbol table that are smaller.
+    /// In other words, this key is the (`rank+1`)st smallest key in the symbol table.
+    ///
+    /// If `rank >= n` where `n` is the size of this symbol table, return `InvalidArgument`.
+    pub fn select(&self, rank: usize) -> Result<&K, InvalidArgument> {
+        self.keys
+            .get(rank)
+            .ok_or_else(|| InvalidArgument(format!("argument to select() is invalid: {}", rank)))
+    }
+
+    /// Returns the number of keys in the symbol table strictly less than `key`.
+    pub fn rank(&self, key: &K) -> usize {
+        let (mut lo, mut hi) = (0, self.size());
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            match key.cmp(&self.keys[mid]) {
+                Ordering::Less => hi = mid,
+                Ordering::Greater => lo = mid + 1,
+                Ordering::Equal => return mid,
+            }
+        }
+        lo
+    }
+
+    /// Returns an iterator over the keys in the symbol table in ascending order.
+    pub fn keys(&self) -> impl DoubleEndedIterator<Item = &K> {
+        self.keys.iter()
+    }
+
+    // are the keys sorted in strictly ascending order, and rank(select(i)) = i?
+    fn check(&self) -> bool {
+        self.keys.len() == self.vals.len()
+            && self.keys.windows(2).all(|w| w[0] < w[1])
+            && (0..self.size()).all(|i| self.rank(&self.keys[i]) == i)
+    }
+}
+
+impl<K, V> Default for BinarySearchST<K, V>
+where
+    K: Ord,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn prepare() -> BinarySearchST<char, usize> {
+        let mut st = BinarySearchST::new();
+        for (i, x) in "SEARCHEXAMPLE".chars().enumerate() {
+            st.put(x, i);
+        }
+        st
+    }
+
+    #[test]
+    fn test_binary_search_st_put_and_keys() {
+        let st = prepare();
+        assert!(!st.is_empty());
+        assert_eq!(st.size(), 10);
+        assert_eq!(st.keys().collect::<String>(), "ACEHLMPRSX");
+        assert_eq!(st.keys().rev().collect::<String>(), "XSRPMLHECA");
+        assert!(BinarySearchST::<char, usize>::new().is_empty());
+    }
+
+    #[test]
+    fn test_binary_search_st_contains_and_get() {
+        let st = prepare();
+        assert!(st.contains(&'X'));
+        assert!(!st.contains(&'Q'));
+        assert!(!st.contains(&'a'));
+
+        assert_eq!(st.get(&'X'), Some(&7));
+        assert_eq!(st.get(&'E'), Some(&12)); // overwritten
+        assert_eq!(st.get(&'Q'), None);
+        assert_eq!(st.get(&'a'), None);
+    }
+
+    #[test]
+    fn test_binary_search_st_delete() {
+        let mut st = prepare();
+        st.delete(&'Q');
+        assert_eq!(st.size(), 10);
+        st.delete(&'E');
+        st.delete(&'A');
+        st.delete(&'X');
+        assert_eq!(st.keys().collect::<String>(), "CHLMPRS");
+        st.delete_min().unwrap();
+        st.delete_max().unwrap();
+        assert_eq!(st.keys().collect::<String>(), "HLMPR");
+        assert!(st.check());
+
+        let mut empty_st: BinarySearchST<i32, String> = BinarySearchST::new();
+        assert!(empty_st.delete_min().is_err());
+        assert!(empty_st.delete_max().is_err());
+    }
+
+    #[test]
+    fn test_binary_search_st_min_and_max() {
+        let empty_st: BinarySearchST<i32, String> = BinarySearchST::new();
+        assert!(empty_st.min().is_none());
+        assert!(empty_st.max().is_none());
+
+        let st = prepare();
+        assert_eq!(st.min(), Some(&'A'));
+        assert_eq!(st.max(), Some(&'X'));
+    }
+
+    #[test]
+    fn test_binary_search_st_floor_and_ceiling() {
+        let empty_st: BinarySearchST<i32, String> = BinarySearchST::new();
+        assert!(empty_st.floor(&9).is_none());
+        assert!(empty_st.ceiling(&20).is_none());
+
+        let st = prepare();
+        assert_eq!(st.floor(&'0'), None);
+        assert_eq!(st.floor(&'A'), Some(&'A'));
+        assert_eq!(st.floor(&'B'), Some(&'A'));
+        assert_eq!(st.floor(&'Z'), Some(&'X'));
+        assert_eq!(st.ceiling(&'A'), Some(&'A'));
+        assert_eq!(st.ceiling(&'B'), Some(&'C'));
+        assert_eq!(st.ceiling(&'X'), Some(&'X'));
+        assert_eq!(st.ceiling(&'Y'), None);
+    }
+
+    #[test]
+    fn test_binary_search_st_select_and_rank() {
+        let empty_st: BinarySearchST<i32, String> = BinarySearchST::new();
+        assert!(empty_st.select(20).is_err());
+        assert!(empty_st.select(0).is_err());
+        assert_eq!(empty_st.rank(&-3), 0);
+        assert_eq!(empty_st.rank(&0), 0);
+
+        let st = prepare();
+        let expected_keys = "ACEHLMPRSX";
+        for (i, v) in expected_keys.chars().enumerate() {
+            assert_eq!(st.select(i).unwrap(), &v);
+        }
+        assert!(st.select(10).is_err());
+
+        let expected_ranks = [
+            ('A', 0),
+            ('B', 1),
+            ('C', 1),
+            ('D', 2),
+            ('E', 2),
+            ('X', 9),
+            ('Z', 10),
+        ];
+        for (k, r) in expected_ranks {
+            assert_eq!(st.rank(&k), r);
+        }
+    }
+}
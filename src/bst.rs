@@ -3,6 +3,9 @@
 use crate::error::InvalidArgument;
 use std::cmp::Ordering;
 use std::collections::VecDeque;
+use std::ops::{Bound, RangeBounds};
+
+pub(crate) mod multibst;
 
 type Link<K, V> = Option<Box<Node<K, V>>>;
 
@@ -49,11 +52,11 @@ impl<K, V> Node<K, V> {
 ///
 /// Construction takes &Theta;(1) time.
 ///
-/// For alternative implementations of the symbol table API, see {@link ST}, {@link BinarySearchST},
-/// {@link SequentialSearchST}, {@link RedBlackBST}, {@link SeparateChainingHashST}, and {@link
-/// LinearProbingHashST}, For additional documentation, see <a
-/// href="https://algs4.cs.princeton.edu/32bst">Section 3.2</a> of <i>Algorithms, 4th Edition</i> by
-/// Robert Sedgewick and Kevin Wayne.
+/// For alternative implementations of the symbol table API, see {@link ST},
+/// [`crate::BinarySearchST`], [`crate::SequentialSearchST`], {@link RedBlackBST},
+/// [`crate::SeparateChainingHashST`], and {@link LinearProbingHashST}, For additional
+/// documentation, see <a href="https://algs4.cs.princeton.edu/32bst">Section 3.2</a> of
+/// <i>Algorithms, 4th Edition</i> by Robert Sedgewick and Kevin Wayne.
 #[derive(Debug)]
 pub struct BST<K, V> {
     root: Link<K, V>,
@@ -88,6 +91,19 @@ where
         get(self.root.as_ref(), key)
     }
 
+    /// Returns a mutable reference to the value associated with the given key.
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        let mut x = self.root.as_deref_mut();
+        while let Some(node) = x {
+            match key.cmp(&node.key) {
+                Ordering::Equal => return Some(&mut node.val),
+                Ordering::Less => x = node.left.as_deref_mut(),
+                Ordering::Greater => x = node.right.as_deref_mut(),
+            }
+        }
+        None
+    }
+
     /// Inserts the specified key-value pair into the symbol table, overwriting the old value with
     /// the new value if the symbol table already contains the specified key.
     pub fn put(&mut self, key: K, val: V) {
@@ -95,6 +111,34 @@ where
         debug_assert!(self.check());
     }
 
+    /// Gets the given key's corresponding entry in the symbol table for in-place manipulation, like
+    /// [`std::collections::BTreeMap::entry`].
+    ///
+    /// The key is searched only once.  For example, counting the words needs no double lookups:
+    /// `*st.entry(word).or_insert(0) += 1`.
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V> {
+        let mut sizes = Vec::new(); // subtree sizes on the search path
+        let mut link = &mut self.root;
+        loop {
+            if link.is_none() {
+                return Entry::Vacant(VacantEntry { key, link, sizes });
+            }
+            let Node {
+                key: k,
+                val,
+                left,
+                right,
+                size,
+            } = &mut **link.as_mut().unwrap();
+            match key.cmp(k) {
+                Ordering::Equal => return Entry::Occupied(OccupiedEntry { key: k, val }),
+                Ordering::Less => link = left,
+                Ordering::Greater => link = right,
+            }
+            sizes.push(size);
+        }
+    }
+
     /// Removes the smallest key and associated value from the symbol table.
     pub fn delete_min(&mut self) -> Result<(), InvalidArgument> {
         if self.is_empty() {
@@ -117,6 +161,24 @@ where
         Ok(())
     }
 
+    /// Removes and returns the smallest key and associated value from the symbol table, or `None`
+    /// if the symbol table is empty.
+    pub fn pop_min(&mut self) -> Option<(K, V)> {
+        let (t, deleted) = delete_min(self.root.take()?);
+        self.root = t;
+        debug_assert!(self.check());
+        Some((deleted.key, deleted.val))
+    }
+
+    /// Removes and returns the largest key and associated value from the symbol table, or `None`
+    /// if the symbol table is empty.
+    pub fn pop_max(&mut self) -> Option<(K, V)> {
+        let (t, deleted) = delete_max(self.root.take()?);
+        self.root = t;
+        debug_assert!(self.check());
+        Some((deleted.key, deleted.val))
+    }
+
     /// Removes the specified key and its associated value from this symbol table (if the key is in
     /// this symbol table).
     pub fn delete(&mut self, key: &K) {
@@ -124,6 +186,47 @@ where
         debug_assert!(self.check());
     }
 
+    /// Moves all the key-value pairs of `other` into this symbol table, consuming `other`.
+    ///
+    /// If a key is in both symbol tables, the value of `other` wins: it overwrites the old value,
+    /// the same as what `put` does.
+    ///
+    /// The pairs of `other` are inserted in pre-order, so that merging into an empty symbol table
+    /// reproduces the shape of `other` instead of degenerating into a linked list.
+    pub fn merge(&mut self, other: BST<K, V>) {
+        consume_pre_order(other.root, &mut |key, val| {
+            self.root = put(self.root.take(), key, val);
+        });
+        debug_assert!(self.check());
+    }
+
+    /// Splits the symbol table into two at the given key.  Returns everything with keys greater
+    /// than or equal to `key` as a new symbol table, leaving the keys less than `key` in this one.
+    ///
+    /// It takes time proportional to the height of the BST, because only the nodes on the search
+    /// path of `key` are relinked.
+    pub fn split_off(&mut self, key: &K) -> BST<K, V> {
+        let (lo, hi) = split(self.root.take(), key);
+        self.root = lo;
+        let other = BST { root: hi };
+        debug_assert!(self.check());
+        debug_assert!(other.check());
+        other
+    }
+
+    /// Retains only the key-value pairs specified by the predicate `f`, that is, removes all the
+    /// pairs `(k, v)` for which `f(&k, &mut v)` returns false.  The pairs are visited in ascending
+    /// order of the keys.
+    ///
+    /// It takes a single traversal of the BST, only the subtrees that lose nodes are relinked.
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&K, &mut V) -> bool,
+    {
+        self.root = retain(self.root.take(), &mut f);
+        debug_assert!(self.check());
+    }
+
     /// Returns the smallest key in the symbol table.
     pub fn min(&self) -> Option<&K> {
         if self.is_empty() {
@@ -194,6 +297,19 @@ where
         KeysRange::new(&self.root, lo, hi)
     }
 
+    /// Returns an iterator over the keys in the symbol table in the given `range`, like
+    /// [`std::collections::BTreeMap::range`].
+    ///
+    /// Each end of the range may be included, excluded, or unbounded, e.g. `'C'..'M'`, `..='R'`, or
+    /// `..`.
+    ///
+    /// The iterator implements DoubleEndedIterator.
+    ///
+    /// Note: this iterator is eager (not lazy at all).  See [KeysRange].
+    pub fn keys_in<R: RangeBounds<K>>(&self, range: R) -> KeysRange<'_, K> {
+        KeysRange::from_bounds(&self.root, range.start_bound(), range.end_bound())
+    }
+
     /// Returns the number of keys in the symbol table in the given range.
     pub fn size_range(&self, lo: &K, hi: &K) -> usize {
         if lo.cmp(hi) == Ordering::Greater {
@@ -218,6 +334,18 @@ where
         KeysLevelOrder::new(&self.root)
     }
 
+    /// Returns a new symbol table with the same keys and shape as this one, with each value mapped
+    /// through `f`.
+    pub fn map_values<W, F>(&self, f: F) -> BST<K, W>
+    where
+        K: Clone,
+        F: Fn(&V) -> W,
+    {
+        BST {
+            root: map_values(self.root.as_ref(), &f),
+        }
+    }
+
     fn check(&self) -> bool {
         let a = self.is_bst();
         if !a {
@@ -369,19 +497,94 @@ fn delete<K: Ord, V>(x: Link<K, V>, key: &K) -> Link<K, V> {
     }
 }
 
-fn min<K, V>(x: &Box<Node<K, V>>) -> &Box<Node<K, V>> {
-    if x.left.is_none() {
-        x
+// Returns new_root
+fn retain<K: Ord, V, F>(x: Link<K, V>, f: &mut F) -> Link<K, V>
+where
+    F: FnMut(&K, &mut V) -> bool,
+{
+    let mut node = x?;
+    node.left = retain(node.left.take(), f);
+    let keep = f(&node.key, &mut node.val);
+    node.right = retain(node.right.take(), f);
+    if keep {
+        node.size = size(node.left.as_ref()) + size(node.right.as_ref()) + 1;
+        Some(node)
     } else {
-        min(x.left.as_ref().unwrap())
+        join(node.left.take(), node.right.take())
+    }
+}
+
+// Joins two trees, where all the keys in `left` are less than the keys in `right`.
+//
+// Returns new_root
+fn join<K: Ord, V>(left: Link<K, V>, right: Link<K, V>) -> Link<K, V> {
+    match (left, right) {
+        (None, right) => right,
+        (left, None) => left,
+        (left, Some(right)) => {
+            let (right, mut node) = delete_min(right); // the successor becomes the new root
+            node.left = left;
+            node.right = right;
+            node.size = size(node.left.as_ref()) + size(node.right.as_ref()) + 1;
+            Some(node)
+        }
+    }
+}
+
+// Splits the tree rooted at `x` into two trees, one with the keys less than `key`, the other with
+// the keys greater than or equal to `key`.
+//
+// Returns: (lo_root, hi_root)
+fn split<K: Ord, V>(x: Link<K, V>, key: &K) -> (Link<K, V>, Link<K, V>) {
+    match x {
+        None => (None, None),
+        Some(mut node) => {
+            if node.key.cmp(key).is_lt() {
+                let (lo, hi) = split(node.right.take(), key);
+                node.right = lo;
+                node.size = size(node.left.as_ref()) + size(node.right.as_ref()) + 1;
+                (Some(node), hi)
+            } else {
+                let (lo, hi) = split(node.left.take(), key);
+                node.left = hi;
+                node.size = size(node.left.as_ref()) + size(node.right.as_ref()) + 1;
+                (lo, Some(node))
+            }
+        }
+    }
+}
+
+// Consumes all the nodes of the tree rooted at `x` in pre-order, passing the key-value pairs to
+// `f`.
+fn consume_pre_order<K, V, F>(x: Link<K, V>, f: &mut F)
+where
+    F: FnMut(K, V),
+{
+    if let Some(node) = x {
+        let Node {
+            key,
+            val,
+            left,
+            right,
+            ..
+        } = *node;
+        f(key, val);
+        consume_pre_order(left, f);
+        consume_pre_order(right, f);
+    }
+}
+
+fn min<K, V>(x: &Box<Node<K, V>>) -> &Box<Node<K, V>> {
+    match &x.left {
+        None => x,
+        Some(left) => min(left),
     }
 }
 
 fn max<K, V>(x: &Box<Node<K, V>>) -> &Box<Node<K, V>> {
-    if x.right.is_none() {
-        x
-    } else {
-        max(x.right.as_ref().unwrap())
+    match &x.right {
+        None => x,
+        Some(right) => max(right),
     }
 }
 
@@ -427,6 +630,21 @@ fn ceiling<'a, K: Ord, V>(x: Option<&'a Box<Node<K, V>>>, key: &K) -> Option<&'a
     }
 }
 
+fn map_values<K: Clone, V, W, F>(x: Option<&Box<Node<K, V>>>, f: &F) -> Link<K, W>
+where
+    F: Fn(&V) -> W,
+{
+    x.map(|y| {
+        Box::new(Node {
+            key: y.key.clone(),
+            val: f(&y.val),
+            left: map_values(y.left.as_ref(), f),
+            right: map_values(y.right.as_ref(), f),
+            size: y.size,
+        })
+    })
+}
+
 // Returns key in BST rooted at x of given rank.
 // Precondition: rank is in legal range.
 fn select<K, V>(x: Option<&Box<Node<K, V>>>, rank: usize) -> Option<&K> {
@@ -455,6 +673,115 @@ fn rank<K: Ord, V>(key: &K, x: Option<&Box<Node<K, V>>>) -> usize {
     }
 }
 
+/// A view into a single entry in a [`BST`], which may either be vacant or occupied.
+///
+/// This enum is constructed from the [`BST::entry`] method.
+pub enum Entry<'a, K, V> {
+    /// A vacant entry.
+    Vacant(VacantEntry<'a, K, V>),
+    /// An occupied entry.
+    Occupied(OccupiedEntry<'a, K, V>),
+}
+
+/// A view into a vacant entry in a [`BST`].  It is part of the [`Entry`] enum.
+pub struct VacantEntry<'a, K, V> {
+    key: K,
+    link: &'a mut Link<K, V>,  // the empty link where the new node goes
+    sizes: Vec<&'a mut usize>, // subtree sizes of the ancestors of the new node
+}
+
+/// A view into an occupied entry in a [`BST`].  It is part of the [`Entry`] enum.
+pub struct OccupiedEntry<'a, K, V> {
+    key: &'a K,
+    val: &'a mut V,
+}
+
+impl<'a, K, V> Entry<'a, K, V> {
+    /// Ensures a value is in the entry by inserting `default` if empty, and returns a mutable
+    /// reference to the value in the entry.
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default),
+        }
+    }
+
+    /// Ensures a value is in the entry by inserting the result of `default` if empty, and returns a
+    /// mutable reference to the value in the entry.
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    /// Provides in-place mutable access to an occupied entry before any potential inserts into the
+    /// symbol table.
+    pub fn and_modify<F: FnOnce(&mut V)>(self, f: F) -> Self {
+        match self {
+            Entry::Occupied(entry) => {
+                f(entry.val);
+                Entry::Occupied(entry)
+            }
+            Entry::Vacant(entry) => Entry::Vacant(entry),
+        }
+    }
+
+    /// Returns a reference to this entry's key.
+    pub fn key(&self) -> &K {
+        match self {
+            Entry::Occupied(entry) => entry.key(),
+            Entry::Vacant(entry) => entry.key(),
+        }
+    }
+}
+
+impl<'a, K, V> VacantEntry<'a, K, V> {
+    /// Returns a reference to the key that would be used when inserting a value through this entry.
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// Sets the value of the entry with the `VacantEntry`'s key, and returns a mutable reference to
+    /// it.
+    ///
+    /// The subtree sizes of all the ancestors of the new node are updated.
+    pub fn insert(self, value: V) -> &'a mut V {
+        for size in self.sizes {
+            *size += 1;
+        }
+        let node = self.link.insert(Box::new(Node::new(self.key, value, 1)));
+        &mut node.val
+    }
+}
+
+impl<'a, K, V> OccupiedEntry<'a, K, V> {
+    /// Returns a reference to the key in the entry.
+    pub fn key(&self) -> &K {
+        self.key
+    }
+
+    /// Returns a reference to the value in the entry.
+    pub fn get(&self) -> &V {
+        self.val
+    }
+
+    /// Returns a mutable reference to the value in the entry.
+    pub fn get_mut(&mut self) -> &mut V {
+        self.val
+    }
+
+    /// Converts the entry into a mutable reference to its value.
+    pub fn into_mut(self) -> &'a mut V {
+        self.val
+    }
+
+    /// Sets the value of the entry, and returns the entry's old value.
+    pub fn insert(&mut self, value: V) -> V {
+        std::mem::replace(self.val, value)
+    }
+}
+
 /// Iterator over all the keys of the given BST.
 ///
 /// This iterator is lazy but not pure lazy.  It consumes part of the tree nodes initially, and then
@@ -511,6 +838,14 @@ impl<'a, K: Ord> KeysRange<'a, K> {
         keys(root, &mut iter.queue, lo, hi);
         iter
     }
+
+    fn from_bounds<'b, V>(root: &'a Link<K, V>, lo: Bound<&'b K>, hi: Bound<&'b K>) -> Self {
+        let mut iter = KeysRange {
+            queue: VecDeque::new(),
+        };
+        keys_in(root, &mut iter.queue, lo, hi);
+        iter
+    }
 }
 
 impl<'a, K: Ord> Iterator for KeysRange<'a, K> {
@@ -548,6 +883,44 @@ fn keys<'a, 'b, K: Ord, V>(x: &'a Link<K, V>, queue: &mut VecDeque<&'a K>, lo: &
     }
 }
 
+fn keys_in<'a, 'b, K: Ord, V>(
+    x: &'a Link<K, V>,
+    queue: &mut VecDeque<&'a K>,
+    lo: Bound<&'b K>,
+    hi: Bound<&'b K>,
+) {
+    if let Some(y) = x {
+        // Is there any key in the left (right) subtree that may be within the range?
+        let go_left = match lo {
+            Bound::Included(l) | Bound::Excluded(l) => l.cmp(&y.key).is_lt(),
+            Bound::Unbounded => true,
+        };
+        let go_right = match hi {
+            Bound::Included(h) | Bound::Excluded(h) => h.cmp(&y.key).is_gt(),
+            Bound::Unbounded => true,
+        };
+        let above_lo = match lo {
+            Bound::Included(l) => l.cmp(&y.key).is_le(),
+            Bound::Excluded(l) => l.cmp(&y.key).is_lt(),
+            Bound::Unbounded => true,
+        };
+        let below_hi = match hi {
+            Bound::Included(h) => h.cmp(&y.key).is_ge(),
+            Bound::Excluded(h) => h.cmp(&y.key).is_gt(),
+            Bound::Unbounded => true,
+        };
+        if go_left {
+            keys_in(&y.left, queue, lo, hi);
+        }
+        if above_lo && below_hi {
+            queue.push_back(&y.key);
+        }
+        if go_right {
+            keys_in(&y.right, queue, lo, hi);
+        }
+    }
+}
+
 fn height<K, V>(x: &Link<K, V>) -> isize {
     match x {
         None => -1,
@@ -701,6 +1074,63 @@ mod tests {
         assert_eq!(st.get(&'A'), Some(&8));
     }
 
+    #[test]
+    fn test_bst_get_mut() {
+        let mut st = prepare_2();
+        *st.get_mut(&'X').unwrap() += 10;
+        assert_eq!(st.get(&'X'), Some(&17));
+        assert_eq!(st.get_mut(&'Q'), None);
+        assert_eq!(st.size(), 10);
+
+        let mut empty_st: BST<char, usize> = BST::new();
+        assert_eq!(empty_st.get_mut(&'A'), None);
+    }
+
+    #[test]
+    fn test_bst_entry() {
+        let mut st: BST<&str, usize> = BST::new();
+        for word in "it was the best of times it was the worst of times".split(' ') {
+            *st.entry(word).or_insert(0) += 1;
+            assert!(st.check());
+        }
+        assert_eq!(st.size(), 7);
+        assert_eq!(st.get(&"it"), Some(&2));
+        assert_eq!(st.get(&"best"), Some(&1));
+        assert_eq!(st.get(&"times"), Some(&2));
+
+        st.entry("best").and_modify(|v| *v += 10).or_insert(0);
+        st.entry("age").and_modify(|v| *v += 10).or_insert(42);
+        assert_eq!(st.get(&"best"), Some(&11));
+        assert_eq!(st.get(&"age"), Some(&42));
+        assert_eq!(st.size(), 8);
+        assert!(st.check());
+
+        assert_eq!(*st.entry("wisdom").or_insert_with(|| 7), 7);
+        assert_eq!(*st.entry("wisdom").or_insert_with(|| 8), 7);
+        assert_eq!(st.entry("wisdom").key(), &"wisdom");
+        assert_eq!(st.size(), 9);
+        assert!(st.check());
+
+        match st.entry("it") {
+            Entry::Occupied(mut e) => {
+                assert_eq!(e.get(), &2);
+                assert_eq!(e.insert(5), 2);
+                *e.get_mut() += 1;
+            }
+            Entry::Vacant(_) => panic!("\"it\" should be occupied"),
+        }
+        assert_eq!(st.get(&"it"), Some(&6));
+
+        let mut st = prepare_2();
+        assert!(matches!(st.entry('B'), Entry::Vacant(_)));
+        assert_eq!(st.size(), 10);
+        *st.entry('B').or_insert(100) += 1;
+        assert_eq!(st.get(&'B'), Some(&101));
+        assert_eq!(st.size(), 11);
+        assert_eq!(st.rank(&'C'), 2);
+        assert!(st.check());
+    }
+
     #[test]
     fn test_bst_delete_min() {
         let mut empty_st: BST<i32, String> = Default::default();
@@ -733,6 +1163,29 @@ mod tests {
         assert!(!st.contains(&'X'));
     }
 
+    #[test]
+    fn test_bst_pop_min_and_pop_max() {
+        let mut empty_st: BST<i32, String> = BST::new();
+        assert!(empty_st.pop_min().is_none());
+        assert!(empty_st.pop_max().is_none());
+
+        let mut st = prepare_2();
+        let mut keys = String::new();
+        while let Some((k, _)) = st.pop_min() {
+            keys.push(k);
+        }
+        assert_eq!(keys, "ACEHLMPRSX");
+        assert!(st.is_empty());
+        assert!(st.pop_min().is_none());
+
+        let mut st = prepare_2();
+        assert_eq!(st.pop_max(), Some(('X', 7)));
+        assert_eq!(st.pop_max(), Some(('S', 0)));
+        assert_eq!(st.pop_min(), Some(('A', 8)));
+        assert_eq!(st.size(), 7);
+        assert_eq!(st.keys().collect::<String>(), "CEHLMPR");
+    }
+
     #[test]
     fn test_bst_delete() {
         let mut empty_st: BST<i32, String> = BST::new();
@@ -747,6 +1200,122 @@ mod tests {
         assert_eq!(st.keys().collect::<String>(), "ACELMPRSX");
     }
 
+    #[test]
+    fn test_bst_merge() {
+        let mut st = prepare_2();
+        st.merge(BST::new());
+        assert_eq!(st.keys().collect::<String>(), "ACEHLMPRSX");
+
+        let mut empty_st = BST::new();
+        empty_st.merge(prepare_2());
+        assert_eq!(empty_st.keys().collect::<String>(), "ACEHLMPRSX");
+        assert_eq!(empty_st.height(), 5);
+        assert_eq!(
+            empty_st.keys_level_order().collect::<String>(),
+            "SEXARCHMLP"
+        );
+
+        // disjoint keys
+        let mut st = prepare_2();
+        let mut other = BST::new();
+        for (i, x) in "BDFZ".chars().enumerate() {
+            other.put(x, 100 + i);
+        }
+        st.merge(other);
+        assert_eq!(st.size(), 14);
+        assert_eq!(st.keys().collect::<String>(), "ABCDEFHLMPRSXZ");
+        assert_eq!(st.get(&'Z'), Some(&103));
+        assert!(st.check());
+
+        // overlapping keys: the values of `other` win
+        let mut st = prepare_2();
+        let mut other = BST::new();
+        for (i, x) in "AMZ".chars().enumerate() {
+            other.put(x, 100 + i);
+        }
+        st.merge(other);
+        assert_eq!(st.size(), 11);
+        assert_eq!(st.keys().collect::<String>(), "ACEHLMPRSXZ");
+        assert_eq!(st.get(&'A'), Some(&100));
+        assert_eq!(st.get(&'M'), Some(&101));
+        assert_eq!(st.get(&'Z'), Some(&102));
+        assert_eq!(st.get(&'X'), Some(&7));
+        assert!(st.check());
+    }
+
+    #[test]
+    fn test_bst_split_off() {
+        let mut empty_st: BST<i32, String> = BST::new();
+        assert!(empty_st.split_off(&3).is_empty());
+        assert!(empty_st.is_empty());
+
+        let mut st = prepare_2();
+        let hi = st.split_off(&'M');
+        assert_eq!(st.keys().collect::<String>(), "ACEHL");
+        assert_eq!(hi.keys().collect::<String>(), "MPRSX");
+        assert_eq!(st.size(), 5);
+        assert_eq!(hi.size(), 5);
+        assert_eq!(hi.get(&'M'), Some(&9));
+        assert!(st.check());
+        assert!(hi.check());
+
+        // split at a key not in the symbol table
+        let mut st = prepare_2();
+        let hi = st.split_off(&'N');
+        assert_eq!(st.keys().collect::<String>(), "ACEHLM");
+        assert_eq!(hi.keys().collect::<String>(), "PRSX");
+
+        let mut st = prepare_2();
+        let hi = st.split_off(&'A');
+        assert!(st.is_empty());
+        assert_eq!(hi.size(), 10);
+
+        let mut st = prepare_2();
+        let hi = st.split_off(&'Y');
+        assert_eq!(st.size(), 10);
+        assert!(hi.is_empty());
+    }
+
+    #[test]
+    fn test_bst_retain() {
+        let mut empty_st: BST<i32, i32> = BST::new();
+        empty_st.retain(|_, _| false);
+        assert!(empty_st.is_empty());
+
+        let mut st: BST<i32, i32> = BST::new();
+        for k in [50, 30, 70, 20, 40, 60, 80, 10, 25, 65] {
+            st.put(k, k / 10);
+        }
+        let mut visited = Vec::new();
+        st.retain(|k, v| {
+            visited.push(*k);
+            *v % 2 == 0
+        });
+        assert_eq!(visited, [10, 20, 25, 30, 40, 50, 60, 65, 70, 80]);
+        assert_eq!(
+            st.keys().cloned().collect::<Vec<i32>>(),
+            [20, 25, 40, 60, 65, 80]
+        );
+        assert_eq!(st.size(), 6);
+        assert_eq!(st.rank(&60), 3);
+        assert!(st.check());
+
+        // values can be modified
+        st.retain(|_, v| {
+            *v *= 10;
+            true
+        });
+        assert_eq!(st.get(&40), Some(&40));
+
+        st.retain(|_, _| false);
+        assert!(st.is_empty());
+
+        let mut st = prepare_2();
+        st.retain(|k, _| *k > 'E');
+        assert_eq!(st.keys().collect::<String>(), "HLMPRSX");
+        assert!(st.check());
+    }
+
     #[test]
     fn test_bst_min_and_max() {
         let empty_st: BST<i32, String> = BST::new();
@@ -833,6 +1402,37 @@ mod tests {
         assert_eq!(itr.next(), None);
     }
 
+    #[test]
+    fn test_bst_keys_in() {
+        let empty_st: BST<i32, String> = BST::new();
+        assert_eq!(empty_st.keys_in(2..8).count(), 0);
+        assert_eq!(empty_st.keys_in(..).count(), 0);
+
+        let st = prepare_2();
+        // expected_keys = "ACEHLMPRSX";
+        assert_eq!(
+            st.keys_in('B'..='Q').collect::<String>(),
+            st.keys_range(&'B', &'Q').collect::<String>()
+        );
+        assert_eq!(st.keys_in('A'..='Z').collect::<String>(), "ACEHLMPRSX");
+        assert_eq!(st.keys_in(..).collect::<String>(), "ACEHLMPRSX");
+        assert_eq!(st.keys_in(..'E').collect::<String>(), "AC");
+        assert_eq!(st.keys_in(..='E').collect::<String>(), "ACE");
+        assert_eq!(st.keys_in('C'..'M').collect::<String>(), "CEHL");
+        assert_eq!(st.keys_in(..='R').collect::<String>(), "ACEHLMPR");
+        assert_eq!(st.keys_in('R'..).collect::<String>(), "RSX");
+        assert_eq!(st.keys_in('C'..'C').collect::<String>(), "");
+        assert_eq!(st.keys_in('M'..'C').collect::<String>(), "");
+
+        // Excluded start bound, which has no range syntax.
+        let r = (Bound::Excluded('E'), Bound::Included('P'));
+        assert_eq!(st.keys_in(r).collect::<String>(), "HLMP");
+        let r = (Bound::Excluded('A'), Bound::Excluded('X'));
+        assert_eq!(st.keys_in(r).collect::<String>(), "CEHLMPRS");
+        let r = (Bound::Excluded('A'), Bound::Unbounded);
+        assert_eq!(st.keys_in(r).rev().collect::<String>(), "XSRPMLHEC");
+    }
+
     #[test]
     fn test_bst_size_range() {
         let empty_st: BST<i32, String> = BST::new();
@@ -858,6 +1458,25 @@ mod tests {
         assert_eq!(st.height(), 5);
     }
 
+    #[test]
+    fn test_bst_map_values() {
+        let empty_st: BST<i32, usize> = BST::new();
+        assert!(empty_st.map_values(|v| v * 2).is_empty());
+
+        let st = prepare_2();
+        let mapped: BST<char, String> = st.map_values(|v| format!("#{v}"));
+        assert_eq!(mapped.size(), st.size());
+        assert_eq!(mapped.height(), st.height());
+        assert_eq!(mapped.keys().collect::<String>(), "ACEHLMPRSX");
+        assert_eq!(
+            mapped.keys_level_order().collect::<String>(),
+            st.keys_level_order().collect::<String>()
+        );
+        assert_eq!(mapped.get(&'X'), Some(&"#7".to_string()));
+        assert_eq!(mapped.get(&'A'), Some(&"#8".to_string()));
+        assert!(mapped.check());
+    }
+
     #[test]
     fn test_bst_keys_level_order() {
         let empty_st: BST<i32, String> = BST::new();
@@ -0,0 +1,130 @@
+use crate::bst::{BST, Keys};
+
+/// An ordered symbol table that allows duplicate keys (a multimap), implemented with a binary
+/// search tree.
+///
+/// Unlike [`BST`], when associating a value with a key that is already in the symbol table, the
+/// new value is appended to the values of the key instead of replacing the old value.  The values
+/// of a key are kept in insertion order.
+///
+/// This implementation uses a [`BST`] whose values are `Vec`s of the values of the keys, so the
+/// `put`, `get_all`, `count`, `contains`, and `delete` operations take time proportional to the
+/// height of the tree, which is &Theta;(<em>n</em>) in the worst case, where <em>n</em> is the
+/// number of distinct keys.
+///
+/// The `size` and `is_empty` operations take &Theta;(1) time.
+///
+/// For additional documentation, see <a href="https://algs4.cs.princeton.edu/32bst">Section
+/// 3.2</a> of <i>Algorithms, 4th Edition</i> by Robert Sedgewick and Kevin Wayne.
+#[derive(Debug)]
+pub struct MultiBST<K, V> {
+    st: BST<K, Vec<V>>,
+    n: usize, // number of key-value pairs
+}
+
+impl<K, V> MultiBST<K, V>
+where
+    K: Ord,
+{
+    /// Initializes an empty symbol table.
+    pub fn new() -> Self {
+        MultiBST {
+            st: BST::new(),
+            n: 0,
+        }
+    }
+
+    /// Returns true if this symbol table is empty, returns false otherwise.
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+
+    /// Returns the number of key-value pairs in this symbol table, counting the duplicate keys.
+    pub fn size(&self) -> usize {
+        self.n
+    }
+
+    /// Returns the number of distinct keys in this symbol table.
+    pub fn size_keys(&self) -> usize {
+        self.st.size()
+    }
+
+    /// Does this symbol table contain the given key?
+    pub fn contains(&self, key: &K) -> bool {
+        self.st.contains(key)
+    }
+
+    /// Inserts the specified key-value pair into the symbol table, keeping the old values of the
+    /// key if any.
+    pub fn put(&mut self, key: K, val: V) {
+        self.st.entry(key).or_insert_with(Vec::new).push(val);
+        self.n += 1;
+    }
+
+    /// Returns all the values associated with the given key in insertion order.
+    pub fn get_all(&self, key: &K) -> &[V] {
+        self.st.get(key).map_or(&[], |vals| vals.as_slice())
+    }
+
+    /// Returns the number of values associated with the given key.
+    pub fn count(&self, key: &K) -> usize {
+        self.get_all(key).len()
+    }
+
+    /// Removes the specified key and all its associated values from this symbol table, returns the
+    /// removed values in insertion order.
+    pub fn delete(&mut self, key: &K) -> Vec<V> {
+        let Some(vals) = self.st.get_mut(key) else {
+            return Vec::new();
+        };
+        let removed = std::mem::take(vals);
+        self.st.delete(key);
+        self.n -= removed.len();
+        removed
+    }
+
+    /// Returns an iterator over the distinct keys in the symbol table in ascending order.
+    pub fn keys(&self) -> Keys<'_, K, Vec<V>> {
+        self.st.keys()
+    }
+}
+
+impl<K, V> Default for MultiBST<K, V>
+where
+    K: Ord,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_multibst() {
+        let mut st = MultiBST::new();
+        assert!(st.is_empty());
+        assert!(st.get_all(&'A').is_empty());
+        for (i, x) in "SEARCHEXAMPLE".chars().enumerate() {
+            st.put(x, i);
+        }
+        assert_eq!(st.size(), 13);
+        assert_eq!(st.size_keys(), 10);
+        assert_eq!(st.keys().collect::<String>(), "ACEHLMPRSX");
+        assert_eq!(st.get_all(&'E'), [1, 6, 12]);
+        assert_eq!(st.get_all(&'A'), [2, 8]);
+        assert_eq!(st.get_all(&'S'), [0]);
+        assert_eq!(st.count(&'E'), 3);
+        assert_eq!(st.count(&'B'), 0);
+        assert!(st.contains(&'X'));
+        assert!(!st.contains(&'B'));
+
+        assert_eq!(st.delete(&'E'), [1, 6, 12]);
+        assert!(st.delete(&'B').is_empty());
+        assert_eq!(st.size(), 10);
+        assert_eq!(st.size_keys(), 9);
+        assert_eq!(st.keys().collect::<String>(), "ACHLMPRSX");
+    }
+}
@@ -0,0 +1,9 @@
+//! Data compression.
+//!
+//! The compression algorithms treat their input as a stream of bits, reading each byte from the
+//! most significant bit to the least significant bit, as `BinaryStdIn` of algs4 does.
+//!
+//! For additional documentation, see <a href="https://algs4.cs.princeton.edu/55compression">Section
+//! 5.5</a> of <i>Algorithms, 4th Edition</i> by Robert Sedgewick and Kevin Wayne.
+
+pub mod run_length;
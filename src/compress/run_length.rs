@@ -0,0 +1,118 @@
+//! Run-length encoding and decoding of bitstreams.
+//!
+//! A bitstream is encoded as the lengths of its alternating runs of 0s and 1s, starting with a run
+//! of 0s, each length stored in 8 bits.  A run longer than 255 is split by a run of length 0 of
+//! the other bit.  For example, the bits `0000 0000 0000 0111 1111 1111 1111 1111` are encoded as
+//! the counts `13, 19`, and a byte `1111 1111` is encoded as `0, 8`.
+//!
+//! It compresses bitmaps with long runs, such as scanned documents, but may double the size of
+//! data with short runs.
+//!
+//! For additional documentation, see <a href="https://algs4.cs.princeton.edu/55compression">Section
+//! 5.5</a> of <i>Algorithms, 4th Edition</i> by Robert Sedgewick and Kevin Wayne.
+
+const R: usize = 256; // maximum run-length count + 1
+const LG_R: usize = 8; // number of bits per count
+
+/// Compresses the bits of `input` into 8-bit run-length counts.
+///
+/// It takes &Theta;(<em>n</em>) time, where <em>n</em> is the number of bits of `input`.
+pub fn compress(input: &[u8]) -> Vec<u8> {
+    let mut output = Vec::new();
+    let mut run: u8 = 0;
+    let mut old = false;
+    for b in input
+        .iter()
+        .flat_map(|&c| (0..8).rev().map(move |i| (c >> i) & 1 == 1))
+    {
+        if b != old {
+            output.push(run);
+            run = 1;
+            old = !old;
+        } else {
+            if run as usize == R - 1 {
+                output.push(run);
+                output.push(0);
+                run = 0;
+            }
+            run += 1;
+        }
+    }
+    output.push(run);
+    output
+}
+
+/// Expands the 8-bit run-length counts of `input` into the bits they encode.
+///
+/// If the number of bits is not a multiple of 8, which never happens for the output of
+/// [`compress`], the last byte is padded with 0s.  It takes &Theta;(<em>n</em>) time, where
+/// <em>n</em> is the number of bits of the output.
+pub fn expand(input: &[u8]) -> Vec<u8> {
+    let mut output = Vec::new();
+    let mut buffer: u8 = 0; // the bits not yet written, from the most significant bit
+    let mut n = 0; // number of bits in buffer
+    let mut b = false;
+    for &run in input {
+        for _ in 0..run {
+            buffer = (buffer << 1) | b as u8;
+            n += 1;
+            if n == LG_R {
+                output.push(buffer);
+                buffer = 0;
+                n = 0;
+            }
+        }
+        b = !b;
+    }
+    if n > 0 {
+        output.push(buffer << (LG_R - n));
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(x: &[u8]) -> Vec<u8> {
+        let compressed = compress(x);
+        assert_eq!(expand(&compressed), x);
+        compressed
+    }
+
+    #[test]
+    fn test_run_length() {
+        // the example of the book, 0 * 15, 1 * 7, 0 * 7, 1 * 11 in 40 bits
+        let bits = [0x00, 0x01, 0xfc, 0x07, 0xff];
+        assert_eq!(round_trip(&bits), [15, 7, 7, 11]);
+
+        // alternating runs
+        assert_eq!(round_trip(&[0x0f, 0x0f]), [4, 4, 4, 4]);
+        assert_eq!(round_trip(&[0x55]), [1; 8]);
+        assert_eq!(round_trip(&[0xaa]), [0, 1, 1, 1, 1, 1, 1, 1, 1]);
+
+        assert_eq!(round_trip(&[]), [0]);
+        assert_eq!(round_trip(&[0x00]), [8]);
+        assert_eq!(round_trip(&[0xff]), [0, 8]);
+    }
+
+    #[test]
+    fn test_run_length_long_runs() {
+        // 1000 zeros: 255 + 255 + 255 + 235 = 1000
+        assert_eq!(round_trip(&[0x00; 125]), [255, 0, 255, 0, 255, 0, 235]);
+        // 255 ones then a zero fit in one run each; 256 ones split into runs of 255, 0, and 1
+        let mut ones = vec![0xff; 32];
+        ones[31] = 0xfe;
+        assert_eq!(round_trip(&ones), [0, 255, 1]);
+        assert_eq!(round_trip(&[0xff; 32]), [0, 255, 0, 1]);
+
+        let random: Vec<u8> = (0..1000).map(|_| rand::random()).collect();
+        round_trip(&random);
+    }
+
+    #[test]
+    fn test_expand_padding() {
+        assert_eq!(expand(&[3, 2]), [0x18]);
+        assert_eq!(expand(&[]), []);
+    }
+}
@@ -0,0 +1,120 @@
+use std::cmp::Ordering;
+use std::fmt;
+
+/// A mutable data type for an integer counter, with a name as its identifier.
+///
+/// The counters are ordered by their tallies, so they can be sorted or kept in a [`crate::MaxPQ`];
+/// counters of equal tallies are ordered by their names, so that the order is consistent with
+/// equality.
+///
+/// For additional documentation, see <a href="https://algs4.cs.princeton.edu/12oop">Section
+/// 1.2</a> of <i>Algorithms, 4th Edition</i> by Robert Sedgewick and Kevin Wayne.
+#[derive(Debug, Clone, Default)]
+pub struct Counter {
+    name: String, // counter name
+    count: usize, // current value
+}
+
+impl Counter {
+    /// Initializes a new counter starting at 0, with the given id.
+    pub fn new(id: &str) -> Self {
+        Counter {
+            name: id.to_string(),
+            count: 0,
+        }
+    }
+
+    /// Increments the counter by 1.
+    pub fn increment(&mut self) {
+        self.count += 1;
+    }
+
+    /// Returns the current value of this counter.
+    pub fn tally(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the id of this counter.
+    pub fn id(&self) -> &str {
+        &self.name
+    }
+}
+
+impl Ord for Counter {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.count
+            .cmp(&other.count)
+            .then_with(|| self.name.cmp(&other.name))
+    }
+}
+
+impl PartialOrd for Counter {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Counter {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Counter {}
+
+impl fmt::Display for Counter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.count, self.name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MaxPQ;
+
+    #[test]
+    fn test_counter() {
+        let mut heads = Counter::new("heads");
+        let mut tails = Counter::new("tails");
+        assert_eq!(heads.tally(), 0);
+        assert_eq!(heads.id(), "heads");
+        for i in 0..10 {
+            if i % 3 == 0 {
+                heads.increment();
+            } else {
+                tails.increment();
+            }
+        }
+        assert_eq!(heads.tally(), 4);
+        assert_eq!(tails.tally(), 6);
+        assert!(heads < tails);
+        assert_eq!(heads.to_string(), "4 heads");
+        assert_eq!(tails.to_string(), "6 tails");
+
+        heads.increment();
+        heads.increment();
+        assert!(heads < tails); // equal tallies are ordered by id
+        assert_ne!(heads, tails);
+        assert_eq!(heads.max(tails.clone()).id(), "tails");
+    }
+
+    #[test]
+    fn test_counter_max_pq() {
+        let mut counters: Vec<_> = ["a", "b", "c"].map(Counter::new).into();
+        for (counter, n) in counters.iter_mut().zip([2, 5, 1]) {
+            for _ in 0..n {
+                counter.increment();
+            }
+        }
+        let mut pq = MaxPQ::new();
+        for counter in counters.iter() {
+            pq.insert(counter.clone());
+        }
+        assert_eq!(pq.del_max().map(|c| c.to_string()).as_deref(), Some("5 b"));
+
+        counters.sort();
+        let ids: Vec<_> = counters.iter().map(Counter::id).collect();
+        assert_eq!(ids, ["c", "a", "b"]);
+    }
+}
@@ -0,0 +1,188 @@
+use crate::bag::linkedbag::LinkedBag as Bag;
+use crate::error::{Algs4Error, InvalidArgument};
+use crate::graph::{read_x, validate_vertex};
+use crate::io::In;
+use std::fmt;
+use std::io::BufRead;
+
+pub(crate) mod cycle;
+pub(crate) mod dfs;
+pub(crate) mod topological;
+
+/// A directed graph.  Parallel edges and self-loops allowed.
+///
+/// The `Digraph` struct represents a directed graph of vertices named `0` through `v - 1`.
+///
+/// It supports the following two primary operations: add a directed edge to the digraph, iterate
+/// over all of the vertices adjacent from a given vertex.  It also provides methods for returning
+/// the indegree or outdegree of a vertex, the number of vertices `v` in the digraph, the number of
+/// edges `e` in the digraph, and the reverse digraph.
+///
+/// This implementation uses an <em>adjacency-lists representation</em>, which is a vertex-indexed
+/// array of `Bag` objects.  It uses &Theta;(`e + v`) space, where `e` is the number of edges and
+/// `v` is the number of vertices.  The `reverse` method takes &Theta;(`e + v`) time and space; all
+/// other instance methods take &Theta;(1) time. (Though, iterating over the vertices returned by
+/// `adj(usize)` takes time proportional to the outdegree of the vertex.)
+///
+/// Constructing an empty digraph with `v` vertices takes &Theta;(`v`) time; constructing a digraph
+/// with `e` edges and `v` vertices takes &Theta;(`e + v`) time.
+///
+/// For additional documentation, see <a href="https://algs4.cs.princeton.edu/42digraph">Section
+/// 4.2</a> of <i>Algorithms, 4th Edition</i> by Robert Sedgewick and Kevin Wayne.
+#[derive(Clone)]
+pub struct Digraph {
+    v: usize,             // number of vertices in this digraph
+    e: usize,             // number of edges in this digraph
+    adj: Vec<Bag<usize>>, // adj[v] = adjacency list for vertex v
+    indegree: Vec<usize>, // indegree[v] = indegree of vertex v
+}
+
+impl Digraph {
+    pub fn new_no_edge(v: usize) -> Self {
+        Digraph {
+            v,
+            e: 0,
+            adj: vec![Bag::new(); v],
+            indegree: vec![0; v],
+        }
+    }
+
+    /// Initializes a digraph from the specified input stream.
+    ///
+    /// The format is the number of vertices `v`, followed by the number of edges `e`, followed by
+    /// `e` pairs of vertices, with each entry separated by whitespace.
+    pub fn new<T: BufRead>(fileinput: &mut In<T>) -> Result<Self, Algs4Error> {
+        let v: usize = read_v(fileinput)?;
+        let e: usize = read_e(fileinput)?;
+        let mut g = Digraph::new_no_edge(v);
+        for _ in 0..e {
+            let v: usize = read_edge_vertex(fileinput)?;
+            let w: usize = read_edge_vertex(fileinput)?;
+            g.add_edge(v, w)?;
+        }
+        Ok(g)
+    }
+
+    /// Adds the directed edge `v->w` to this digraph.
+    pub fn add_edge(&mut self, v: usize, w: usize) -> Result<(), InvalidArgument> {
+        self.validate_vertex(v)?;
+        self.validate_vertex(w)?;
+        self.adj[v].add(w);
+        self.indegree[w] += 1;
+        self.e += 1;
+        Ok(())
+    }
+
+    /// Returns the number of vertices in this digraph.
+    pub fn count_vertices(&self) -> usize {
+        self.v
+    }
+
+    /// Returns the number of edges in this digraph.
+    pub fn count_edges(&self) -> usize {
+        self.e
+    }
+
+    /// Returns the vertices adjacent from vertex `v` in this digraph.
+    pub fn adj(&self, v: usize) -> Result<impl Iterator<Item = &usize>, InvalidArgument> {
+        self.validate_vertex(v)?;
+        Ok(self.adj[v].iter())
+    }
+
+    /// Returns the number of directed edges incident from vertex `v`.  This is known as the
+    /// <em>outdegree</em> of vertex `v`.
+    pub fn outdegree(&self, v: usize) -> Result<usize, InvalidArgument> {
+        self.validate_vertex(v)?;
+        Ok(self.adj[v].len())
+    }
+
+    /// Returns the number of directed edges incident to vertex `v`.  This is known as the
+    /// <em>indegree</em> of vertex `v`.
+    pub fn indegree(&self, v: usize) -> Result<usize, InvalidArgument> {
+        self.validate_vertex(v)?;
+        Ok(self.indegree[v])
+    }
+
+    /// Returns the reverse of the digraph, which has the edge `w->v` for every edge `v->w` of this
+    /// digraph.
+    pub fn reverse(&self) -> Digraph {
+        let mut reverse = Digraph::new_no_edge(self.v);
+        for v in 0..self.v {
+            for &w in self.adj[v].iter() {
+                reverse
+                    .add_edge(w, v)
+                    .expect("w and v should be valid vertices");
+            }
+        }
+        reverse
+    }
+
+    /// Returns a string representation of this digraph in DOT format,
+    /// suitable for visualization with Graphviz.
+    ///
+    /// See [`crate::Graph::to_dot`] for how to render it.
+    pub fn to_dot(&self) -> String {
+        let mut s = String::new();
+        s.push_str("digraph {\n");
+        s.push_str(
+            "node[shape=circle, style=filled, fixedsize=true, width=0.3, fontsize=\"10pt\"]\n",
+        );
+        s.push_str("edge[arrowhead=normal]\n");
+        for v in 0..self.v {
+            for &w in self.adj[v].iter() {
+                s.push_str(&format!("{v} -> {w}\n"));
+            }
+        }
+        s.push_str("}\n");
+        s
+    }
+
+    fn validate_vertex(&self, v: usize) -> Result<(), InvalidArgument> {
+        validate_vertex(v, self.v)
+    }
+}
+
+impl fmt::Debug for Digraph {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut s = format!("{} vertices, {} edges\n", self.v, self.e);
+        for v in 0..self.v {
+            s.push_str(&format!("{}: ", v));
+            for w in self.adj[v].iter() {
+                s.push_str(&w.to_string());
+                s.push(' ');
+            }
+            s.push('\n');
+        }
+        write!(f, "{s}")
+    }
+}
+
+fn read_v<T: BufRead>(fileinput: &mut In<T>) -> Result<usize, Algs4Error> {
+    read_x(
+        fileinput,
+        "number of vertices in a Digraph must be non-negative integer, invalid input format in Digraph constructor",
+        "number of vertices not found in input, invalid input format in Digraph constructor",
+        "I/O error when reading number of vertices, invalid input format in Digraph constructor",
+    )
+}
+
+fn read_e<T: BufRead>(fileinput: &mut In<T>) -> Result<usize, Algs4Error> {
+    read_x(
+        fileinput,
+        "number of edges in a Digraph must be non-negative integer, invalid input format in Digraph constructor",
+        "number of edges not found in input, invalid input format in Digraph constructor",
+        "I/O error when reading number of edges, invalid input format in Digraph constructor",
+    )
+}
+
+fn read_edge_vertex<T: BufRead>(fileinput: &mut In<T>) -> Result<usize, Algs4Error> {
+    read_x(
+        fileinput,
+        "vertex in a Digraph must be non-negative integer, invalid input format in Digraph constructor",
+        "vertex of an edge not found in input, invalid input format in Digraph constructor",
+        "I/O error when reading a vertex of an edge, invalid input format in Digraph constructor",
+    )
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,85 @@
+use crate::digraph::Digraph;
+use crate::stack::resizingstack::ResizingStack as Stack;
+
+/// Determines whether a digraph has a directed cycle and, if so, finds such a cycle, using
+/// depth-first search.
+///
+/// A self-loop `v->v` is the directed cycle `v v`.
+///
+/// The constructor takes &Theta;(<em>V</em> + <em>E</em>) time in the worst case, where
+/// <em>V</em> is the number of vertices and <em>E</em> is the number of edges.
+///
+/// Each instance method takes &Theta;(1) time, not including copying the cycle.
+///
+/// It uses &Theta;(<em>V</em>) extra space (not including the digraph).
+///
+/// For additional documentation, see <a href="https://algs4.cs.princeton.edu/42digraph">Section
+/// 4.2</a> of <i>Algorithms, 4th Edition</i> by Robert Sedgewick and Kevin Wayne.
+pub struct DirectedCycle {
+    marked: Vec<bool>,         // marked[v] = has vertex v been marked?
+    edge_to: Vec<usize>,       // edge_to[v] = previous vertex on path to v
+    on_stack: Vec<bool>,       // on_stack[v] = is vertex on the stack?
+    cycle: Option<Vec<usize>>, // directed cycle (or None if no such cycle)
+}
+
+impl DirectedCycle {
+    /// Determines whether the digraph `g` has a directed cycle and, if so, finds such a cycle.
+    pub fn new(g: &Digraph) -> Self {
+        let v = g.count_vertices();
+        let mut finder = DirectedCycle {
+            marked: vec![false; v],
+            edge_to: vec![0; v],
+            on_stack: vec![false; v],
+            cycle: None,
+        };
+        for s in 0..v {
+            if !finder.marked[s] && finder.cycle.is_none() {
+                finder.dfs(g, s);
+            }
+        }
+        finder
+    }
+
+    // run DFS and find a directed cycle (if one exists)
+    //
+    // Precondition: `v` is a valid vertex
+    fn dfs(&mut self, g: &Digraph, v: usize) {
+        self.on_stack[v] = true;
+        self.marked[v] = true;
+        for &w in g.adj(v).expect("v should be a valid vertex") {
+            // short circuit if directed cycle found
+            if self.cycle.is_some() {
+                return;
+            }
+
+            if !self.marked[w] {
+                // found new vertex, so recur
+                self.edge_to[w] = v;
+                self.dfs(g, w);
+            } else if self.on_stack[w] {
+                // trace back directed cycle
+                let mut cycle = Stack::new();
+                let mut x = v;
+                while x != w {
+                    cycle.push(x);
+                    x = self.edge_to[x];
+                }
+                cycle.push(w);
+                cycle.push(v);
+                self.cycle = Some(cycle.iter().cloned().collect());
+            }
+        }
+        self.on_stack[v] = false;
+    }
+
+    /// Returns true if the digraph has a directed cycle.
+    pub fn has_cycle(&self) -> bool {
+        self.cycle.is_some()
+    }
+
+    /// Returns a directed cycle in the digraph, which starts and ends with the same vertex, or an
+    /// empty vector if the digraph has no directed cycle.
+    pub fn cycle(&self) -> Vec<usize> {
+        self.cycle.clone().unwrap_or_default()
+    }
+}
@@ -0,0 +1,88 @@
+use crate::digraph::Digraph;
+use crate::error::InvalidArgument;
+use crate::graph::validate_vertex;
+use crate::stack::resizingstack::ResizingStack as Stack;
+
+/// Determines the vertices reachable from a given source vertex, or a set of source vertices, in a
+/// digraph, using depth-first search.
+///
+/// With multiple sources, a vertex is reachable if there is a directed path to it from any of the
+/// sources.  For example, the vertices reachable from the roots of a heap are the objects that a
+/// mark-and-sweep garbage collector keeps.
+///
+/// The constructor takes &Theta;(<em>V</em> + <em>E</em>) time in the worst case, where
+/// <em>V</em> is the number of vertices and <em>E</em> is the number of edges.  Each instance
+/// method takes &Theta;(1) time.  It uses &Theta;(<em>V</em>) extra space (not including the
+/// digraph).  The depth-first search uses an explicit stack rather than recursion, so it does not
+/// overflow the call stack on large digraphs.
+///
+/// For additional documentation, see <a href="https://algs4.cs.princeton.edu/42digraph">Section
+/// 4.2</a> of <i>Algorithms, 4th Edition</i> by Robert Sedgewick and Kevin Wayne.
+pub struct DirectedDFS {
+    marked: Vec<bool>, // marked[v] = is there an s->v path?
+    count: usize,      // number of vertices reachable from source(s)
+}
+
+impl DirectedDFS {
+    /// Computes the vertices in the digraph `g` that are reachable from the source vertex `s`.
+    pub fn new(g: &Digraph, s: usize) -> Result<Self, InvalidArgument> {
+        DirectedDFS::new_multiple_sources(g, [s])
+    }
+
+    /// Computes the vertices in the digraph `g` that are reachable from any of the source
+    /// vertices `sources`.
+    ///
+    /// It returns an error if there is no source vertex, or if any source vertex is invalid.
+    pub fn new_multiple_sources<I>(g: &Digraph, sources: I) -> Result<Self, InvalidArgument>
+    where
+        I: IntoIterator<Item = usize>,
+    {
+        let v = g.count_vertices();
+        let sources: Vec<usize> = sources.into_iter().collect();
+        if sources.is_empty() {
+            return Err(InvalidArgument("zero vertices".to_string()));
+        }
+        for &s in &sources {
+            validate_vertex(s, v)?;
+        }
+        let mut dfs = DirectedDFS {
+            marked: vec![false; v],
+            count: 0,
+        };
+        dfs.dfs(g, &sources);
+        Ok(dfs)
+    }
+
+    // Precondition: the sources are valid vertices
+    fn dfs(&mut self, g: &Digraph, sources: &[usize]) {
+        let mut stack = Stack::new();
+        for &s in sources {
+            if !self.marked[s] {
+                self.marked[s] = true;
+                self.count += 1;
+                stack.push(s);
+            }
+        }
+        while let Some(v) = stack.pop() {
+            for &w in g.adj(v).expect("v should be a valid vertex") {
+                if !self.marked[w] {
+                    self.marked[w] = true;
+                    self.count += 1;
+                    stack.push(w);
+                }
+            }
+        }
+    }
+
+    /// Is there a directed path from the source vertex (or any of the source vertices) to vertex
+    /// `v`?
+    pub fn reachable(&self, v: usize) -> Result<bool, InvalidArgument> {
+        validate_vertex(v, self.marked.len())?;
+        Ok(self.marked[v])
+    }
+
+    /// Returns the number of vertices reachable from the source vertex (or source vertices).
+    pub fn count(&self) -> usize {
+        self.count
+    }
+}
@@ -0,0 +1,223 @@
+use super::Digraph;
+use super::cycle::DirectedCycle;
+use super::dfs::DirectedDFS;
+use super::topological::Topological;
+use crate::io::In;
+use std::collections::HashSet;
+
+macro_rules! cmp_adj {
+    ($a:expr, $b:expr) => {
+        assert_eq!($a.cloned().collect::<HashSet<usize>>(), HashSet::from($b));
+    };
+}
+
+const TINY_DG: &str = "13
+22
+ 4  2
+ 2  3
+ 3  2
+ 6  0
+ 0  1
+ 2  0
+11 12
+12  9
+ 9 10
+ 9 11
+ 7  9
+10 12
+11  4
+ 4  3
+ 3  5
+ 6  8
+ 8  6
+ 5  4
+ 0  5
+ 6  4
+ 6  9
+ 7  6
+";
+
+fn tiny_digraph() -> Digraph {
+    let mut input = In::from(TINY_DG);
+    Digraph::new(&mut input).unwrap()
+}
+
+#[test]
+fn digraph_basics() {
+    let g = tiny_digraph();
+    assert_eq!(g.count_vertices(), 13);
+    assert_eq!(g.count_edges(), 22);
+    cmp_adj!(g.adj(0).unwrap(), [5, 1]);
+    cmp_adj!(g.adj(1).unwrap(), []);
+    cmp_adj!(g.adj(2).unwrap(), [0, 3]);
+    cmp_adj!(g.adj(3).unwrap(), [5, 2]);
+    cmp_adj!(g.adj(4).unwrap(), [3, 2]);
+    cmp_adj!(g.adj(6).unwrap(), [9, 4, 8, 0]);
+    cmp_adj!(g.adj(7).unwrap(), [6, 9]);
+    cmp_adj!(g.adj(12).unwrap(), [9]);
+    assert_eq!(g.outdegree(6).unwrap(), 4);
+    assert_eq!(g.indegree(6).unwrap(), 2);
+    assert_eq!(g.outdegree(1).unwrap(), 0);
+    assert_eq!(g.indegree(1).unwrap(), 1);
+    assert!(g.adj(13).is_err());
+    assert!(g.outdegree(13).is_err());
+
+    let sum_out: usize = (0..13).map(|v| g.outdegree(v).unwrap()).sum();
+    let sum_in: usize = (0..13).map(|v| g.indegree(v).unwrap()).sum();
+    assert_eq!(sum_out, 22);
+    assert_eq!(sum_in, 22);
+
+    let dot = g.to_dot();
+    assert!(dot.starts_with("digraph {\n"));
+    assert!(dot.contains("7 -> 6\n"));
+    assert!(!dot.contains("6 -> 7\n"));
+    assert!(format!("{:?}", g).starts_with("13 vertices, 22 edges\n"));
+}
+
+#[test]
+fn digraph_reverse() {
+    let g = tiny_digraph();
+    let r = g.reverse();
+    assert_eq!(r.count_vertices(), g.count_vertices());
+    assert_eq!(r.count_edges(), g.count_edges());
+    for v in 0..g.count_vertices() {
+        assert_eq!(r.outdegree(v).unwrap(), g.indegree(v).unwrap());
+        assert_eq!(r.indegree(v).unwrap(), g.outdegree(v).unwrap());
+        for &w in g.adj(v).unwrap() {
+            assert!(r.adj(w).unwrap().any(|&x| x == v));
+        }
+    }
+    cmp_adj!(r.adj(6).unwrap(), [8, 7]);
+    cmp_adj!(r.adj(1).unwrap(), [0]);
+}
+
+#[test]
+fn digraph_new_error() {
+    let mut input = In::from("3 2 0 1 1");
+    assert!(Digraph::new(&mut input).is_err());
+    let mut input = In::from("3 1 0 3");
+    assert!(Digraph::new(&mut input).is_err());
+    let mut input = In::from("-3 0");
+    let err = Digraph::new(&mut input).err().unwrap();
+    assert!(err.to_string().contains("Digraph"));
+}
+
+// A DAG of the course prerequisites, like `tinyDAG.txt`.
+fn tiny_dag() -> Digraph {
+    let mut g = Digraph::new_no_edge(13);
+    for (v, w) in [
+        (2, 3),
+        (0, 6),
+        (0, 1),
+        (2, 0),
+        (11, 12),
+        (9, 12),
+        (9, 10),
+        (9, 11),
+        (3, 5),
+        (8, 7),
+        (5, 4),
+        (0, 5),
+        (6, 4),
+        (6, 9),
+        (7, 6),
+    ] {
+        g.add_edge(v, w).unwrap();
+    }
+    g
+}
+
+#[test]
+fn directed_cycle() {
+    let g = tiny_digraph();
+    let finder = DirectedCycle::new(&g);
+    assert!(finder.has_cycle());
+    let cycle = finder.cycle();
+    assert!(cycle.len() >= 2);
+    assert_eq!(cycle.first(), cycle.last());
+    for pair in cycle.windows(2) {
+        assert!(g.adj(pair[0]).unwrap().any(|&w| w == pair[1]));
+    }
+
+    let finder = DirectedCycle::new(&tiny_dag());
+    assert!(!finder.has_cycle());
+    assert!(finder.cycle().is_empty());
+
+    let mut g = Digraph::new_no_edge(2);
+    g.add_edge(0, 1).unwrap();
+    g.add_edge(1, 1).unwrap();
+    assert_eq!(DirectedCycle::new(&g).cycle(), [1, 1]);
+}
+
+#[test]
+fn topological() {
+    let g = tiny_dag();
+    let topological = Topological::new(&g);
+    assert!(topological.has_order());
+    let order = topological.order().unwrap();
+    assert_eq!(order.len(), 13);
+    assert_eq!(order.iter().cloned().collect::<HashSet<_>>().len(), 13);
+    for (i, &v) in order.iter().enumerate() {
+        assert_eq!(topological.rank(v).unwrap(), Some(i));
+    }
+    for v in 0..g.count_vertices() {
+        for &w in g.adj(v).unwrap() {
+            assert!(topological.rank(v).unwrap() < topological.rank(w).unwrap());
+        }
+    }
+    assert!(topological.rank(13).is_err());
+
+    // adding a back edge makes a cycle
+    let mut g = g;
+    g.add_edge(4, 2).unwrap();
+    let topological = Topological::new(&g);
+    assert!(!topological.has_order());
+    assert_eq!(topological.order(), None);
+    assert_eq!(topological.rank(0).unwrap(), None);
+}
+
+fn reachable_set(dfs: &DirectedDFS, v: usize) -> HashSet<usize> {
+    (0..v).filter(|&w| dfs.reachable(w).unwrap()).collect()
+}
+
+#[test]
+fn directed_dfs() {
+    let g = tiny_digraph();
+    let dfs = DirectedDFS::new(&g, 1).unwrap();
+    assert_eq!(reachable_set(&dfs, 13), HashSet::from([1]));
+    let dfs = DirectedDFS::new(&g, 2).unwrap();
+    assert_eq!(reachable_set(&dfs, 13), HashSet::from([0, 1, 2, 3, 4, 5]));
+    assert_eq!(dfs.count(), 6);
+
+    // the example of the book
+    let dfs = DirectedDFS::new_multiple_sources(&g, [1, 2, 6]).unwrap();
+    assert_eq!(
+        reachable_set(&dfs, 13),
+        HashSet::from([0, 1, 2, 3, 4, 5, 6, 8, 9, 10, 11, 12])
+    );
+    assert_eq!(dfs.count(), 12);
+    assert!(dfs.reachable(13).is_err());
+
+    assert!(DirectedDFS::new(&g, 13).is_err());
+    assert!(DirectedDFS::new_multiple_sources(&g, [1, 13]).is_err());
+    assert!(DirectedDFS::new_multiple_sources(&g, []).is_err());
+}
+
+#[test]
+fn directed_dfs_two_roots() {
+    // two roots of a heap: 0 -> 1 -> 2 -> 4 and 3 -> 4, with the garbage 5 -> 6 and 7
+    let mut g = Digraph::new_no_edge(8);
+    for (v, w) in [(0, 1), (1, 2), (2, 4), (3, 4), (5, 6), (6, 2)] {
+        g.add_edge(v, w).unwrap();
+    }
+    let from_0 = reachable_set(&DirectedDFS::new(&g, 0).unwrap(), 8);
+    let from_3 = reachable_set(&DirectedDFS::new(&g, 3).unwrap(), 8);
+    assert_eq!(from_0, HashSet::from([0, 1, 2, 4]));
+    assert_eq!(from_3, HashSet::from([3, 4]));
+
+    let dfs = DirectedDFS::new_multiple_sources(&g, [0, 3]).unwrap();
+    let union: HashSet<usize> = from_0.union(&from_3).copied().collect();
+    assert_eq!(reachable_set(&dfs, 8), union);
+    assert_eq!(dfs.count(), 5);
+    assert!(!dfs.reachable(5).unwrap() && !dfs.reachable(7).unwrap());
+}
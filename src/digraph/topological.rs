@@ -0,0 +1,99 @@
+use crate::digraph::Digraph;
+use crate::digraph::cycle::DirectedCycle;
+use crate::error::InvalidArgument;
+use crate::graph::validate_vertex;
+
+/// Determines whether a digraph has a topological order and, if so, finds such a topological
+/// order.
+///
+/// A digraph has a topological order if and only if it is a DAG (directed acyclic graph).  The
+/// topological order is the reverse postorder of a depth-first search, in which every edge
+/// `v->w` goes from an earlier vertex `v` to a later vertex `w`.
+///
+/// The constructor takes &Theta;(<em>V</em> + <em>E</em>) time in the worst case, where
+/// <em>V</em> is the number of vertices and <em>E</em> is the number of edges.
+///
+/// The `rank` and `has_order` methods take &Theta;(1) time; the `order` method takes
+/// &Theta;(<em>V</em>) time to copy the order.
+///
+/// It uses &Theta;(<em>V</em>) extra space (not including the digraph).
+///
+/// For additional documentation, see <a href="https://algs4.cs.princeton.edu/42digraph">Section
+/// 4.2</a> of <i>Algorithms, 4th Edition</i> by Robert Sedgewick and Kevin Wayne.
+pub struct Topological {
+    order: Option<Vec<usize>>, // topological order
+    rank: Vec<usize>,          // rank[v] = rank of vertex v in order
+}
+
+impl Topological {
+    /// Determines whether the digraph `g` has a topological order and, if so, finds such a
+    /// topological order.
+    pub fn new(g: &Digraph) -> Self {
+        let v = g.count_vertices();
+        let mut topological = Topological {
+            order: None,
+            rank: vec![0; v],
+        };
+        if DirectedCycle::new(g).has_cycle() {
+            return topological;
+        }
+
+        let mut marked = vec![false; v];
+        let mut postorder = Vec::with_capacity(v);
+        for s in 0..v {
+            if !marked[s] {
+                dfs(g, s, &mut marked, &mut postorder);
+            }
+        }
+        postorder.reverse();
+        for (i, &v) in postorder.iter().enumerate() {
+            topological.rank[v] = i;
+        }
+        topological.order = Some(postorder);
+        debug_assert!(topological.check(g));
+        topological
+    }
+
+    /// Returns true if the digraph has a topological order, that is, the digraph is a DAG.
+    pub fn has_order(&self) -> bool {
+        self.order.is_some()
+    }
+
+    /// Returns a topological order if the digraph has a topological order, or `None` otherwise.
+    pub fn order(&self) -> Option<Vec<usize>> {
+        self.order.clone()
+    }
+
+    /// Returns the rank of vertex `v` in the topological order, or `None` if the digraph is not a
+    /// DAG.
+    pub fn rank(&self, v: usize) -> Result<Option<usize>, InvalidArgument> {
+        validate_vertex(v, self.rank.len())?;
+        Ok(self.order.as_ref().map(|_| self.rank[v]))
+    }
+
+    // Check that every edge goes forward in the order.
+    fn check(&self, g: &Digraph) -> bool {
+        for v in 0..g.count_vertices() {
+            for &w in g.adj(v).expect("v should be a valid vertex") {
+                if self.rank[v] >= self.rank[w] {
+                    eprintln!("edge {}->{} goes backward in the order", v, w);
+                    return false;
+                }
+            }
+        }
+        true
+    }
+}
+
+// run DFS from `v`, appending the vertices to `postorder` when they are finished
+//
+// Precondition: `v` is a valid vertex
+fn dfs(g: &Digraph, v: usize, marked: &mut [bool], postorder: &mut Vec<usize>) {
+    marked[v] = true;
+    for &w in g.adj(v).expect("v should be a valid vertex") {
+        if !marked[w] {
+            dfs(g, w, marked, postorder);
+        }
+    }
+    postorder.push(v);
+}
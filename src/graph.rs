@@ -4,7 +4,16 @@ use crate::io::In;
 use std::fmt;
 use std::io::{BufRead, ErrorKind};
 
+pub(crate) mod bipartite;
+pub(crate) mod bridges;
+pub(crate) mod cc;
+pub(crate) mod cycle;
+pub(crate) mod edge_weighted_graph;
+pub mod generator;
+pub(crate) mod mst;
 pub(crate) mod path;
+pub(crate) mod stats;
+pub(crate) mod symbol_graph;
 
 /// An undirected graph.  Parallel edges and self-loops allowed.
 ///
@@ -13,14 +22,16 @@ pub(crate) mod path;
 ///
 /// It supports the following two primary operations: add an edge to the graph, iterate over all of
 /// the vertices adjacent to a vertex.  It also provides methods for returning the degree of a
-/// vertex, the number of vertices `v` in the graph, and the number of edges `e` in the graph.
+/// vertex, the number of vertices `v` in the graph, and the number of edges `e` in the graph, and
+/// methods for testing and removing an edge.
 ///
 /// Parallel edges and self-loops are permitted.  By convention, a self-loop `v-v` appears in the
 /// adjacency list of `v` twice and contributes two to the degree of `v`.
 ///
 /// This implementation uses an <em>adjacency-lists representation</em>, which is a vertex-indexed
 /// array of `Bag` objects.  It uses &Theta;(`e + v`) space, where `e` is the number of edges and
-/// `v` is the number of vertices.  All instance methods take &Theta;(1) time. (Though, iterating
+/// `v` is the number of vertices.  All instance methods take &Theta;(1) time, except `has_edge` and
+/// `remove_edge`, which take time proportional to the degrees of the vertices. (Though, iterating
 /// over the vertices returned by `adj(usize)` takes time proportional to the degree of the vertex.)
 ///
 /// Constructing an empty graph with `v` vertices takes &Theta;(`v`) time; constructing a graph with
@@ -71,6 +82,30 @@ impl Graph {
         Ok(())
     }
 
+    /// Returns true if this graph has the edge `v-w`.
+    pub fn has_edge(&self, v: usize, w: usize) -> Result<bool, InvalidArgument> {
+        self.validate_vertex(v)?;
+        self.validate_vertex(w)?;
+        Ok(self.adj[v].iter().any(|&x| x == w))
+    }
+
+    /// Removes one occurrence of the undirected edge `v-w` from this graph.  Returns true if the
+    /// edge was in this graph, returns false otherwise.
+    ///
+    /// It takes time proportional to the degrees of `v` and `w`.
+    pub fn remove_edge(&mut self, v: usize, w: usize) -> Result<bool, InvalidArgument> {
+        self.validate_vertex(v)?;
+        self.validate_vertex(w)?;
+        if !self.adj[v].remove(&w) {
+            return Ok(false);
+        }
+        // a self-loop `v-v` appears in the adjacency list of `v` twice
+        let removed = self.adj[w].remove(&v);
+        debug_assert!(removed);
+        self.e -= 1;
+        Ok(true)
+    }
+
     /// Returns the number of vertices in this graph.
     pub fn count_vertices(&self) -> usize {
         self.v
@@ -93,6 +128,59 @@ impl Graph {
         Ok(self.adj[v].len())
     }
 
+    /// Returns the maximum degree of this graph, or `0` if it has no vertices.
+    pub fn max_degree(&self) -> usize {
+        self.adj.iter().map(|bag| bag.len()).max().unwrap_or(0)
+    }
+
+    /// Returns the average degree of this graph, which is `2e / v`, or `0.0` if it has no
+    /// vertices.
+    pub fn average_degree(&self) -> f64 {
+        if self.v == 0 {
+            0.0
+        } else {
+            2.0 * self.e as f64 / self.v as f64
+        }
+    }
+
+    /// Returns the number of self-loops of this graph.  Each self-loop `v-v` is counted once,
+    /// although it appears in the adjacency list of `v` twice.
+    pub fn number_of_self_loops(&self) -> usize {
+        let mut count = 0;
+        for v in 0..self.v {
+            for &w in self.adj[v].iter() {
+                if v == w {
+                    count += 1;
+                }
+            }
+        }
+        count / 2
+    }
+
+    /// Returns a string representation of this graph in the input format of [`Graph::new`]: the
+    /// number of vertices, the number of edges, and then the edges `v w`, one per line.
+    ///
+    /// Each edge `v-w` appears once, with `v <= w`, so reading the string with [`Graph::new`]
+    /// results in a graph with the same edges.
+    pub fn to_input_format(&self) -> String {
+        let mut s = format!("{}\n{}\n", self.v, self.e);
+        let mut self_loops: usize = 0;
+        for v in 0..self.v {
+            for &w in self.adj[v].iter() {
+                if v < w {
+                    s.push_str(&format!("{v} {w}\n"));
+                } else if v == w {
+                    // include only one copy of each self loop (self loops will be consecutive)
+                    if self_loops.is_multiple_of(2) {
+                        s.push_str(&format!("{v} {w}\n"));
+                    }
+                    self_loops += 1;
+                }
+            }
+        }
+        s
+    }
+
     /// Returns a string representation of this graph in DOT format,
     /// suitable for visualization with Graphviz.
     ///
@@ -124,7 +212,7 @@ impl Graph {
                     s.push_str(&format!("{v} -- {w}\n"));
                 } else if v == w {
                     // include only one copy of each self loop (self loops will be consecutive)
-                    if self_loops % 2 == 0 {
+                    if self_loops.is_multiple_of(2) {
                         s.push_str(&format!("{v} -- {w}\n"));
                     }
                     self_loops += 1;
@@ -163,7 +251,21 @@ impl fmt::Debug for Graph {
     }
 }
 
-fn read_x<T: BufRead>(
+// Validates the vertex `s` of a graph with `count_vertices` vertices, shared by the graph
+// processing modules.
+pub(crate) fn validate_vertex(s: usize, count_vertices: usize) -> Result<(), InvalidArgument> {
+    if s >= count_vertices {
+        Err(InvalidArgument(format!(
+            "vertex {} is not between 0 and {}",
+            s,
+            count_vertices - 1
+        )))
+    } else {
+        Ok(())
+    }
+}
+
+pub(crate) fn read_x<T: BufRead>(
     fileinput: &mut In<T>,
     invalid_arg_err_msg: &'static str,
     not_found_err_msg: &'static str,
@@ -172,9 +274,9 @@ fn read_x<T: BufRead>(
     match fileinput.read_int() {
         Ok(x) => Ok(x),
         Err(e) => match e.kind() {
-            ErrorKind::InvalidData => {
-                Err(Algs4Error::InvalidArgument(invalid_arg_err_msg.to_string()))
-            }
+            ErrorKind::InvalidData => Err(Algs4Error::InvalidArgument(format!(
+                "{invalid_arg_err_msg}: {e}"
+            ))),
             ErrorKind::NotFound => Err(Algs4Error::InvalidArgument(not_found_err_msg.to_string())),
             _ => Err(Algs4Error::InvalidArgument(io_err_msg.to_string())),
         },
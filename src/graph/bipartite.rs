@@ -0,0 +1,99 @@
+use crate::error::InvalidArgument;
+use crate::graph::{Graph, validate_vertex};
+use crate::stack::resizingstack::ResizingStack as Stack;
+
+/// Determines whether an undirected graph is <em>bipartite</em> and finds either a bipartition or
+/// an odd-length cycle, using depth-first search.
+///
+/// A graph is bipartite if and only if it has no odd-length cycle.  A self-loop is an odd cycle
+/// `v v`, so a graph with a self-loop is not bipartite.
+///
+/// The constructor takes &Theta;(<em>V</em> + <em>E</em>) time in the worst case, where <em>V</em>
+/// is the number of vertices and <em>E</em> is the number of edges.
+///
+/// Each instance method takes &Theta;(1) time, not including copying the odd cycle.
+///
+/// It uses &Theta;(<em>V</em>) extra space (not including the graph).
+///
+/// For additional documentation, see <a href="https://algs4.cs.princeton.edu/41graph">Section
+/// 4.1</a> of <i>Algorithms, 4th Edition</i> by Robert Sedgewick and Kevin Wayne.
+pub struct Bipartite {
+    is_bipartite: bool,        // is the graph bipartite?
+    color: Vec<bool>,          // color[v] gives vertices on one side of bipartition
+    marked: Vec<bool>,         // marked[v] = true iff v has been visited in DFS
+    edge_to: Vec<usize>,       // edge_to[v] = last edge on path to v
+    cycle: Option<Vec<usize>>, // odd-length cycle
+}
+
+impl Bipartite {
+    /// Determines whether the undirected graph `g` is bipartite.
+    pub fn new(g: &Graph) -> Self {
+        let v = g.count_vertices();
+        let mut b = Bipartite {
+            is_bipartite: true,
+            color: vec![false; v],
+            marked: vec![false; v],
+            edge_to: vec![0; v],
+            cycle: None,
+        };
+        for s in 0..v {
+            if !b.marked[s] {
+                b.dfs(g, s);
+            }
+        }
+        b
+    }
+
+    // Precondition: `v` is a valid vertex
+    fn dfs(&mut self, g: &Graph, v: usize) {
+        self.marked[v] = true;
+        for &w in g.adj(v).expect("v should be a valid vertex") {
+            // short circuit if odd-length cycle found
+            if self.cycle.is_some() {
+                return;
+            }
+
+            if !self.marked[w] {
+                // found uncolored vertex, so recur
+                self.edge_to[w] = v;
+                self.color[w] = !self.color[v];
+                self.dfs(g, w);
+            } else if self.color[w] == self.color[v] {
+                // if v-w create an odd-length cycle, find it
+                self.is_bipartite = false;
+                let mut cycle = Stack::new();
+                cycle.push(w); // include the start vertex twice
+                let mut x = v;
+                while x != w {
+                    cycle.push(x);
+                    x = self.edge_to[x];
+                }
+                cycle.push(w);
+                self.cycle = Some(cycle.iter().cloned().collect());
+            }
+        }
+    }
+
+    /// Returns true if the graph is bipartite.
+    pub fn is_bipartite(&self) -> bool {
+        self.is_bipartite
+    }
+
+    /// Returns the side of the bipartition that vertex `v` is on.  Two vertices are on the same
+    /// side if and only if they have the same color.
+    ///
+    /// It returns an error if `v` is not a valid vertex, or if the graph is not bipartite.
+    pub fn color(&self, v: usize) -> Result<bool, InvalidArgument> {
+        validate_vertex(v, self.marked.len())?;
+        if !self.is_bipartite {
+            return Err(InvalidArgument("graph is not bipartite".to_string()));
+        }
+        Ok(self.color[v])
+    }
+
+    /// Returns an odd-length cycle if the graph is not bipartite, or an empty vector otherwise.
+    /// The cycle starts and ends with the same vertex.
+    pub fn odd_cycle(&self) -> Vec<usize> {
+        self.cycle.clone().unwrap_or_default()
+    }
+}
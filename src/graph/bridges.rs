@@ -0,0 +1,186 @@
+use crate::error::InvalidArgument;
+use crate::graph::{Graph, validate_vertex};
+use crate::stack::resizingstack::ResizingStack as Stack;
+
+/// Identifies the bridges in an undirected graph, and computes its 2-edge-connected components.
+///
+/// A <em>bridge</em> (or cut-edge) is an edge whose deletion increases the number of connected
+/// components.  An edge is a bridge if and only if it is not contained in any cycle.  A
+/// <em>2-edge-connected component</em> is a maximal set of vertices that stay connected after
+/// deleting any one edge, so the bridges are exactly the edges between them.
+///
+/// Parallel edges are never bridges, because they form a cycle of length 2.
+///
+/// This implementation uses depth-first search with low-link values, as in Tarjan's algorithm.
+/// The constructor takes &Theta;(<em>V</em> + <em>E</em>) time in the worst case, where
+/// <em>V</em> is the number of vertices and <em>E</em> is the number of edges.  It uses
+/// &Theta;(<em>V</em>) extra space (not including the graph).  The depth-first search uses an
+/// explicit stack rather than recursion, so it does not overflow the call stack on large graphs.
+///
+/// For additional documentation, see <a href="https://algs4.cs.princeton.edu/41graph">Section
+/// 4.1</a> of <i>Algorithms, 4th Edition</i> by Robert Sedgewick and Kevin Wayne.
+pub struct Bridge {
+    bridges: Vec<(usize, usize)>, // the bridges v-w, where v is the parent of w in the DFS tree
+    components: usize,            // number of 2-edge-connected components
+}
+
+impl Bridge {
+    /// Computes the bridges of the undirected graph `g`.
+    pub fn new(g: &Graph) -> Self {
+        let low_link = LowLink::new(g);
+        Bridge {
+            components: low_link.connected_components + low_link.bridges.len(),
+            bridges: low_link.bridges,
+        }
+    }
+
+    /// Returns the number of 2-edge-connected components, which is the number of connected
+    /// components plus the number of bridges.
+    pub fn components(&self) -> usize {
+        self.components
+    }
+
+    /// Returns the number of bridges.
+    pub fn count(&self) -> usize {
+        self.bridges.len()
+    }
+
+    /// Returns the bridges `(v, w)`, each once, in the order the depth-first search finds them.
+    pub fn bridges(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.bridges.iter().copied()
+    }
+}
+
+/// Identifies the articulation points in an undirected graph.
+///
+/// An <em>articulation point</em> (or cut vertex) is a vertex whose removal increases the number
+/// of connected components.  A graph without articulation points, with at least 3 vertices, is
+/// <em>biconnected</em>.
+///
+/// This implementation uses depth-first search with low-link values, as in Tarjan's algorithm.
+/// The constructor takes &Theta;(<em>V</em> + <em>E</em>) time in the worst case, where
+/// <em>V</em> is the number of vertices and <em>E</em> is the number of edges.  Each instance
+/// method takes &Theta;(1) time.  It uses &Theta;(<em>V</em>) extra space (not including the
+/// graph).
+///
+/// For additional documentation, see <a href="https://algs4.cs.princeton.edu/41graph">Section
+/// 4.1</a> of <i>Algorithms, 4th Edition</i> by Robert Sedgewick and Kevin Wayne.
+pub struct ArticulationPoints {
+    articulation: Vec<bool>, // articulation[v] = is v an articulation point?
+    count: usize,            // number of articulation points
+}
+
+impl ArticulationPoints {
+    /// Computes the articulation points of the undirected graph `g`.
+    pub fn new(g: &Graph) -> Self {
+        let articulation = LowLink::new(g).articulation;
+        let count = articulation.iter().filter(|&&a| a).count();
+        ArticulationPoints {
+            articulation,
+            count,
+        }
+    }
+
+    /// Is vertex `v` an articulation point?
+    pub fn is_articulation(&self, v: usize) -> Result<bool, InvalidArgument> {
+        validate_vertex(v, self.articulation.len())?;
+        Ok(self.articulation[v])
+    }
+
+    /// Returns the number of articulation points.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the articulation points in increasing order.
+    pub fn articulation_points(&self) -> impl Iterator<Item = usize> + '_ {
+        self.articulation
+            .iter()
+            .enumerate()
+            .filter_map(|(v, &a)| a.then_some(v))
+    }
+}
+
+// The results of a depth-first search that computes, for each vertex v, the preorder number
+// pre[v], and the low-link value low[v], which is the lowest preorder number reachable from v by
+// tree edges down and at most one back edge up.
+//
+// A tree edge v-w is a bridge if and only if low[w] > pre[v].  A non-root vertex v is an
+// articulation point if and only if it has a child w with low[w] >= pre[v], and a root is an
+// articulation point if and only if it has at least two children.
+struct LowLink {
+    bridges: Vec<(usize, usize)>,
+    articulation: Vec<bool>,
+    connected_components: usize,
+}
+
+impl LowLink {
+    fn new(g: &Graph) -> Self {
+        let v = g.count_vertices();
+        let mut pre: Vec<Option<usize>> = vec![None; v];
+        let mut low = vec![0; v];
+        let mut parent: Vec<Option<usize>> = vec![None; v];
+        // whether one edge to the parent has been skipped, so that a parallel edge counts
+        let mut skipped_parent = vec![false; v];
+        let mut low_link = LowLink {
+            bridges: Vec::new(),
+            articulation: vec![false; v],
+            connected_components: 0,
+        };
+
+        // to be able to iterate over each adjacency list, keeping track of which
+        // vertex in each adjacency list needs to be explored next
+        let mut adj: Vec<_> = (0..v)
+            .map(|v| g.adj(v).expect("v should be a valid vertex"))
+            .collect();
+        let mut counter = 0;
+        let mut stack = Stack::new();
+        for s in 0..v {
+            if pre[s].is_some() {
+                continue;
+            }
+            low_link.connected_components += 1;
+            pre[s] = Some(counter);
+            low[s] = counter;
+            counter += 1;
+            stack.push(s);
+            let mut root_children = 0;
+            while let Some(&x) = stack.peek() {
+                match adj[x].next() {
+                    Some(&w) => match pre[w] {
+                        None => {
+                            // tree edge x-w
+                            parent[w] = Some(x);
+                            pre[w] = Some(counter);
+                            low[w] = counter;
+                            counter += 1;
+                            stack.push(w);
+                        }
+                        Some(_) if parent[x] == Some(w) && !skipped_parent[x] => {
+                            skipped_parent[x] = true;
+                        }
+                        Some(pre_w) => low[x] = low[x].min(pre_w), // back edge
+                    },
+                    None => {
+                        stack.pop();
+                        let Some(p) = parent[x] else { continue };
+                        let pre_p = pre[p].expect("parent should be visited");
+                        low[p] = low[p].min(low[x]);
+                        if low[x] > pre_p {
+                            low_link.bridges.push((p, x));
+                        }
+                        if parent[p].is_none() {
+                            root_children += 1;
+                        } else if low[x] >= pre_p {
+                            low_link.articulation[p] = true;
+                        }
+                    }
+                }
+            }
+            if root_children >= 2 {
+                low_link.articulation[s] = true;
+            }
+        }
+        low_link
+    }
+}
@@ -0,0 +1,89 @@
+use crate::error::InvalidArgument;
+use crate::graph::{Graph, validate_vertex};
+
+/// Computes the connected components of an undirected graph, using depth-first search.
+///
+/// Each vertex gets a component identifier between `0` and `count() - 1`, and two vertices are in
+/// the same connected component if and only if they have the same identifier.
+///
+/// The constructor takes &Theta;(<em>V</em> + <em>E</em>) time in the worst case, where <em>V</em>
+/// is the number of vertices and <em>E</em> is the number of edges.
+///
+/// Each instance method takes &Theta;(1) time.
+///
+/// It uses &Theta;(<em>V</em>) extra space (not including the graph).
+///
+/// For additional documentation, see <a href="https://algs4.cs.princeton.edu/41graph">Section
+/// 4.1</a> of <i>Algorithms, 4th Edition</i> by Robert Sedgewick and Kevin Wayne.
+pub struct ConnectedComponents {
+    marked: Vec<bool>, // marked[v] = has vertex v been marked?
+    id: Vec<usize>,    // id[v] = id of connected component containing v
+    size: Vec<usize>,  // size[id] = number of vertices in given component
+    count: usize,      // number of connected components
+}
+
+impl ConnectedComponents {
+    /// Computes the connected components of the undirected graph `g`.
+    pub fn new(g: &Graph) -> Self {
+        let v = g.count_vertices();
+        let mut cc = ConnectedComponents {
+            marked: vec![false; v],
+            id: vec![0; v],
+            size: Vec::new(),
+            count: 0,
+        };
+        for s in 0..v {
+            if !cc.marked[s] {
+                cc.size.push(0);
+                cc.dfs(g, s);
+                cc.count += 1;
+            }
+        }
+        cc
+    }
+
+    // depth-first search for a Graph
+    //
+    // Precondition: `v` is a valid vertex
+    fn dfs(&mut self, g: &Graph, v: usize) {
+        self.marked[v] = true;
+        self.id[v] = self.count;
+        self.size[self.count] += 1;
+        for &w in g.adj(v).expect("v should be a valid vertex") {
+            if !self.marked[w] {
+                self.dfs(g, w);
+            }
+        }
+    }
+
+    /// Returns the number of connected components in the graph.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the component id of the connected component containing vertex `v`.
+    pub fn id(&self, v: usize) -> Result<usize, InvalidArgument> {
+        self.validate_vertex(v)?;
+        Ok(self.id[v])
+    }
+
+    /// Returns true if vertices `v` and `w` are in the same connected component.
+    pub fn connected(&self, v: usize, w: usize) -> Result<bool, InvalidArgument> {
+        Ok(self.id(v)? == self.id(w)?)
+    }
+
+    /// Returns the number of vertices in the connected component with identifier `id`.
+    pub fn size(&self, id: usize) -> Result<usize, InvalidArgument> {
+        if id >= self.count {
+            return Err(InvalidArgument(format!(
+                "component id {} is not less than the number of components {}",
+                id, self.count
+            )));
+        }
+        Ok(self.size[id])
+    }
+
+    fn validate_vertex(&self, v: usize) -> Result<(), InvalidArgument> {
+        validate_vertex(v, self.marked.len())
+    }
+}
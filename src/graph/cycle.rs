@@ -0,0 +1,183 @@
+use crate::graph::Graph;
+use crate::stack::resizingstack::ResizingStack as Stack;
+
+/// Determines whether an undirected graph has a cycle and, if so, finds such a cycle, using
+/// depth-first search.
+///
+/// Self-loops and parallel edges count as cycles: a self-loop `v-v` is the cycle `v v`, and
+/// parallel edges `v-w` form the cycle `v w v`.
+///
+/// The constructor takes <em>O</em>(<em>V</em> + <em>E</em>) time in the worst case, where
+/// <em>V</em> is the number of vertices and <em>E</em> is the number of edges.
+///
+/// Each instance method takes &Theta;(1) time, not including copying the cycle.
+///
+/// It uses &Theta;(<em>V</em>) extra space (not including the graph).
+///
+/// For additional documentation, see <a href="https://algs4.cs.princeton.edu/41graph">Section
+/// 4.1</a> of <i>Algorithms, 4th Edition</i> by Robert Sedgewick and Kevin Wayne.
+pub struct Cycle {
+    marked: Vec<bool>,
+    edge_to: Vec<usize>,
+    cycle: Option<Vec<usize>>,
+}
+
+impl Cycle {
+    /// Determines whether the undirected graph `g` has a cycle and, if so, finds such a cycle.
+    pub fn new(g: &Graph) -> Self {
+        let v = g.count_vertices();
+        let mut finder = Cycle {
+            marked: vec![false; v],
+            edge_to: vec![0; v],
+            cycle: None,
+        };
+        // need special-case code to handle self loops and parallel edges
+        if finder.has_self_loop(g) || finder.has_parallel_edges(g) {
+            return finder;
+        }
+        for s in 0..v {
+            if !finder.marked[s] {
+                finder.dfs(g, None, s);
+            }
+        }
+        finder
+    }
+
+    // Does this graph have a self loop?  Side effect: initialize cycle to be self loop.
+    fn has_self_loop(&mut self, g: &Graph) -> bool {
+        for v in 0..g.count_vertices() {
+            for &w in g.adj(v).expect("v should be a valid vertex") {
+                if v == w {
+                    self.cycle = Some(vec![v, v]);
+                    return true;
+                }
+            }
+        }
+        false
+    }
+
+    // Does this graph have two parallel edges?  Side effect: initialize cycle to be two parallel
+    // edges.
+    fn has_parallel_edges(&mut self, g: &Graph) -> bool {
+        for v in 0..g.count_vertices() {
+            // check for parallel edges incident to v
+            for &w in g.adj(v).expect("v should be a valid vertex") {
+                if self.marked[w] {
+                    self.cycle = Some(vec![v, w, v]);
+                    return true;
+                }
+                self.marked[w] = true;
+            }
+
+            // reset so marked[v] = false for all v
+            for &w in g.adj(v).expect("v should be a valid vertex") {
+                self.marked[w] = false;
+            }
+        }
+        false
+    }
+
+    // depth-first search from `v`, which is reached from `parent`
+    //
+    // Precondition: `v` is a valid vertex
+    fn dfs(&mut self, g: &Graph, parent: Option<usize>, v: usize) {
+        self.marked[v] = true;
+        for &w in g.adj(v).expect("v should be a valid vertex") {
+            // short circuit if cycle already found
+            if self.cycle.is_some() {
+                return;
+            }
+
+            if !self.marked[w] {
+                self.edge_to[w] = v;
+                self.dfs(g, Some(v), w);
+            } else if Some(w) != parent {
+                // check for cycle (but disregard reverse of edge leading to v)
+                let mut cycle = Stack::new();
+                let mut x = v;
+                while x != w {
+                    cycle.push(x);
+                    x = self.edge_to[x];
+                }
+                cycle.push(w);
+                cycle.push(v);
+                self.cycle = Some(cycle.iter().cloned().collect());
+            }
+        }
+    }
+
+    /// Returns true if the graph has a cycle.
+    pub fn has_cycle(&self) -> bool {
+        self.cycle.is_some()
+    }
+
+    /// Returns a cycle in the graph, which starts and ends with the same vertex, or an empty
+    /// vector if the graph has no cycle.
+    pub fn cycle(&self) -> Vec<usize> {
+        self.cycle.clone().unwrap_or_default()
+    }
+}
+
+/// Enumerates the distinct simple cycles of at most `max_len` vertices in the undirected graph
+/// `g`, intended for small graphs.
+///
+/// A simple cycle visits at least three distinct vertices and returns to its first vertex without
+/// repeating any vertex, so self-loops and parallel edges are not reported.  Each cycle is
+/// reported once, no matter which vertex it starts with or which direction it goes: it starts
+/// with its smallest vertex, and its second vertex is smaller than its last vertex.  The cycles
+/// are returned in lexicographic order.
+///
+/// This implementation uses depth-first search with backtracking, starting from each vertex `s`
+/// and only extending the path with vertices greater than `s`.  The number of simple cycles can
+/// be exponential in the number of vertices, so the worst case running time is exponential, too.
+/// The bound `max_len` limits the depth of the search, which takes
+/// <em>O</em>(<em>V</em> <em>D</em><sup><em>L</em>&minus;1</sup>) time, where <em>V</em> is the
+/// number of vertices, <em>D</em> is the maximum degree, and <em>L</em> is `max_len`.
+///
+/// For additional documentation, see <a href="https://algs4.cs.princeton.edu/41graph">Section
+/// 4.1</a> of <i>Algorithms, 4th Edition</i> by Robert Sedgewick and Kevin Wayne.
+pub fn find_cycles(g: &Graph, max_len: usize) -> Vec<Vec<usize>> {
+    let mut cycles = Vec::new();
+    if max_len < 3 {
+        return cycles;
+    }
+    let mut on_path = vec![false; g.count_vertices()];
+    let mut path = Vec::new();
+    for s in 0..g.count_vertices() {
+        path.push(s);
+        on_path[s] = true;
+        extend(g, s, max_len, &mut path, &mut on_path, &mut cycles);
+        on_path[s] = false;
+        path.pop();
+    }
+    // parallel edges may report the same cycle more than once
+    cycles.sort();
+    cycles.dedup();
+    cycles
+}
+
+// Extends the simple path starting at `s`, collects the cycles closed by an edge back to `s`.
+fn extend(
+    g: &Graph,
+    s: usize,
+    max_len: usize,
+    path: &mut Vec<usize>,
+    on_path: &mut [bool],
+    cycles: &mut Vec<Vec<usize>>,
+) {
+    let v = *path.last().expect("path should not be empty");
+    for &w in g.adj(v).expect("v should be a valid vertex") {
+        if w == s {
+            // report a cycle only in the direction in which the second vertex is the smaller end
+            if path.len() >= 3 && path[1] < v {
+                cycles.push(path.clone());
+            }
+        } else if w > s && !on_path[w] && path.len() < max_len {
+            path.push(w);
+            on_path[w] = true;
+            extend(g, s, max_len, path, on_path, cycles);
+            on_path[w] = false;
+            path.pop();
+        }
+    }
+}
@@ -0,0 +1,259 @@
+use crate::bag::linkedbag::LinkedBag as Bag;
+use crate::error::{Algs4Error, InvalidArgument};
+use crate::graph::{read_x, validate_vertex};
+use crate::io::In;
+use std::cmp::Ordering;
+use std::fmt;
+use std::io::{BufRead, ErrorKind};
+
+/// A weighted edge in an [`EdgeWeightedGraph`].
+///
+/// Each edge consists of two integers (naming the two vertices) and a real-value weight.  The
+/// edges are ordered by their weights; edges of equal weights are ordered by their vertices, so
+/// that the order is consistent with equality.  The weights are compared with
+/// [`f64::total_cmp`], so `NaN` does not break the order.
+///
+/// For additional documentation, see <a href="https://algs4.cs.princeton.edu/43mst">Section
+/// 4.3</a> of <i>Algorithms, 4th Edition</i> by Robert Sedgewick and Kevin Wayne.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Edge {
+    v: usize,
+    w: usize,
+    weight: f64,
+}
+
+impl Edge {
+    /// Initializes an edge between vertices `v` and `w` of the given `weight`.
+    pub fn new(v: usize, w: usize, weight: f64) -> Self {
+        Edge { v, w, weight }
+    }
+
+    /// Returns the weight of this edge.
+    pub fn weight(&self) -> f64 {
+        self.weight
+    }
+
+    /// Returns either endpoint of this edge.
+    pub fn either(&self) -> usize {
+        self.v
+    }
+
+    /// Returns the endpoint of this edge that is different from the given vertex.
+    pub fn other(&self, vertex: usize) -> Result<usize, InvalidArgument> {
+        if vertex == self.v {
+            Ok(self.w)
+        } else if vertex == self.w {
+            Ok(self.v)
+        } else {
+            Err(InvalidArgument(format!(
+                "vertex {} is not an endpoint of edge {}",
+                vertex, self
+            )))
+        }
+    }
+}
+
+impl Ord for Edge {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.weight
+            .total_cmp(&other.weight)
+            .then(self.v.cmp(&other.v))
+            .then(self.w.cmp(&other.w))
+    }
+}
+
+impl PartialOrd for Edge {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Edge {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Edge {}
+
+impl fmt::Display for Edge {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{} {:.5}", self.v, self.w, self.weight)
+    }
+}
+
+/// An edge-weighted undirected graph.  Parallel edges and self-loops allowed.
+///
+/// The `EdgeWeightedGraph` struct represents an edge-weighted graph of vertices named `0` through
+/// `v - 1`, where each undirected edge is of type [`Edge`] and has a real-valued weight.
+///
+/// It supports the following two primary operations: add an edge to the graph, iterate over all
+/// of the edges incident to a vertex.  It also provides methods for returning the degree of a
+/// vertex, the number of vertices `v` in the graph, and the number of edges `e` in the graph.
+///
+/// By convention, a self-loop `v-v` appears in the adjacency list of `v` twice and contributes
+/// two to the degree of `v`.
+///
+/// This implementation uses an <em>adjacency-lists representation</em>, which is a vertex-indexed
+/// array of `Bag` objects.  It uses &Theta;(`e + v`) space, where `e` is the number of edges and
+/// `v` is the number of vertices.  All instance methods take &Theta;(1) time, except `edges`,
+/// which takes &Theta;(`e + v`) time. (Though, iterating over the edges returned by `adj(usize)`
+/// takes time proportional to the degree of the vertex.)
+///
+/// Constructing an empty edge-weighted graph with `v` vertices takes &Theta;(`v`) time;
+/// constructing an edge-weighted graph with `e` edges and `v` vertices takes &Theta;(`e + v`)
+/// time.
+///
+/// For additional documentation, see <a href="https://algs4.cs.princeton.edu/43mst">Section
+/// 4.3</a> of <i>Algorithms, 4th Edition</i> by Robert Sedgewick and Kevin Wayne.
+#[derive(Clone)]
+pub struct EdgeWeightedGraph {
+    v: usize,
+    e: usize,
+    adj: Vec<Bag<Edge>>,
+}
+
+impl EdgeWeightedGraph {
+    pub fn new_no_edge(v: usize) -> Self {
+        EdgeWeightedGraph {
+            v,
+            e: 0,
+            adj: vec![Bag::new(); v],
+        }
+    }
+
+    /// Initializes an edge-weighted graph from the specified input stream.
+    ///
+    /// The format is the number of vertices `v`, followed by the number of edges `e`, followed by
+    /// `e` triples of two vertices and a weight, with each entry separated by whitespace.
+    pub fn new<T: BufRead>(fileinput: &mut In<T>) -> Result<Self, Algs4Error> {
+        let v: usize = read_v(fileinput)?;
+        let e: usize = read_e(fileinput)?;
+        let mut g = EdgeWeightedGraph::new_no_edge(v);
+        for _ in 0..e {
+            let v: usize = read_edge_vertex(fileinput)?;
+            let w: usize = read_edge_vertex(fileinput)?;
+            let weight: f64 = read_weight(fileinput)?;
+            g.add_edge(Edge::new(v, w, weight))?;
+        }
+        Ok(g)
+    }
+
+    /// Returns the number of vertices in this edge-weighted graph.
+    pub fn count_vertices(&self) -> usize {
+        self.v
+    }
+
+    /// Returns the number of edges in this edge-weighted graph.
+    pub fn count_edges(&self) -> usize {
+        self.e
+    }
+
+    /// Adds the undirected edge `e` to this edge-weighted graph.
+    pub fn add_edge(&mut self, e: Edge) -> Result<(), InvalidArgument> {
+        let v = e.either();
+        let w = e.other(v).expect("v should be an endpoint of e");
+        self.validate_vertex(v)?;
+        self.validate_vertex(w)?;
+        self.adj[v].add(e);
+        self.adj[w].add(e);
+        self.e += 1;
+        Ok(())
+    }
+
+    /// Returns the edges incident on vertex `v`.
+    pub fn adj(&self, v: usize) -> Result<impl Iterator<Item = &Edge>, InvalidArgument> {
+        self.validate_vertex(v)?;
+        Ok(self.adj[v].iter())
+    }
+
+    /// Returns the degree of vertex `v`.
+    pub fn degree(&self, v: usize) -> Result<usize, InvalidArgument> {
+        self.validate_vertex(v)?;
+        Ok(self.adj[v].len())
+    }
+
+    /// Returns all edges in this edge-weighted graph.  Each edge appears once, including the
+    /// self-loops.
+    pub fn edges(&self) -> Vec<Edge> {
+        let mut list = Vec::with_capacity(self.e);
+        for v in 0..self.v {
+            let mut self_loops: usize = 0;
+            for e in self.adj[v].iter() {
+                let w = e.other(v).expect("v should be an endpoint of e");
+                if w > v {
+                    list.push(*e);
+                } else if w == v {
+                    // add only one copy of each self loop (self loops will be consecutive)
+                    if self_loops.is_multiple_of(2) {
+                        list.push(*e);
+                    }
+                    self_loops += 1;
+                }
+            }
+        }
+        list
+    }
+
+    fn validate_vertex(&self, v: usize) -> Result<(), InvalidArgument> {
+        validate_vertex(v, self.v)
+    }
+}
+
+impl fmt::Debug for EdgeWeightedGraph {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut s = format!("{} vertices, {} edges\n", self.v, self.e);
+        for v in 0..self.v {
+            s.push_str(&format!("{}: ", v));
+            for e in self.adj[v].iter() {
+                s.push_str(&format!("{e}  "));
+            }
+            s.push('\n');
+        }
+        write!(f, "{s}")
+    }
+}
+
+fn read_v<T: BufRead>(fileinput: &mut In<T>) -> Result<usize, Algs4Error> {
+    read_x(
+        fileinput,
+        "number of vertices in an EdgeWeightedGraph must be non-negative integer, invalid input format in EdgeWeightedGraph constructor",
+        "number of vertices not found in input, invalid input format in EdgeWeightedGraph constructor",
+        "I/O error when reading number of vertices, invalid input format in EdgeWeightedGraph constructor",
+    )
+}
+
+fn read_e<T: BufRead>(fileinput: &mut In<T>) -> Result<usize, Algs4Error> {
+    read_x(
+        fileinput,
+        "number of edges in an EdgeWeightedGraph must be non-negative integer, invalid input format in EdgeWeightedGraph constructor",
+        "number of edges not found in input, invalid input format in EdgeWeightedGraph constructor",
+        "I/O error when reading number of edges, invalid input format in EdgeWeightedGraph constructor",
+    )
+}
+
+fn read_edge_vertex<T: BufRead>(fileinput: &mut In<T>) -> Result<usize, Algs4Error> {
+    read_x(
+        fileinput,
+        "vertex in an EdgeWeightedGraph must be non-negative integer, invalid input format in EdgeWeightedGraph constructor",
+        "vertex of an edge not found in input, invalid input format in EdgeWeightedGraph constructor",
+        "I/O error when reading a vertex of an edge, invalid input format in EdgeWeightedGraph constructor",
+    )
+}
+
+fn read_weight<T: BufRead>(fileinput: &mut In<T>) -> Result<f64, Algs4Error> {
+    match fileinput.read_float() {
+        Ok(x) => Ok(x),
+        Err(e) => match e.kind() {
+            ErrorKind::InvalidData => Err(Algs4Error::InvalidArgument(format!(
+                "weight of an edge must be a real number, invalid input format in EdgeWeightedGraph constructor: {e}"
+            ))),
+            ErrorKind::NotFound => Err(Algs4Error::InvalidArgument(
+                "weight of an edge not found in input, invalid input format in EdgeWeightedGraph constructor".to_string(),
+            )),
+            _ => Err(Algs4Error::InvalidArgument(
+                "I/O error when reading the weight of an edge, invalid input format in EdgeWeightedGraph constructor".to_string(),
+            )),
+        },
+    }
+}
@@ -0,0 +1,72 @@
+//! Functions to generate reproducible random graphs, for testing and benchmarking.
+//!
+//! The random choices are made by a seeded pseudo-random number generator, so the same arguments
+//! always generate the same graph.
+//!
+//! For additional documentation, see <a href="https://algs4.cs.princeton.edu/41graph">Section
+//! 4.1</a> of <i>Algorithms, 4th Edition</i> by Robert Sedgewick and Kevin Wayne.
+
+use crate::error::InvalidArgument;
+use crate::graph::Graph;
+use crate::std_random::StdRandom;
+use std::collections::HashSet;
+
+/// Returns a random simple graph with `v` vertices and `e` edges, that is, a graph without
+/// parallel edges or self-loops.
+///
+/// It returns an error if `e` exceeds `v * (v - 1) / 2`, the number of edges of a complete graph.
+pub fn simple(v: usize, e: usize, seed: u64) -> Result<Graph, InvalidArgument> {
+    let max_edges = v * v.saturating_sub(1) / 2;
+    if e > max_edges {
+        return Err(InvalidArgument(format!(
+            "too many edges: {} vertices have at most {} edges",
+            v, max_edges
+        )));
+    }
+    let rng = StdRandom::with_seed(seed);
+    let mut g = Graph::new_no_edge(v);
+    let mut set = HashSet::new();
+    while g.count_edges() < e {
+        let a = rng.uniform_int(v);
+        let b = rng.uniform_int(v);
+        let edge = (a.min(b), a.max(b));
+        if a != b && set.insert(edge) {
+            g.add_edge(a, b).expect("a and b should be valid vertices");
+        }
+    }
+    Ok(g)
+}
+
+/// Returns a random simple graph on `v` vertices, with an edge between any two vertices with
+/// probability `p`.  This is sometimes referred to as the Erdős–Rényi random graph model.
+///
+/// It returns an error if `p` is not between `0.0` and `1.0`.
+pub fn erdos_renyi(v: usize, p: f64, seed: u64) -> Result<Graph, InvalidArgument> {
+    if !(0.0..=1.0).contains(&p) {
+        return Err(InvalidArgument(format!(
+            "probability {} is not between 0.0 and 1.0",
+            p
+        )));
+    }
+    let rng = StdRandom::with_seed(seed);
+    let mut g = Graph::new_no_edge(v);
+    for i in 0..v {
+        for j in (i + 1)..v {
+            if rng.uniform_f64() < p {
+                g.add_edge(i, j).expect("i and j should be valid vertices");
+            }
+        }
+    }
+    Ok(g)
+}
+
+/// Returns the complete graph on `v` vertices, in which every two distinct vertices are adjacent.
+pub fn complete(v: usize) -> Graph {
+    let mut g = Graph::new_no_edge(v);
+    for i in 0..v {
+        for j in (i + 1)..v {
+            g.add_edge(i, j).expect("i and j should be valid vertices");
+        }
+    }
+    g
+}
@@ -0,0 +1,154 @@
+use crate::graph::edge_weighted_graph::{Edge, EdgeWeightedGraph};
+use crate::heap::minpq::MinPQ;
+use crate::heap::sort::heap_sort;
+use crate::unionfind::UF;
+
+/// Computes a minimum spanning tree (or forest) of an edge-weighted graph, using a lazy version of
+/// <em>Prim's algorithm</em> with a binary heap.
+///
+/// If the graph is not connected, it computes a <em>minimum spanning forest</em>, which is the
+/// union of minimum spanning trees in each connected component.  The `weight` method returns the
+/// weight of a minimum spanning tree and the `edges` method iterates over its edges.
+///
+/// The constructor takes <em>O</em>(<em>E</em> log <em>E</em>) time in the worst case, where
+/// <em>E</em> is the number of edges.  Each instance method takes &Theta;(1) time.  It uses
+/// &Theta;(<em>E</em>) extra space (not including the edge-weighted graph).
+///
+/// For additional documentation, see <a href="https://algs4.cs.princeton.edu/43mst">Section
+/// 4.3</a> of <i>Algorithms, 4th Edition</i> by Robert Sedgewick and Kevin Wayne.
+pub struct LazyPrimMST {
+    weight: f64,       // total weight of MST
+    mst: Vec<Edge>,    // edges in the MST
+    marked: Vec<bool>, // marked[v] = true iff v on tree
+    pq: MinPQ<Edge>,   // edges with one endpoint in tree
+}
+
+impl LazyPrimMST {
+    /// Computes a minimum spanning tree (or forest) of the edge-weighted graph `g`.
+    pub fn new(g: &EdgeWeightedGraph) -> Self {
+        let mut mst = LazyPrimMST {
+            weight: 0.0,
+            mst: Vec::new(),
+            marked: vec![false; g.count_vertices()],
+            pq: MinPQ::new(),
+        };
+        for v in 0..g.count_vertices() {
+            // run Prim from all vertices to get a minimum spanning forest
+            if !mst.marked[v] {
+                mst.prim(g, v);
+            }
+        }
+        mst
+    }
+
+    // run Prim's algorithm
+    fn prim(&mut self, g: &EdgeWeightedGraph, s: usize) {
+        self.scan(g, s);
+        while let Some(e) = self.pq.del_min() {
+            // better to stop when mst has V-1 edges
+            let v = e.either();
+            let w = e.other(v).expect("v should be an endpoint of e");
+            debug_assert!(self.marked[v] || self.marked[w]);
+            if self.marked[v] && self.marked[w] {
+                continue; // lazy, both v and w already scanned
+            }
+            self.mst.push(e); // add e to MST
+            self.weight += e.weight();
+            if !self.marked[v] {
+                self.scan(g, v); // v becomes part of tree
+            }
+            if !self.marked[w] {
+                self.scan(g, w); // w becomes part of tree
+            }
+        }
+    }
+
+    // add all edges e incident to v onto pq if the other endpoint has not yet been scanned
+    fn scan(&mut self, g: &EdgeWeightedGraph, v: usize) {
+        debug_assert!(!self.marked[v]);
+        self.marked[v] = true;
+        for e in g.adj(v).expect("v should be a valid vertex") {
+            if !self.marked[e.other(v).expect("v should be an endpoint of e")] {
+                self.pq.insert(*e);
+            }
+        }
+    }
+
+    /// Returns the edges in a minimum spanning tree (or forest).
+    pub fn edges(&self) -> impl Iterator<Item = &Edge> {
+        self.mst.iter()
+    }
+
+    /// Returns the sum of the edge weights in a minimum spanning tree (or forest).
+    pub fn weight(&self) -> f64 {
+        self.weight
+    }
+}
+
+/// Computes a minimum spanning tree (or forest) of an edge-weighted graph, using <em>Kruskal's
+/// algorithm</em> and the union-find data type [`crate::UF`].
+///
+/// If the graph is not connected, it computes a <em>minimum spanning forest</em>, which is the
+/// union of minimum spanning trees in each connected component.  The `weight` method returns the
+/// weight of a minimum spanning tree and the `edges` method iterates over its edges.
+///
+/// The constructor takes <em>O</em>(<em>E</em> log <em>E</em>) time in the worst case, where
+/// <em>E</em> is the number of edges.  Each instance method takes &Theta;(1) time.  It uses
+/// &Theta;(<em>E</em>) extra space (not including the edge-weighted graph).
+///
+/// This implementation sorts the edges with [`crate::heap_sort`].  See [`LazyPrimMST`] for a
+/// version that uses a priority queue.
+///
+/// For additional documentation, see <a href="https://algs4.cs.princeton.edu/43mst">Section
+/// 4.3</a> of <i>Algorithms, 4th Edition</i> by Robert Sedgewick and Kevin Wayne.
+pub struct KruskalMST {
+    weight: f64,    // weight of MST
+    mst: Vec<Edge>, // edges in MST
+}
+
+impl KruskalMST {
+    /// Computes a minimum spanning tree (or forest) of the edge-weighted graph `g`.
+    pub fn new(g: &EdgeWeightedGraph) -> Self {
+        let mut mst = KruskalMST {
+            weight: 0.0,
+            mst: Vec::new(),
+        };
+
+        // sort edges
+        let mut edges = g.edges();
+        heap_sort(&mut edges);
+
+        // run greedy algorithm
+        let v = g.count_vertices();
+        let mut uf = UF::new(v);
+        for e in edges {
+            if mst.mst.len() + 1 >= v {
+                break;
+            }
+            let v = e.either();
+            let w = e.other(v).expect("v should be an endpoint of e");
+
+            // v-w does not create a cycle
+            if !uf
+                .connected(v, w)
+                .expect("v and w should be valid vertices")
+            {
+                // merge v and w components
+                uf.union(v, w).expect("v and w should be valid vertices");
+                mst.mst.push(e); // add edge e to mst
+                mst.weight += e.weight();
+            }
+        }
+        mst
+    }
+
+    /// Returns the edges in a minimum spanning tree (or forest).
+    pub fn edges(&self) -> impl Iterator<Item = &Edge> {
+        self.mst.iter()
+    }
+
+    /// Returns the sum of the edge weights in a minimum spanning tree (or forest).
+    pub fn weight(&self) -> f64 {
+        self.weight
+    }
+}
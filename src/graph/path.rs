@@ -1,5 +1,5 @@
 use crate::error::InvalidArgument;
-use crate::graph::Graph;
+use crate::graph::{Graph, validate_vertex};
 use crate::queue::resizingqueue::ResizingQueue as Queue;
 use crate::stack::resizingstack::ResizingStack as Stack;
 
@@ -13,6 +13,9 @@ use crate::stack::resizingstack::ResizingStack as Stack;
 ///
 /// It uses &Theta;(<em>V</em>) extra space (not including the graph).
 ///
+/// The depth-first search uses an explicit stack rather than recursion, so it does not overflow
+/// the call stack on large graphs, such as `largeG.txt`.
+///
 /// For additional documentation, see <a href="https://algs4.cs.princeton.edu/41graph">Section
 /// 4.1</a> of <i>Algorithms, 4th Edition</i> by Robert Sedgewick and Kevin Wayne.
 pub struct DepthFirstPaths {
@@ -34,13 +37,35 @@ impl DepthFirstPaths {
         Ok(paths)
     }
 
-    // Precondition: `v` is a valid vertex
-    fn dfs(&mut self, g: &Graph, v: usize) {
-        self.marked[v] = true;
-        for &w in g.adj(v).expect("v should be a valid vertex") {
-            if !self.marked[w] {
-                self.edge_to[w] = v;
-                self.dfs(g, w);
+    // Depth-first search using an explicit stack instead of recursion, so that it does not
+    // overflow the call stack on large graphs.  It visits the vertices in the same order as the
+    // recursive version, so it computes the same `edge_to`.
+    //
+    // Precondition: `s` is a valid vertex
+    fn dfs(&mut self, g: &Graph, s: usize) {
+        // to be able to iterate over each adjacency list, keeping track of which
+        // vertex in each adjacency list needs to be explored next
+        let mut adj: Vec<_> = (0..g.count_vertices())
+            .map(|v| g.adj(v).expect("v should be a valid vertex"))
+            .collect();
+
+        // depth-first search using an explicit stack
+        let mut stack = Stack::new();
+        self.marked[s] = true;
+        stack.push(s);
+        while let Some(&v) = stack.peek() {
+            match adj[v].next() {
+                Some(&w) => {
+                    if !self.marked[w] {
+                        // discovered vertex w for the first time
+                        self.marked[w] = true;
+                        self.edge_to[w] = v;
+                        stack.push(w);
+                    }
+                }
+                None => {
+                    stack.pop();
+                }
             }
         }
     }
@@ -50,6 +75,21 @@ impl DepthFirstPaths {
         Ok(self.marked[v])
     }
 
+    /// Returns the vertices that have a path from the source, in increasing order, including the
+    /// source itself.
+    pub fn reachable(&self) -> impl Iterator<Item = usize> + '_ {
+        self.marked
+            .iter()
+            .enumerate()
+            .filter_map(|(v, &marked)| marked.then_some(v))
+    }
+
+    /// Returns the number of vertices that have a path from the source, including the source
+    /// itself.
+    pub fn count_reachable(&self) -> usize {
+        self.marked.iter().filter(|&&marked| marked).count()
+    }
+
     pub fn path_to(&self, v: usize) -> Result<Vec<usize>, InvalidArgument> {
         if !self.has_path_to(v)? {
             Ok(Vec::new())
@@ -70,18 +110,6 @@ impl DepthFirstPaths {
     }
 }
 
-fn validate_vertex(s: usize, count_vertices: usize) -> Result<(), InvalidArgument> {
-    if s >= count_vertices {
-        Err(InvalidArgument(format!(
-            "vertex {} is not between 0 and {}",
-            s,
-            count_vertices - 1
-        )))
-    } else {
-        Ok(())
-    }
-}
-
 fn validate_vertices(sources: &[usize], count_vertices: usize) -> Result<(), InvalidArgument> {
     if sources.is_empty() {
         return Err(InvalidArgument("zero vertices".to_string()));
@@ -92,13 +120,49 @@ fn validate_vertices(sources: &[usize], count_vertices: usize) -> Result<(), Inv
     Ok(())
 }
 
+/// Returns the number of edges on a shortest path between vertices `s` and `t` in an undirected
+/// graph, or `None` if `t` is not reachable from `s`.
+///
+/// It runs a breadth-first search from `s` that stops as soon as `t` is dequeued, and it does not
+/// record the paths, so it is cheaper than [`BreadthFirstPaths`] if only the distance is needed.
+///
+/// It takes <em>O</em>(<em>V</em> + <em>E</em>) time in the worst case, where <em>V</em> is the
+/// number of vertices and <em>E</em> is the number of edges, and uses &Theta;(<em>V</em>) extra
+/// space (not including the graph).
+pub fn shortest_path_length(
+    g: &Graph,
+    s: usize,
+    t: usize,
+) -> Result<Option<usize>, InvalidArgument> {
+    let v = g.count_vertices();
+    validate_vertex(s, v)?;
+    validate_vertex(t, v)?;
+    let mut dist_to = vec![INFINITY; v];
+    let mut q: Queue<usize> = Queue::new();
+    dist_to[s] = 0;
+    q.enqueue(s);
+    while let Some(x) = q.dequeue() {
+        if x == t {
+            return Ok(Some(dist_to[x]));
+        }
+        for &w in g.adj(x).expect("x should be a valid vertex") {
+            if dist_to[w] == INFINITY {
+                dist_to[w] = dist_to[x] + 1;
+                q.enqueue(w);
+            }
+        }
+    }
+    Ok(None)
+}
+
 /// Finds shortest paths (number of edges) from a source vertex `s` (or a set of source vertices) to
 /// every other vertex in an undirected graph, using breadth-first search.
 ///
 /// The constructor takes &Theta;(<em>V</em> + <em>E</em>) time in the worst case, where <em>V</em>
 /// is the number of vertices and <em>E</em> is the number of edges.
 ///
-/// Each instance method takes &Theta;(1) time.
+/// Each instance method takes &Theta;(1) time, except `path_to`, which takes time proportional
+/// to the length of the path, and `layers`, which takes &Theta;(<em>V</em>) time.
 ///
 /// It uses &Theta;(<em>V</em>) extra space (not including the graph).
 ///
@@ -192,6 +256,21 @@ impl BreadthFirstPaths {
         Ok(self.marked[v])
     }
 
+    /// Returns the vertices that have a path from the source (or sources), in increasing order,
+    /// including the source itself.
+    pub fn reachable(&self) -> impl Iterator<Item = usize> + '_ {
+        self.marked
+            .iter()
+            .enumerate()
+            .filter_map(|(v, &marked)| marked.then_some(v))
+    }
+
+    /// Returns the number of vertices that have a path from the source (or sources), including the
+    /// source itself.
+    pub fn count_reachable(&self) -> usize {
+        self.marked.iter().filter(|&&marked| marked).count()
+    }
+
     pub fn dist_to(&self, v: usize) -> Result<usize, InvalidArgument> {
         self.validate_vertex(v)?;
         Ok(self.dist_to[v])
@@ -212,6 +291,25 @@ impl BreadthFirstPaths {
         }
     }
 
+    /// Returns the reachable vertices grouped by their distance from the source (or the nearest
+    /// source): `layers()[d]` holds the vertices at distance `d`, in increasing order.
+    ///
+    /// The unreachable vertices are skipped, and no layer is empty because the distances of the
+    /// reachable vertices are consecutive.
+    pub fn layers(&self) -> Vec<Vec<usize>> {
+        let mut layers: Vec<Vec<usize>> = Vec::new();
+        for (v, &d) in self.dist_to.iter().enumerate() {
+            if d == INFINITY {
+                continue;
+            }
+            if d >= layers.len() {
+                layers.resize_with(d + 1, Vec::new);
+            }
+            layers[d].push(v);
+        }
+        layers
+    }
+
     // Check optimality conditions for single source.
     fn check(&self, g: &Graph, s: usize) -> bool {
         // check that the distance of s = 0
@@ -0,0 +1,75 @@
+use crate::graph::Graph;
+use crate::stack::resizingstack::ResizingStack as Stack;
+use std::fmt;
+
+/// A summary of the basic statistics of an undirected graph, suitable for reports of command line
+/// tools.
+///
+/// It collects the number of vertices, the number of edges, the maximum degree, the average
+/// degree, the number of self-loops, and the number of connected components of a [`Graph`].
+///
+/// Construction takes &Theta;(<em>V</em> + <em>E</em>) time, where <em>V</em> is the number of
+/// vertices and <em>E</em> is the number of edges.
+///
+/// For additional documentation, see <a href="https://algs4.cs.princeton.edu/41graph">Section
+/// 4.1</a> of <i>Algorithms, 4th Edition</i> by Robert Sedgewick and Kevin Wayne.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GraphStats {
+    pub vertices: usize,
+    pub edges: usize,
+    pub max_degree: usize,
+    pub avg_degree: f64,
+    pub self_loops: usize,
+    pub components: usize,
+}
+
+impl GraphStats {
+    /// Computes the statistics of the graph `g`.
+    pub fn new(g: &Graph) -> Self {
+        let vertices = g.count_vertices();
+        let edges = g.count_edges();
+        GraphStats {
+            vertices,
+            edges,
+            max_degree: g.max_degree(),
+            avg_degree: g.average_degree(),
+            self_loops: g.number_of_self_loops(),
+            components: count_components(g),
+        }
+    }
+}
+
+impl fmt::Display for GraphStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "vertices:   {}", self.vertices)?;
+        writeln!(f, "edges:      {}", self.edges)?;
+        writeln!(f, "max degree: {}", self.max_degree)?;
+        writeln!(f, "avg degree: {:.2}", self.avg_degree)?;
+        writeln!(f, "self-loops: {}", self.self_loops)?;
+        writeln!(f, "components: {}", self.components)
+    }
+}
+
+// Counts the connected components with an iterative depth-first search.
+fn count_components(g: &Graph) -> usize {
+    let mut marked = vec![false; g.count_vertices()];
+    let mut count = 0;
+    for s in 0..g.count_vertices() {
+        if marked[s] {
+            continue;
+        }
+        count += 1;
+        let mut stack = Stack::new();
+        marked[s] = true;
+        stack.push(s);
+        while let Some(v) = stack.pop() {
+            for &w in g.adj(v).expect("v should be a valid vertex") {
+                if !marked[w] {
+                    marked[w] = true;
+                    stack.push(w);
+                }
+            }
+        }
+    }
+    count
+}
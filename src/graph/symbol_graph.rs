@@ -0,0 +1,99 @@
+use crate::bst::BST;
+use crate::error::{Algs4Error, InvalidArgument};
+use crate::graph::{Graph, validate_vertex};
+use crate::io::In;
+use std::io::BufRead;
+
+/// An undirected graph, where the vertex names are arbitrary strings.
+///
+/// By providing mappings between string vertex names and integers, it serves as a wrapper around
+/// the [`Graph`] data type, which assumes the vertex names are integers between `0` and `v - 1`.
+/// It also supports initializing a symbol graph from an input stream.
+///
+/// This implementation uses a [`BST`] to map from strings to integers, a vector to map from
+/// integers to strings, and a [`Graph`] to store the underlying graph.  The `index_of` and
+/// `contains` operations take time proportional to the height of the BST, which is
+/// <em>O</em>(log <em>V</em>) on average, where <em>V</em> is the number of vertices.  The
+/// `name_of` operation takes &Theta;(1) time.
+///
+/// For additional documentation, see <a href="https://algs4.cs.princeton.edu/41graph">Section
+/// 4.1</a> of <i>Algorithms, 4th Edition</i> by Robert Sedgewick and Kevin Wayne.
+pub struct SymbolGraph {
+    st: BST<String, usize>, // string -> index
+    keys: Vec<String>,      // index  -> string
+    graph: Graph,           // the underlying graph
+}
+
+impl SymbolGraph {
+    /// Initializes a graph from the specified input stream, using the specified delimiter.
+    ///
+    /// Each line consists of the name of a vertex, followed by the names of its neighbors,
+    /// separated by `delimiter`, for example, `JFK/MCO/ATL/ORD` with the delimiter `/`.  Blank
+    /// lines are ignored.
+    pub fn new<T: BufRead>(fileinput: &mut In<T>, delimiter: &str) -> Result<Self, Algs4Error> {
+        if delimiter.is_empty() {
+            return Err(Algs4Error::InvalidArgument(
+                "delimiter of a SymbolGraph must not be empty".to_string(),
+            ));
+        }
+        let lines = fileinput.read_all_lines()?;
+
+        // first pass builds the index by reading strings to associate distinct strings with an
+        // index
+        let mut st: BST<String, usize> = BST::new();
+        let mut keys = Vec::new();
+        for line in lines.iter().filter(|line| !line.is_empty()) {
+            for name in line.split(delimiter) {
+                if !st.contains(&name.to_string()) {
+                    st.put(name.to_string(), keys.len());
+                    keys.push(name.to_string());
+                }
+            }
+        }
+
+        // second pass builds the graph by connecting first vertex on each line to all others
+        let mut graph = Graph::new_no_edge(keys.len());
+        for line in lines.iter().filter(|line| !line.is_empty()) {
+            let mut names = line.split(delimiter);
+            let v = *st
+                .get(
+                    &names
+                        .next()
+                        .expect("split yields at least one field")
+                        .to_string(),
+                )
+                .expect("every name should be indexed");
+            for name in names {
+                let w = *st
+                    .get(&name.to_string())
+                    .expect("every name should be indexed");
+                graph.add_edge(v, w)?;
+            }
+        }
+
+        Ok(SymbolGraph { st, keys, graph })
+    }
+
+    /// Does the graph contain the vertex named `name`?
+    pub fn contains(&self, name: &str) -> bool {
+        self.st.contains(&name.to_string())
+    }
+
+    /// Returns the integer associated with the vertex named `name`, or `None` if there is no such
+    /// vertex.
+    pub fn index_of(&self, name: &str) -> Option<usize> {
+        self.st.get(&name.to_string()).copied()
+    }
+
+    /// Returns the name of the vertex associated with the integer `v`.
+    pub fn name_of(&self, v: usize) -> Result<&str, InvalidArgument> {
+        validate_vertex(v, self.keys.len())?;
+        Ok(&self.keys[v])
+    }
+
+    /// Returns the graph associated with the symbol graph.  It is the responsibility of the client
+    /// not to mutate the graph.
+    pub fn graph(&self) -> &Graph {
+        &self.graph
+    }
+}
@@ -1,5 +1,15 @@
 use super::Graph;
-use super::path::{BreadthFirstPaths, DepthFirstPaths};
+use super::bipartite::Bipartite;
+use super::bridges::{ArticulationPoints, Bridge};
+use super::cc::ConnectedComponents;
+use super::cycle::{Cycle, find_cycles};
+use super::edge_weighted_graph::{Edge, EdgeWeightedGraph};
+use super::generator;
+use super::mst::{KruskalMST, LazyPrimMST};
+use super::path::{BreadthFirstPaths, DepthFirstPaths, shortest_path_length};
+use super::stats::GraphStats;
+use super::symbol_graph::SymbolGraph;
+use crate::io::In;
 use std::collections::HashSet;
 
 // fn cmp_adjacency_lists<'a, T, const N: usize>(a: T, b: [usize; N])
@@ -70,6 +80,78 @@ fn graph_basics() {
     check_tiny_graph(&g2);
 }
 
+#[test]
+fn test_has_edge_remove_edge() {
+    let mut g = tiny_graph();
+    assert!(g.has_edge(0, 5).unwrap());
+    assert!(g.has_edge(5, 0).unwrap());
+    assert!(!g.has_edge(0, 3).unwrap());
+    assert!(g.has_edge(0, 13).is_err());
+    assert!(g.remove_edge(13, 0).is_err());
+
+    assert!(g.remove_edge(5, 0).unwrap());
+    assert_eq!(g.count_edges(), 12);
+    assert!(!g.has_edge(0, 5).unwrap());
+    cmp_adj!(g.adj(0).unwrap(), [6, 2, 1]);
+    cmp_adj!(g.adj(5).unwrap(), [3, 4]);
+    assert!(!g.remove_edge(0, 5).unwrap());
+    assert_eq!(g.count_edges(), 12);
+
+    g.add_edge(0, 5).unwrap();
+    assert_eq!(g.count_edges(), 13);
+    check_tiny_graph(&g);
+
+    // parallel edges are removed one at a time, and a self-loop is removed as a whole
+    let mut g = Graph::new_no_edge(2);
+    g.add_edge(0, 1).unwrap();
+    g.add_edge(0, 1).unwrap();
+    g.add_edge(1, 1).unwrap();
+    assert!(g.remove_edge(1, 0).unwrap());
+    assert!(g.has_edge(0, 1).unwrap());
+    assert!(g.remove_edge(1, 1).unwrap());
+    assert!(!g.has_edge(1, 1).unwrap());
+    assert_eq!(g.degree(1).unwrap(), 1);
+    assert_eq!(g.count_edges(), 1);
+}
+
+// Returns the sorted adjacency lists of `g`.
+fn sorted_adj(g: &Graph) -> Vec<Vec<usize>> {
+    (0..g.count_vertices())
+        .map(|v| {
+            let mut adj: Vec<usize> = g.adj(v).unwrap().cloned().collect();
+            adj.sort();
+            adj
+        })
+        .collect()
+}
+
+#[test]
+fn test_to_input_format() {
+    let mut g = Graph::new_no_edge(20);
+    for i in 0..60 {
+        g.add_edge((i * 7) % 20, (i * 13 + 5) % 20).unwrap();
+    }
+    g.add_edge(3, 3).unwrap();
+    g.add_edge(3, 3).unwrap();
+    g.add_edge(3, 4).unwrap();
+
+    let s = g.to_input_format();
+    assert!(s.starts_with("20\n63\n"));
+    assert_eq!(s.lines().count(), 2 + 63);
+    let mut input = In::from(s.as_str());
+    let g2 = Graph::new(&mut input).unwrap();
+    assert_eq!(g2.count_vertices(), 20);
+    assert_eq!(g2.count_edges(), 63);
+    assert_eq!(g2.number_of_self_loops(), g.number_of_self_loops());
+    assert_eq!(sorted_adj(&g2), sorted_adj(&g));
+
+    let g = tiny_graph();
+    let mut input = In::from(g.to_input_format());
+    check_tiny_graph(&Graph::new(&mut input).unwrap());
+
+    assert_eq!(Graph::new_no_edge(0).to_input_format(), "0\n0\n");
+}
+
 #[test]
 fn test_dfs() {
     let g = tiny_connected_graph();
@@ -83,6 +165,37 @@ fn test_dfs() {
     assert!(dfs.path_to(6).is_err());
 }
 
+#[test]
+fn test_reachable() {
+    let g = tiny_graph();
+    let dfs = DepthFirstPaths::new(&g, 0).unwrap();
+    let bfs = BreadthFirstPaths::new(&g, 0).unwrap();
+    assert_eq!(dfs.reachable().collect::<Vec<_>>(), [0, 1, 2, 3, 4, 5, 6]);
+    assert!(bfs.reachable().eq(dfs.reachable()));
+    assert_eq!(dfs.count_reachable(), 7);
+    assert_eq!(bfs.count_reachable(), 7);
+
+    let dfs = DepthFirstPaths::new(&g, 8).unwrap();
+    assert_eq!(dfs.reachable().collect::<Vec<_>>(), [7, 8]);
+    let bfs = BreadthFirstPaths::new_multiple_sources(&g, &[8, 10]).unwrap();
+    assert_eq!(bfs.reachable().collect::<Vec<_>>(), [7, 8, 9, 10, 11, 12]);
+    assert_eq!(bfs.count_reachable(), 6);
+}
+
+#[test]
+fn test_dfs_large_path_graph() {
+    // a recursive depth-first search would overflow the call stack
+    let n = 200_000;
+    let mut g = Graph::new_no_edge(n);
+    for v in 1..n {
+        g.add_edge(v - 1, v).unwrap();
+    }
+    let dfs = DepthFirstPaths::new(&g, 0).unwrap();
+    let path = dfs.path_to(n - 1).unwrap();
+    assert_eq!(path.len(), n);
+    assert!(path.iter().cloned().eq(0..n));
+}
+
 #[test]
 fn test_bfs() {
     let g = tiny_connected_graph();
@@ -95,3 +208,559 @@ fn test_bfs() {
     assert_eq!(bfs.path_to(5).unwrap(), [0, 5]);
     assert!(bfs.path_to(6).is_err());
 }
+
+#[test]
+fn test_bfs_layers() {
+    let g = tiny_connected_graph();
+    let bfs = BreadthFirstPaths::new(&g, 0).unwrap();
+    assert_eq!(bfs.layers(), [vec![0], vec![1, 2, 5], vec![3, 4]]);
+    for (d, layer) in bfs.layers().iter().enumerate() {
+        for &v in layer {
+            assert_eq!(bfs.dist_to(v).unwrap(), d);
+        }
+    }
+
+    // the unreachable vertices are skipped
+    let g = tiny_graph();
+    let bfs = BreadthFirstPaths::new(&g, 9).unwrap();
+    assert_eq!(bfs.layers(), [vec![9], vec![10, 11, 12]]);
+    let bfs = BreadthFirstPaths::new_multiple_sources(&g, &[7, 10]).unwrap();
+    assert_eq!(bfs.layers(), [vec![7, 10], vec![8, 9], vec![11, 12]]);
+}
+
+#[test]
+fn test_shortest_path_length() {
+    let g = tiny_connected_graph();
+    let bfs = BreadthFirstPaths::new(&g, 0).unwrap();
+    assert_eq!(shortest_path_length(&g, 0, 4).unwrap(), Some(2));
+    for t in 0..g.count_vertices() {
+        assert_eq!(
+            shortest_path_length(&g, 0, t).unwrap(),
+            Some(bfs.dist_to(t).unwrap())
+        );
+    }
+    assert!(shortest_path_length(&g, 0, 6).is_err());
+    assert!(shortest_path_length(&g, 6, 0).is_err());
+
+    let g = tiny_graph();
+    assert_eq!(shortest_path_length(&g, 0, 3).unwrap(), Some(2));
+    assert_eq!(shortest_path_length(&g, 0, 7).unwrap(), None);
+    assert_eq!(shortest_path_length(&g, 9, 9).unwrap(), Some(0));
+}
+
+#[test]
+fn test_degree_stats() {
+    let g = tiny_graph();
+    assert_eq!(g.max_degree(), 4);
+    assert_eq!(g.degree(0).unwrap(), 4);
+    assert_eq!(g.average_degree(), 2.0);
+    assert_eq!(g.number_of_self_loops(), 0);
+
+    let mut g = Graph::new_no_edge(4);
+    g.add_edge(0, 1).unwrap();
+    g.add_edge(2, 2).unwrap();
+    g.add_edge(1, 2).unwrap();
+    g.add_edge(1, 1).unwrap();
+    g.add_edge(2, 2).unwrap();
+    assert_eq!(g.number_of_self_loops(), 3);
+    assert_eq!(g.max_degree(), 5);
+    assert_eq!(g.average_degree(), 2.5);
+
+    let g = Graph::new_no_edge(0);
+    assert_eq!(g.max_degree(), 0);
+    assert_eq!(g.average_degree(), 0.0);
+    assert_eq!(g.number_of_self_loops(), 0);
+}
+
+#[test]
+fn test_graph_stats() {
+    let g = tiny_graph();
+    let stats = GraphStats::new(&g);
+    assert_eq!(stats.vertices, 13);
+    assert_eq!(stats.edges, 13);
+    assert_eq!(stats.max_degree, 4);
+    assert_eq!(stats.avg_degree, 2.0);
+    assert_eq!(stats.self_loops, 0);
+    assert_eq!(stats.components, 3);
+    let report = stats.to_string();
+    assert!(report.contains("vertices:   13"));
+    assert!(report.contains("edges:      13"));
+    assert!(report.contains("components: 3"));
+
+    let mut g = Graph::new_no_edge(3);
+    g.add_edge(1, 1).unwrap();
+    g.add_edge(1, 2).unwrap();
+    let stats = GraphStats::new(&g);
+    assert_eq!(stats.self_loops, 1);
+    assert_eq!(stats.max_degree, 3);
+    assert_eq!(stats.components, 2);
+
+    let stats = GraphStats::new(&Graph::new_no_edge(0));
+    assert_eq!(stats.avg_degree, 0.0);
+    assert_eq!(stats.components, 0);
+}
+
+#[test]
+fn test_connected_components() {
+    let g = tiny_graph();
+    let cc = ConnectedComponents::new(&g);
+    assert_eq!(cc.count(), 3);
+    assert!(cc.connected(7, 8).unwrap());
+    assert!(!cc.connected(0, 7).unwrap());
+    assert!(!cc.connected(8, 9).unwrap());
+    let id = cc.id(7).unwrap();
+    assert_eq!(cc.size(id).unwrap(), 2);
+    for v in 0..=6 {
+        assert_eq!(cc.id(v).unwrap(), cc.id(0).unwrap());
+    }
+    for v in 9..=12 {
+        assert_eq!(cc.id(v).unwrap(), cc.id(9).unwrap());
+    }
+    let sizes: Vec<usize> = (0..cc.count()).map(|i| cc.size(i).unwrap()).collect();
+    assert_eq!(sizes, [7, 2, 4]);
+    assert!(cc.size(3).is_err());
+    assert!(cc.id(13).is_err());
+    assert!(cc.connected(0, 13).is_err());
+
+    let cc = ConnectedComponents::new(&Graph::new_no_edge(0));
+    assert_eq!(cc.count(), 0);
+}
+
+// Checks that `cycle` is a cycle of `g`: it is closed, and each pair of consecutive vertices is
+// an edge.
+fn check_cycle(g: &Graph, cycle: &[usize]) {
+    assert!(cycle.len() >= 3, "{cycle:?} is too short");
+    assert_eq!(cycle.first(), cycle.last());
+    for pair in cycle.windows(2) {
+        assert!(
+            g.adj(pair[0]).unwrap().any(|&w| w == pair[1]),
+            "{}-{} is not an edge",
+            pair[0],
+            pair[1]
+        );
+    }
+}
+
+#[test]
+fn test_cycle() {
+    let g = tiny_connected_graph();
+    let finder = Cycle::new(&g);
+    assert!(finder.has_cycle());
+    let cycle = finder.cycle();
+    check_cycle(&g, &cycle);
+    // no vertex is repeated, except the first one
+    let distinct: HashSet<usize> = cycle[1..].iter().cloned().collect();
+    assert_eq!(distinct.len(), cycle.len() - 1);
+
+    // a tree has no cycle
+    let mut tree = Graph::new_no_edge(7);
+    for (v, w) in [(0, 1), (0, 2), (1, 3), (1, 4), (2, 5), (2, 6)] {
+        tree.add_edge(v, w).unwrap();
+    }
+    let finder = Cycle::new(&tree);
+    assert!(!finder.has_cycle());
+    assert!(finder.cycle().is_empty());
+
+    // self-loop
+    let mut g = Graph::new_no_edge(3);
+    g.add_edge(0, 1).unwrap();
+    g.add_edge(2, 2).unwrap();
+    let finder = Cycle::new(&g);
+    assert!(finder.has_cycle());
+    assert_eq!(finder.cycle(), [2, 2]);
+
+    // parallel edges
+    let mut g = Graph::new_no_edge(3);
+    g.add_edge(0, 1).unwrap();
+    g.add_edge(1, 2).unwrap();
+    g.add_edge(2, 1).unwrap();
+    let finder = Cycle::new(&g);
+    assert!(finder.has_cycle());
+    check_cycle(&g, &finder.cycle());
+}
+
+fn sorted_bridges(bridge: &Bridge) -> Vec<(usize, usize)> {
+    let mut bridges: Vec<_> = bridge
+        .bridges()
+        .map(|(v, w)| (v.min(w), v.max(w)))
+        .collect();
+    bridges.sort_unstable();
+    bridges
+}
+
+#[test]
+fn test_bridges_articulation_points() {
+    // two triangles joined by the bridge 2-3, and an isolated vertex 6
+    let mut g = Graph::new_no_edge(7);
+    for (v, w) in [(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 5), (5, 3)] {
+        g.add_edge(v, w).unwrap();
+    }
+    let bridge = Bridge::new(&g);
+    assert_eq!(sorted_bridges(&bridge), [(2, 3)]);
+    assert_eq!(bridge.count(), 1);
+    assert_eq!(bridge.components(), 3);
+    let ap = ArticulationPoints::new(&g);
+    assert_eq!(ap.articulation_points().collect::<Vec<_>>(), [2, 3]);
+    assert_eq!(ap.count(), 2);
+    assert!(ap.is_articulation(2).unwrap());
+    assert!(!ap.is_articulation(6).unwrap());
+    assert!(ap.is_articulation(7).is_err());
+
+    // a cycle is biconnected
+    let mut g = Graph::new_no_edge(6);
+    for v in 0..6 {
+        g.add_edge(v, (v + 1) % 6).unwrap();
+    }
+    let bridge = Bridge::new(&g);
+    assert_eq!(bridge.count(), 0);
+    assert_eq!(bridge.components(), 1);
+    assert_eq!(ArticulationPoints::new(&g).count(), 0);
+
+    // every edge of a path is a bridge, but not a parallel edge
+    let mut g = Graph::new_no_edge(4);
+    for (v, w) in [(0, 1), (1, 2), (2, 3), (2, 3)] {
+        g.add_edge(v, w).unwrap();
+    }
+    let bridge = Bridge::new(&g);
+    assert_eq!(sorted_bridges(&bridge), [(0, 1), (1, 2)]);
+    assert_eq!(bridge.components(), 3);
+    let ap = ArticulationPoints::new(&g);
+    assert_eq!(ap.articulation_points().collect::<Vec<_>>(), [1, 2]);
+
+    // the root of the depth-first search is an articulation point if it has two children
+    let g = tiny_graph();
+    let bridge = Bridge::new(&g);
+    assert_eq!(sorted_bridges(&bridge), [(0, 1), (0, 2), (7, 8), (9, 10)]);
+    assert_eq!(bridge.components(), 7);
+    let ap = ArticulationPoints::new(&g);
+    assert_eq!(ap.articulation_points().collect::<Vec<_>>(), [0, 9]);
+}
+
+#[test]
+fn test_bipartite() {
+    // even cycle 0-1-2-3-4-5-0
+    let mut g = Graph::new_no_edge(6);
+    for v in 0..6 {
+        g.add_edge(v, (v + 1) % 6).unwrap();
+    }
+    let b = Bipartite::new(&g);
+    assert!(b.is_bipartite());
+    assert!(b.odd_cycle().is_empty());
+    for v in 0..6 {
+        for &w in g.adj(v).unwrap() {
+            assert_ne!(b.color(v).unwrap(), b.color(w).unwrap());
+        }
+    }
+    assert!(b.color(6).is_err());
+
+    // triangle 0-1-2 with a tail 2-3
+    let mut g = Graph::new_no_edge(4);
+    g.add_edge(0, 1).unwrap();
+    g.add_edge(1, 2).unwrap();
+    g.add_edge(2, 0).unwrap();
+    g.add_edge(2, 3).unwrap();
+    let b = Bipartite::new(&g);
+    assert!(!b.is_bipartite());
+    assert!(b.color(0).is_err());
+    let cycle = b.odd_cycle();
+    check_cycle(&g, &cycle);
+    assert_eq!(cycle.len(), 4);
+    assert_eq!(
+        cycle[..3].iter().cloned().collect::<HashSet<_>>(),
+        HashSet::from([0, 1, 2])
+    );
+
+    // a self-loop is an odd cycle
+    let mut g = Graph::new_no_edge(2);
+    g.add_edge(1, 1).unwrap();
+    let b = Bipartite::new(&g);
+    assert!(!b.is_bipartite());
+    assert_eq!(b.odd_cycle(), [1, 1]);
+}
+
+#[test]
+fn test_find_cycles() {
+    // a triangle 0-1-2, a square 3-4-5-6 sharing no edge, and a bridge 2-3
+    let mut g = Graph::new_no_edge(8);
+    g.add_edge(0, 1).unwrap();
+    g.add_edge(1, 2).unwrap();
+    g.add_edge(2, 0).unwrap();
+    g.add_edge(2, 3).unwrap();
+    g.add_edge(3, 4).unwrap();
+    g.add_edge(4, 5).unwrap();
+    g.add_edge(5, 6).unwrap();
+    g.add_edge(6, 3).unwrap();
+    g.add_edge(6, 7).unwrap();
+    g.add_edge(7, 7).unwrap(); // self-loop
+    g.add_edge(0, 1).unwrap(); // parallel edge
+
+    assert_eq!(find_cycles(&g, 4), vec![vec![0, 1, 2], vec![3, 4, 5, 6]]);
+    assert_eq!(find_cycles(&g, 3), vec![vec![0, 1, 2]]);
+    assert!(find_cycles(&g, 2).is_empty());
+    assert!(find_cycles(&tiny_connected_graph(), 2).is_empty());
+
+    // K4 has four triangles and three squares
+    let mut k4 = Graph::new_no_edge(4);
+    for v in 0..4 {
+        for w in v + 1..4 {
+            k4.add_edge(v, w).unwrap();
+        }
+    }
+    assert_eq!(
+        find_cycles(&k4, 4),
+        vec![
+            vec![0, 1, 2],
+            vec![0, 1, 2, 3],
+            vec![0, 1, 3],
+            vec![0, 1, 3, 2],
+            vec![0, 2, 1, 3],
+            vec![0, 2, 3],
+            vec![1, 2, 3],
+        ]
+    );
+}
+
+#[test]
+fn test_graph_new_error_position() {
+    let mut input = In::from_str("3\n2\n0 1\n1 x\n");
+    let err = Graph::new(&mut input).err().unwrap();
+    assert!(err.to_string().contains("\"x\" (line 4, column 3)"));
+}
+
+const TINY_EWG: &str = "8
+16
+4 5 0.35
+4 7 0.37
+5 7 0.28
+0 7 0.16
+1 5 0.32
+0 4 0.38
+2 3 0.17
+1 7 0.19
+0 2 0.26
+1 2 0.36
+1 3 0.29
+2 7 0.34
+6 2 0.40
+3 6 0.52
+6 0 0.58
+6 4 0.93
+";
+
+fn tiny_ewg() -> EdgeWeightedGraph {
+    let mut input = In::from(TINY_EWG);
+    EdgeWeightedGraph::new(&mut input).unwrap()
+}
+
+#[test]
+fn test_edge() {
+    let e = Edge::new(4, 5, 0.35);
+    assert_eq!(e.either(), 4);
+    assert_eq!(e.other(4).unwrap(), 5);
+    assert_eq!(e.other(5).unwrap(), 4);
+    assert!(e.other(6).is_err());
+    assert_eq!(e.weight(), 0.35);
+    assert_eq!(e.to_string(), "4-5 0.35000");
+    assert!(Edge::new(0, 7, 0.16) < e);
+    assert!(Edge::new(0, 1, 0.5) > e);
+    assert_eq!(e, Edge::new(4, 5, 0.35));
+    assert_ne!(e, Edge::new(5, 4, 0.35));
+}
+
+#[test]
+fn test_edge_weighted_graph() {
+    let g = tiny_ewg();
+    assert_eq!(g.count_vertices(), 8);
+    assert_eq!(g.count_edges(), 16);
+    assert_eq!(g.degree(2).unwrap(), 5);
+    assert!(g.adj(8).is_err());
+
+    // edges of vertex 2 in weight order
+    let mut adj: Vec<Edge> = g.adj(2).unwrap().cloned().collect();
+    adj.sort();
+    let others: Vec<usize> = adj.iter().map(|e| e.other(2).unwrap()).collect();
+    assert_eq!(others, [3, 0, 7, 1, 6]);
+    let weights: Vec<f64> = adj.iter().map(|e| e.weight()).collect();
+    assert_eq!(weights, [0.17, 0.26, 0.34, 0.36, 0.40]);
+
+    let edges = g.edges();
+    assert_eq!(edges.len(), 16);
+    let total: f64 = edges.iter().map(|e| e.weight()).sum();
+    assert!((total - 5.90).abs() < 1e-9);
+    assert!(format!("{:?}", g).starts_with("8 vertices, 16 edges\n"));
+
+    // a self-loop is listed once in edges()
+    let mut g = EdgeWeightedGraph::new_no_edge(2);
+    g.add_edge(Edge::new(1, 1, 0.5)).unwrap();
+    g.add_edge(Edge::new(0, 1, 0.25)).unwrap();
+    assert_eq!(g.degree(1).unwrap(), 3);
+    assert_eq!(g.edges().len(), 2);
+    assert!(g.add_edge(Edge::new(0, 2, 1.0)).is_err());
+
+    let mut input = In::from("2 1 0 1 heavy");
+    let err = EdgeWeightedGraph::new(&mut input).err().unwrap();
+    assert!(err.to_string().contains("weight"));
+}
+
+#[test]
+fn test_lazy_prim_mst() {
+    let g = tiny_ewg();
+    let mst = LazyPrimMST::new(&g);
+    assert_eq!(mst.edges().count(), g.count_vertices() - 1);
+    assert!((mst.weight() - 1.81).abs() < 1e-9);
+    let mut edges: Vec<Edge> = mst.edges().cloned().collect();
+    edges.sort();
+    let expected = [
+        Edge::new(0, 7, 0.16),
+        Edge::new(2, 3, 0.17),
+        Edge::new(1, 7, 0.19),
+        Edge::new(0, 2, 0.26),
+        Edge::new(5, 7, 0.28),
+        Edge::new(4, 5, 0.35),
+        Edge::new(6, 2, 0.40),
+    ];
+    assert_eq!(edges, expected);
+
+    // a minimum spanning forest of two components
+    let mut g = EdgeWeightedGraph::new_no_edge(5);
+    g.add_edge(Edge::new(0, 1, 1.0)).unwrap();
+    g.add_edge(Edge::new(1, 2, 2.0)).unwrap();
+    g.add_edge(Edge::new(0, 2, 0.5)).unwrap();
+    g.add_edge(Edge::new(3, 4, 3.0)).unwrap();
+    g.add_edge(Edge::new(3, 3, 0.1)).unwrap();
+    let mst = LazyPrimMST::new(&g);
+    assert_eq!(mst.edges().count(), 3);
+    assert_eq!(mst.weight(), 4.5);
+}
+
+#[test]
+fn test_kruskal_mst() {
+    let g = tiny_ewg();
+    let kruskal = KruskalMST::new(&g);
+    let prim = LazyPrimMST::new(&g);
+    assert_eq!(kruskal.edges().count(), g.count_vertices() - 1);
+    assert!((kruskal.weight() - prim.weight()).abs() < 1e-9);
+    let mut edges: Vec<Edge> = kruskal.edges().cloned().collect();
+    edges.sort();
+    let mut prim_edges: Vec<Edge> = prim.edges().cloned().collect();
+    prim_edges.sort();
+    assert_eq!(edges, prim_edges);
+
+    // a minimum spanning forest of two components, with a self-loop and parallel edges
+    let mut g = EdgeWeightedGraph::new_no_edge(5);
+    g.add_edge(Edge::new(0, 1, 1.0)).unwrap();
+    g.add_edge(Edge::new(1, 2, 2.0)).unwrap();
+    g.add_edge(Edge::new(0, 2, 0.5)).unwrap();
+    g.add_edge(Edge::new(2, 0, 0.25)).unwrap();
+    g.add_edge(Edge::new(3, 4, 3.0)).unwrap();
+    g.add_edge(Edge::new(3, 3, 0.1)).unwrap();
+    let kruskal = KruskalMST::new(&g);
+    assert_eq!(kruskal.edges().count(), 3);
+    assert_eq!(kruskal.weight(), 4.25);
+    assert_eq!(kruskal.weight(), LazyPrimMST::new(&g).weight());
+
+    let empty = KruskalMST::new(&EdgeWeightedGraph::new_no_edge(0));
+    assert_eq!(empty.edges().count(), 0);
+}
+
+#[test]
+fn test_symbol_graph() {
+    // a few lines of routes.txt, with a blank line
+    let routes = "JFK MCO
+ORD DEN
+ORD HOU
+
+DFW PHX
+JFK ATL
+ORD DFW
+ORD PHX
+ATL HOU
+";
+    let mut input = In::from(routes);
+    let sg = SymbolGraph::new(&mut input, " ").unwrap();
+    let g = sg.graph();
+    assert_eq!(g.count_vertices(), 8);
+    assert_eq!(g.count_edges(), 8);
+    assert!(sg.contains("ORD"));
+    assert!(!sg.contains("LAX"));
+    assert_eq!(sg.index_of("LAX"), None);
+    assert_eq!(sg.index_of("JFK"), Some(0));
+    assert_eq!(sg.name_of(0).unwrap(), "JFK");
+    assert!(sg.name_of(8).is_err());
+    for name in ["JFK", "MCO", "ORD", "DEN", "HOU", "DFW", "PHX", "ATL"] {
+        assert_eq!(sg.name_of(sg.index_of(name).unwrap()).unwrap(), name);
+    }
+
+    let neighbors = |name: &str| -> HashSet<&str> {
+        g.adj(sg.index_of(name).unwrap())
+            .unwrap()
+            .map(|&w| sg.name_of(w).unwrap())
+            .collect()
+    };
+    assert_eq!(
+        neighbors("ORD"),
+        HashSet::from(["DEN", "HOU", "DFW", "PHX"])
+    );
+    assert_eq!(neighbors("JFK"), HashSet::from(["MCO", "ATL"]));
+    assert_eq!(neighbors("HOU"), HashSet::from(["ORD", "ATL"]));
+    assert_eq!(neighbors("DEN"), HashSet::from(["ORD"]));
+
+    // names with spaces, separated by '/'
+    let movies = "Movie A (2001)/Actor, One/Actor, Two\nMovie B (2002)/Actor, Two";
+    let mut input = In::from(movies);
+    let sg = SymbolGraph::new(&mut input, "/").unwrap();
+    assert_eq!(sg.graph().count_vertices(), 4);
+    let two = sg.index_of("Actor, Two").unwrap();
+    assert_eq!(sg.graph().degree(two).unwrap(), 2);
+
+    let mut input = In::from("a b");
+    assert!(SymbolGraph::new(&mut input, "").is_err());
+}
+
+#[test]
+fn test_generator() {
+    let seed = 20240501;
+    let g = generator::simple(10, 15, seed).unwrap();
+    assert_eq!(g.count_vertices(), 10);
+    assert_eq!(g.count_edges(), 15);
+    assert_eq!(g.number_of_self_loops(), 0);
+    let mut edges = HashSet::new();
+    for v in 0..10 {
+        for &w in g.adj(v).unwrap() {
+            assert_ne!(v, w);
+            edges.insert((v.min(w), v.max(w)));
+        }
+    }
+    assert_eq!(edges.len(), 15, "no parallel edges");
+
+    // the same seed generates the same graph
+    let g2 = generator::simple(10, 15, seed).unwrap();
+    assert_eq!(g2.to_input_format(), g.to_input_format());
+
+    assert_eq!(generator::simple(10, 45, seed).unwrap().count_edges(), 45);
+    assert!(generator::simple(10, 46, seed).is_err());
+    assert_eq!(generator::simple(0, 0, seed).unwrap().count_vertices(), 0);
+
+    let g = generator::complete(6);
+    assert_eq!(g.count_edges(), 15);
+    assert_eq!(g.max_degree(), 5);
+
+    assert_eq!(
+        generator::erdos_renyi(8, 0.0, seed).unwrap().count_edges(),
+        0
+    );
+    assert_eq!(
+        generator::erdos_renyi(8, 1.0, seed).unwrap().count_edges(),
+        28
+    );
+    let g = generator::erdos_renyi(100, 0.1, seed).unwrap();
+    assert_eq!(g.number_of_self_loops(), 0);
+    // 495 edges are expected, with a standard deviation about 21
+    assert!(
+        (395..=595).contains(&g.count_edges()),
+        "{}",
+        g.count_edges()
+    );
+    assert!(generator::erdos_renyi(8, 1.5, seed).is_err());
+    assert!(generator::erdos_renyi(8, f64::NAN, seed).is_err());
+}
@@ -0,0 +1,210 @@
+use crate::sequential_search_st::SequentialSearchST;
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hash};
+
+const INIT_CAPACITY: usize = 4;
+
+/// A symbol table of generic key-value pairs, implemented with a hash table.
+///
+/// It supports the usual `put`, `get`, `contains`, `delete`, `size`, and `is_empty` methods.  It
+/// also provides a `keys` method for iterating over all of the keys.  Unlike [`crate::BST`], the
+/// keys need not be ordered, but they must implement [`Hash`] and [`Eq`], and `keys` iterates over
+/// them in no particular order.
+///
+/// A symbol table implements the *associative array* abstraction: when associating a value with a
+/// key that is already in the symbol table, the convention is to replace the old value with the new
+/// value.
+///
+/// This implementation uses a *separate chaining* hash table: an array of `m` chains, where a key
+/// is in the chain of its hash modulo `m`, and each chain is a [`SequentialSearchST`].  The array
+/// is doubled when the average chain length `n / m` reaches 10, and halved when it drops to 2,
+/// where `n` is the number of key-value pairs.
+///
+/// The `put`, `get`, `contains`, and `delete` operations take &Theta;(1) time on average, under
+/// the uniform hashing assumption, and &Theta;(<em>n</em>) time in the worst case.  The `put` and
+/// `delete` operations take amortized time, because of the resizing.  The `size` and `is_empty`
+/// operations take &Theta;(1) time.  Construction takes &Theta;(1) time.
+///
+/// For additional documentation, see <a href="https://algs4.cs.princeton.edu/34hash">Section
+/// 3.4</a> of <i>Algorithms, 4th Edition</i> by Robert Sedgewick and Kevin Wayne.
+#[derive(Debug, Clone)]
+pub struct SeparateChainingHashST<K, V> {
+    n: usize,                          // number of key-value pairs
+    st: Vec<SequentialSearchST<K, V>>, // array of chains, whose length m is the number of chains
+    hash_builder: RandomState,         // hashes the keys
+}
+
+impl<K, V> SeparateChainingHashST<K, V>
+where
+    K: Hash + Eq,
+{
+    /// Initializes an empty symbol table.
+    pub fn new() -> Self {
+        SeparateChainingHashST::with_chains(INIT_CAPACITY)
+    }
+
+    /// Initializes an empty symbol table with `m` chains.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `m` is `0`.
+    pub fn with_chains(m: usize) -> Self {
+        assert!(m > 0, "number of chains must be positive: {}", m);
+        SeparateChainingHashST {
+            n: 0,
+            st: (0..m).map(|_| SequentialSearchST::new()).collect(),
+            hash_builder: RandomState::new(),
+        }
+    }
+
+    // hash value between 0 and m-1
+    fn hash(&self, key: &K) -> usize {
+        (self.hash_builder.hash_one(key) % self.st.len() as u64) as usize
+    }
+
+    // resize the hash table to have the given number of chains, rehashing all of the keys
+    fn resize(&mut self, chains: usize) {
+        let new = (0..chains).map(|_| SequentialSearchST::new()).collect();
+        let old = std::mem::replace(&mut self.st, new);
+        for (key, val) in old.into_iter().flatten() {
+            let i = self.hash(&key);
+            self.st[i].put(key, val);
+        }
+    }
+
+    /// Returns the number of key-value pairs in this symbol table.
+    pub fn size(&self) -> usize {
+        self.n
+    }
+
+    /// Returns true if this symbol table is empty, returns false otherwise.
+    pub fn is_empty(&self) -> bool {
+        self.size() == 0
+    }
+
+    /// Returns the number of chains.
+    pub fn chains(&self) -> usize {
+        self.st.len()
+    }
+
+    /// Returns the load factor, that is, the average chain length `n / m`.
+    pub fn load_factor(&self) -> f64 {
+        self.n as f64 / self.st.len() as f64
+    }
+
+    /// Does this symbol table contain the given key?
+    pub fn contains(&self, key: &K) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Returns the value associated with the given key, or `None` if the key is not in the symbol
+    /// table.
+    pub fn get(&self, key: &K) -> Option<&V> {
+        let i = self.hash(key);
+        self.st[i].get(key)
+    }
+
+    /// Inserts the specified key-value pair into the symbol table, overwriting the old value with
+    /// the new value if the symbol table already contains the specified key.
+    pub fn put(&mut self, key: K, val: V) {
+        let i = self.hash(&key);
+        if self.st[i].contains(&key) {
+            self.st[i].put(key, val);
+            return;
+        }
+
+        // double table size if average length of list >= 10
+        if self.n >= 10 * self.st.len() {
+            self.resize(2 * self.st.len());
+        }
+        let i = self.hash(&key);
+        self.st[i].put(key, val);
+        self.n += 1;
+    }
+
+    /// Removes the specified key and its associated value from this symbol table (if the key is in
+    /// this symbol table), and returns the value.
+    pub fn delete(&mut self, key: &K) -> Option<V> {
+        let i = self.hash(key);
+        let val = self.st[i].delete(key)?;
+        self.n -= 1;
+
+        // halve table size if average length of list <= 2
+        if self.st.len() > INIT_CAPACITY && self.n <= 2 * self.st.len() {
+            self.resize(self.st.len() / 2);
+        }
+        Some(val)
+    }
+
+    /// Returns an iterator over the keys in the symbol table, in no particular order.
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.st.iter().flat_map(SequentialSearchST::keys)
+    }
+}
+
+impl<K, V> Default for SeparateChainingHashST<K, V>
+where
+    K: Hash + Eq,
+{
+    fn default() -> Self {
+        SeparateChainingHashST::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_separate_chaining_hash_st() {
+        let mut st = SeparateChainingHashST::new();
+        assert!(st.is_empty());
+        assert_eq!(st.get(&"a"), None);
+        for (i, key) in "s e a r c h e x a m p l e".split_whitespace().enumerate() {
+            st.put(key, i);
+        }
+        assert_eq!(st.size(), 10);
+        assert_eq!(st.get(&"e"), Some(&12));
+        assert_eq!(st.get(&"s"), Some(&0));
+        assert!(st.contains(&"x"));
+        assert!(!st.contains(&"z"));
+
+        let mut keys: Vec<_> = st.keys().copied().collect();
+        keys.sort_unstable();
+        assert_eq!(keys, ["a", "c", "e", "h", "l", "m", "p", "r", "s", "x"]);
+
+        assert_eq!(st.delete(&"e"), Some(12));
+        assert_eq!(st.delete(&"e"), None);
+        assert_eq!(st.size(), 9);
+        assert!(!st.contains(&"e"));
+    }
+
+    #[test]
+    fn test_separate_chaining_hash_st_resize() {
+        let n = 10000;
+        let mut st = SeparateChainingHashST::new();
+        assert_eq!(st.chains(), INIT_CAPACITY);
+        for i in 0..n {
+            st.put(i, i * i);
+            assert!(st.load_factor() <= 10.0);
+        }
+        assert_eq!(st.size(), n);
+        assert!(st.chains() >= n / 10);
+        assert!(st.load_factor() > 2.0);
+        assert!((0..n).all(|i| st.get(&i) == Some(&(i * i))));
+        assert_eq!(st.keys().count(), n);
+
+        // overwriting does not add keys
+        st.put(0, 1);
+        assert_eq!(st.get(&0), Some(&1));
+        assert_eq!(st.size(), n);
+
+        for i in 0..n - 1 {
+            assert!(st.delete(&i).is_some());
+            assert!(st.chains() == INIT_CAPACITY || st.load_factor() > 2.0);
+        }
+        assert_eq!(st.size(), 1);
+        assert_eq!(st.chains(), INIT_CAPACITY);
+        assert_eq!(st.get(&(n - 1)), Some(&((n - 1) * (n - 1))));
+    }
+}
@@ -1,3 +1,6 @@
+pub(crate) mod boundedmaxpq;
+pub(crate) mod index_maxpq;
+pub(crate) mod index_minpq;
 pub(crate) mod maxpq;
 pub(crate) mod minpq;
 pub(crate) mod sort;
@@ -0,0 +1,116 @@
+use crate::MaxPQ;
+
+/// A bounded priority queue that keeps the `k` smallest keys offered to it, for streaming top-k
+/// queries.
+///
+/// It uses an internal [`MaxPQ`] of at most `k` keys, so that the largest of the kept keys is on
+/// the top of the heap, and it can be evicted when a smaller key is offered.
+///
+/// The `offer` operation takes &Theta;(log <em>k</em>) amortized time.  The `max`, `len`,
+/// `is_empty` operations take &Theta;(1) time in the worst case.
+///
+/// For additional documentation, see <a href="https://algs4.cs.princeton.edu/24pq">Section 2.4</a>
+/// of <i>Algorithms, 4th Edition</i> by Robert Sedgewick and Kevin Wayne.
+pub struct BoundedMaxPQ<T> {
+    pq: MaxPQ<T>, // the kept keys, the largest one on the top
+    k: usize,     // maximum number of keys to keep
+}
+
+impl<T> BoundedMaxPQ<T>
+where
+    T: Ord + Default,
+{
+    /// Creates an empty bounded priority queue that keeps at most `k` keys.
+    pub fn new(k: usize) -> Self {
+        BoundedMaxPQ {
+            pq: MaxPQ::with_capacity(k + 1),
+            k,
+        }
+    }
+
+    /// Returns true if this priority queue is empty.
+    pub fn is_empty(&self) -> bool {
+        self.pq.is_empty()
+    }
+
+    /// Returns the number of keys on this priority queue.
+    pub fn len(&self) -> usize {
+        self.pq.len()
+    }
+
+    /// Returns the maximum number of keys that this priority queue keeps.
+    pub fn capacity(&self) -> usize {
+        self.k
+    }
+
+    /// Returns a largest key of the kept keys.
+    pub fn max(&self) -> Option<&T> {
+        self.pq.max()
+    }
+
+    /// Offers the key `x` to this priority queue, returns true if `x` is kept.
+    ///
+    /// If there are already `k` keys kept, `x` is kept only if it is smaller than the largest kept
+    /// key, which is evicted.
+    pub fn offer(&mut self, x: T) -> bool {
+        if self.pq.len() < self.k {
+            self.pq.insert(x);
+            true
+        } else if self.pq.max().is_some_and(|max| x.cmp(max).is_lt()) {
+            self.pq.del_max();
+            self.pq.insert(x);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Consumes this priority queue and returns the kept keys in ascending order.
+    pub fn into_sorted_vec(mut self) -> Vec<T> {
+        let mut v = Vec::with_capacity(self.pq.len());
+        while let Some(x) = self.pq.del_max() {
+            v.push(x);
+        }
+        v.reverse();
+        v
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bounded_maxpq_basics() {
+        let mut pq = BoundedMaxPQ::new(3);
+        assert!(pq.is_empty());
+        assert!(pq.offer(5));
+        assert!(pq.offer(1));
+        assert!(pq.offer(8));
+        assert_eq!(pq.len(), 3);
+        assert_eq!(pq.max(), Some(&8));
+        assert!(!pq.offer(9));
+        assert!(!pq.offer(8));
+        assert!(pq.offer(2));
+        assert_eq!(pq.len(), 3);
+        assert_eq!(pq.max(), Some(&5));
+        assert_eq!(pq.into_sorted_vec(), [1, 2, 5]);
+
+        let mut pq = BoundedMaxPQ::new(0);
+        assert!(!pq.offer(1));
+        assert!(pq.is_empty());
+        assert!(pq.into_sorted_vec().is_empty());
+    }
+
+    #[test]
+    fn bounded_maxpq_top_k() {
+        let mut pq = BoundedMaxPQ::new(10);
+        // 7919 is a prime, so the values are a permutation of 0..1000
+        for i in 0..1000 {
+            pq.offer((i * 7919) % 1000);
+        }
+        assert_eq!(pq.len(), 10);
+        assert_eq!(pq.capacity(), 10);
+        assert_eq!(pq.into_sorted_vec(), (0..10).collect::<Vec<i32>>());
+    }
+}
@@ -0,0 +1,323 @@
+use crate::error::InvalidArgument;
+
+/// An indexed priority queue of generic keys.
+///
+/// It supports the usual `insert` and `del_min` operations, along with `delete` and `change_key`
+/// methods.  In order to let the client refer to keys on the priority queue, an integer between `0`
+/// and `max_n - 1` is associated with each key; the client uses this integer to specify which key
+/// to delete or change.  It also supports methods for peeking at the minimum key and testing if
+/// the priority queue is empty.
+///
+/// This implementation uses a <em>binary heap</em> along with an array to associate keys with
+/// integers in the given range.  The `insert`, `del_min`, `delete`, `change_key`, `decrease_key`,
+/// and `increase_key` operations take &Theta;(log <em>n</em>) time in the worst case, where
+/// <em>n</em> is the number of elements in the priority queue.  Construction takes time
+/// proportional to the specified capacity.
+///
+/// We use a one-based array to simplify parent and child calculations.
+///
+/// For additional documentation, see <a href="https://algs4.cs.princeton.edu/24pq">Section 2.4</a>
+/// of <i>Algorithms, 4th Edition</i> by Robert Sedgewick and Kevin Wayne.
+pub struct IndexMinPQ<T> {
+    max_n: usize,           // maximum number of elements on PQ
+    n: usize,               // number of elements on PQ
+    pq: Vec<usize>,         // binary heap using 1-based indexing
+    qp: Vec<Option<usize>>, // inverse of pq - qp[pq[i]] = pq[qp[i]] = i
+    keys: Vec<Option<T>>,   // keys[i] = priority of i
+}
+
+impl<T: Ord> IndexMinPQ<T> {
+    /// Initializes an empty indexed priority queue with indices between `0` and `max_n - 1`.
+    pub fn new(max_n: usize) -> Self {
+        IndexMinPQ {
+            max_n,
+            n: 0,
+            pq: vec![0; max_n + 1],
+            qp: vec![None; max_n],
+            keys: (0..max_n).map(|_| None).collect(),
+        }
+    }
+
+    /// Returns true if this priority queue is empty.
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+
+    /// Returns the number of keys on this priority queue.
+    pub fn len(&self) -> usize {
+        self.n
+    }
+
+    /// Is `i` an index on this priority queue?
+    pub fn contains(&self, i: usize) -> Result<bool, InvalidArgument> {
+        self.validate_index(i)?;
+        Ok(self.qp[i].is_some())
+    }
+
+    /// Associates key with index `i`.
+    ///
+    /// It returns an error if `i` is not a valid index, or if there is already an item associated
+    /// with index `i`.
+    pub fn insert(&mut self, i: usize, key: T) -> Result<(), InvalidArgument> {
+        if self.contains(i)? {
+            return Err(InvalidArgument(format!(
+                "index {} is already in the priority queue",
+                i
+            )));
+        }
+        self.n += 1;
+        self.qp[i] = Some(self.n);
+        self.pq[self.n] = i;
+        self.keys[i] = Some(key);
+        self.swim(self.n);
+        debug_assert!(self.is_min_heap());
+        Ok(())
+    }
+
+    /// Returns an index associated with a minimum key, or `None` if this priority queue is empty.
+    pub fn min_index(&self) -> Option<usize> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(self.pq[1])
+        }
+    }
+
+    /// Returns a minimum key, or `None` if this priority queue is empty.
+    pub fn min_key(&self) -> Option<&T> {
+        self.min_index().map(|i| self.key(i))
+    }
+
+    /// Removes a minimum key and returns its associated index, or `None` if this priority queue is
+    /// empty.
+    pub fn del_min(&mut self) -> Option<usize> {
+        let min = self.min_index()?;
+        self.exch(1, self.n);
+        self.n -= 1;
+        self.sink(1);
+        self.qp[min] = None; // delete
+        self.keys[min] = None; // to help with garbage collection
+        debug_assert!(self.is_min_heap());
+        Some(min)
+    }
+
+    /// Returns the key associated with index `i`.
+    ///
+    /// It returns an error if `i` is not a valid index, or if no key is associated with index `i`.
+    pub fn key_of(&self, i: usize) -> Result<&T, InvalidArgument> {
+        self.validate_contained(i)?;
+        Ok(self.key(i))
+    }
+
+    /// Changes the key associated with index `i` to the specified value.
+    ///
+    /// It returns an error if `i` is not a valid index, or if no key is associated with index `i`.
+    pub fn change_key(&mut self, i: usize, key: T) -> Result<(), InvalidArgument> {
+        let k = self.validate_contained(i)?;
+        self.keys[i] = Some(key);
+        self.swim(k);
+        let k = self.qp[i].expect("i should be on the priority queue");
+        self.sink(k);
+        debug_assert!(self.is_min_heap());
+        Ok(())
+    }
+
+    /// Decreases the key associated with index `i` to the specified value.
+    ///
+    /// It returns an error if `i` is not a valid index, if no key is associated with index `i`, or
+    /// if `key` is not strictly less than the key associated with index `i`.
+    pub fn decrease_key(&mut self, i: usize, key: T) -> Result<(), InvalidArgument> {
+        let k = self.validate_contained(i)?;
+        if key >= *self.key(i) {
+            return Err(InvalidArgument(format!(
+                "calling decrease_key() with a key not strictly less than the key of index {}",
+                i
+            )));
+        }
+        self.keys[i] = Some(key);
+        self.swim(k);
+        debug_assert!(self.is_min_heap());
+        Ok(())
+    }
+
+    /// Increases the key associated with index `i` to the specified value.
+    ///
+    /// It returns an error if `i` is not a valid index, if no key is associated with index `i`, or
+    /// if `key` is not strictly greater than the key associated with index `i`.
+    pub fn increase_key(&mut self, i: usize, key: T) -> Result<(), InvalidArgument> {
+        let k = self.validate_contained(i)?;
+        if key <= *self.key(i) {
+            return Err(InvalidArgument(format!(
+                "calling increase_key() with a key not strictly greater than the key of index {}",
+                i
+            )));
+        }
+        self.keys[i] = Some(key);
+        self.sink(k);
+        debug_assert!(self.is_min_heap());
+        Ok(())
+    }
+
+    /// Removes the key associated with index `i`, and returns it.
+    ///
+    /// It returns an error if `i` is not a valid index, or if no key is associated with index `i`.
+    pub fn delete(&mut self, i: usize) -> Result<T, InvalidArgument> {
+        let index = self.validate_contained(i)?;
+        self.exch(index, self.n);
+        self.n -= 1;
+        if index <= self.n {
+            self.swim(index);
+            self.sink(index);
+        }
+        self.qp[i] = None;
+        debug_assert!(self.is_min_heap());
+        Ok(self.keys[i].take().expect("i should have a key"))
+    }
+
+    // Returns the heap position of index `i`, or an error if `i` is not on the priority queue.
+    fn validate_contained(&self, i: usize) -> Result<usize, InvalidArgument> {
+        self.validate_index(i)?;
+        self.qp[i]
+            .ok_or_else(|| InvalidArgument(format!("index {} is not in the priority queue", i)))
+    }
+
+    fn validate_index(&self, i: usize) -> Result<(), InvalidArgument> {
+        if i >= self.max_n {
+            Err(InvalidArgument(format!(
+                "index {} is not less than the capacity {}",
+                i, self.max_n
+            )))
+        } else {
+            Ok(())
+        }
+    }
+
+    // Precondition: index `i` is on the priority queue.
+    fn key(&self, i: usize) -> &T {
+        self.keys[i].as_ref().expect("i should have a key")
+    }
+
+    fn greater(&self, i: usize, j: usize) -> bool {
+        self.key(self.pq[i]) > self.key(self.pq[j])
+    }
+
+    fn exch(&mut self, i: usize, j: usize) {
+        self.pq.swap(i, j);
+        self.qp[self.pq[i]] = Some(i);
+        self.qp[self.pq[j]] = Some(j);
+    }
+
+    fn swim(&mut self, mut k: usize) {
+        while k > 1 && self.greater(k / 2, k) {
+            self.exch(k, k / 2);
+            k /= 2;
+        }
+    }
+
+    fn sink(&mut self, mut k: usize) {
+        while 2 * k <= self.n {
+            let mut j = 2 * k;
+            if j < self.n && self.greater(j, j + 1) {
+                j += 1;
+            }
+            if !self.greater(k, j) {
+                break;
+            }
+            self.exch(k, j);
+            k = j;
+        }
+    }
+
+    // is pq[1..=n] a min heap, and are pq and qp inverses?
+    fn is_min_heap(&self) -> bool {
+        for k in 1..=self.n {
+            if self.qp[self.pq[k]] != Some(k) {
+                return false;
+            }
+            for child in [2 * k, 2 * k + 1] {
+                if child <= self.n && self.greater(k, child) {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn index_minpq_basics() {
+        let strings = [
+            "it", "was", "the", "best", "of", "times", "it", "was", "the", "worst",
+        ];
+        let mut pq = IndexMinPQ::new(strings.len());
+        assert!(pq.is_empty());
+        assert_eq!(pq.min_index(), None);
+        assert_eq!(pq.min_key(), None);
+        assert_eq!(pq.del_min(), None);
+        for (i, s) in strings.iter().enumerate() {
+            pq.insert(i, *s).unwrap();
+        }
+        assert_eq!(pq.len(), 10);
+        assert!(pq.contains(3).unwrap());
+        assert_eq!(pq.min_index(), Some(3));
+        assert_eq!(pq.min_key(), Some(&"best"));
+        assert_eq!(pq.key_of(5).unwrap(), &"times");
+
+        let mut deleted = Vec::new();
+        while let Some(i) = pq.del_min() {
+            deleted.push(strings[i]);
+            assert!(!pq.contains(i).unwrap());
+        }
+        assert_eq!(
+            deleted,
+            [
+                "best", "it", "it", "of", "the", "the", "times", "was", "was", "worst"
+            ]
+        );
+        assert!(pq.is_empty());
+    }
+
+    #[test]
+    fn index_minpq_change_keys() {
+        let mut pq = IndexMinPQ::new(5);
+        for (i, key) in [50, 40, 30, 20, 10].into_iter().enumerate() {
+            pq.insert(i, key).unwrap();
+        }
+        pq.decrease_key(0, 5).unwrap();
+        assert_eq!(pq.min_index(), Some(0));
+        pq.increase_key(0, 35).unwrap();
+        assert_eq!(pq.min_index(), Some(4));
+        pq.change_key(2, 45).unwrap();
+        pq.change_key(1, 1).unwrap();
+        assert_eq!(pq.delete(3).unwrap(), 20);
+        assert!(!pq.contains(3).unwrap());
+
+        let mut order = Vec::new();
+        while let Some(i) = pq.del_min() {
+            order.push(i);
+        }
+        assert_eq!(order, [1, 4, 0, 2]);
+    }
+
+    #[test]
+    fn index_minpq_errors() {
+        let mut pq = IndexMinPQ::new(3);
+        assert!(pq.insert(3, 'a').is_err());
+        assert!(pq.contains(3).is_err());
+        pq.insert(1, 'b').unwrap();
+        assert!(pq.insert(1, 'c').is_err());
+        assert_eq!(pq.key_of(1).unwrap(), &'b');
+        assert!(pq.key_of(0).is_err());
+        assert!(pq.change_key(0, 'a').is_err());
+        assert!(pq.decrease_key(1, 'b').is_err());
+        assert!(pq.decrease_key(1, 'c').is_err());
+        assert!(pq.increase_key(1, 'a').is_err());
+        assert!(pq.delete(2).is_err());
+        assert_eq!(pq.len(), 1);
+        assert_eq!(pq.key_of(1).unwrap(), &'b');
+    }
+}
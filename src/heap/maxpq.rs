@@ -1,4 +1,5 @@
 use crate::SVec;
+use std::cmp::Ordering;
 
 /// A priority queue of generic keys.  A better alternative is [`std::collections::BinaryHeap`].
 ///
@@ -17,13 +18,17 @@ use crate::SVec;
 ///
 /// We use a one-based array to simplify parent and child calculations.
 ///
+/// The keys are compared with their natural order by default.  Use [`MaxPQ::with_comparator`] to
+/// order them by a custom comparator instead, for example, by a field of a struct.
+///
 /// Can be optimized by replacing full exchanges with half exchanges (aka insertion sort).
 ///
 /// For additional documentation, see <a href="https://algs4.cs.princeton.edu/24pq">Section 2.4</a>
 /// of <i>Algorithms, 4th Edition</i> by Robert Sedgewick and Kevin Wayne.
-pub struct MaxPQ<T> {
+pub struct MaxPQ<T, C = fn(&T, &T) -> Ordering> {
     pq: SVec<T>, // store items at indices 1 to n
     len: usize,  // number of items on priority queue
+    cmp: C,      // the order of the keys
 }
 
 impl<T> MaxPQ<T>
@@ -32,9 +37,7 @@ where
 {
     /// Creates an empty priority queue.
     pub fn new() -> Self {
-        let mut data = SVec::new();
-        data.push(T::default());
-        MaxPQ { pq: data, len: 0 }
+        Self::with_comparator(T::cmp)
     }
 
     /// Creates an empty priority queue with the given initial capacity.
@@ -45,11 +48,50 @@ where
     ///
     /// Panics if the new capacity exceeds `isize::MAX` *bytes*.
     pub fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity_and_comparator(capacity, T::cmp)
+    }
+}
+
+impl<T, C> MaxPQ<T, C>
+where
+    T: Default,
+    C: Fn(&T, &T) -> Ordering,
+{
+    /// Creates an empty priority queue whose keys are ordered by the comparator `cmp`, so that
+    /// `del_max` returns a largest key according to `cmp`.
+    pub fn with_comparator(cmp: C) -> Self {
+        let mut data = SVec::new();
+        data.push(T::default());
+        MaxPQ {
+            pq: data,
+            len: 0,
+            cmp,
+        }
+    }
+
+    /// Creates an empty priority queue with the given initial capacity, whose keys are ordered by
+    /// the comparator `cmp`.
+    ///
+    /// If capacity is zero, no allocation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX` *bytes*.
+    pub fn with_capacity_and_comparator(capacity: usize, cmp: C) -> Self {
         let mut data = SVec::with_capacity(capacity);
         data.push(T::default());
-        MaxPQ { pq: data, len: 0 }
+        MaxPQ {
+            pq: data,
+            len: 0,
+            cmp,
+        }
     }
+}
 
+impl<T, C> MaxPQ<T, C>
+where
+    C: Fn(&T, &T) -> Ordering,
+{
     /// Returns true if this priority queue is empty.
     pub fn is_empty(&self) -> bool {
         self.len == 0
@@ -69,6 +111,14 @@ where
         }
     }
 
+    /// Returns an iterator over the keys on this priority queue, without removing them.
+    ///
+    /// The keys are visited in the order of the underlying heap array, which is unspecified, and
+    /// is not the sorted order.  Use `into_iter` to visit the keys from largest to smallest.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.pq[1..=self.len].iter()
+    }
+
     /// Adds a new key to this priority queue.
     pub fn insert(&mut self, x: T) {
         self.len += 1;
@@ -90,10 +140,25 @@ where
         Some(max)
     }
 
+    /// Returns a vector of the keys on this priority queue, sorted from largest to smallest.
+    pub fn into_sorted_vec(self) -> Vec<T> {
+        self.into_iter().collect()
+    }
+
+    // Restores the heap order of pq[1..=n] bottom-up, in linear time.
+    fn heapify(&mut self) {
+        let mut k = self.len / 2;
+        while k >= 1 {
+            self.sink(k);
+            k -= 1;
+        }
+        debug_assert!(self.is_max_heap());
+    }
+
     fn swim(&mut self, mut k: usize) {
         while k > 1 && self.less(k / 2, k) {
             self.exch(k / 2, k);
-            k = k / 2;
+            k /= 2;
         }
     }
 
@@ -112,7 +177,7 @@ where
     }
 
     fn less(&self, i: usize, j: usize) -> bool {
-        self.pq[i].cmp(&self.pq[j]).is_lt()
+        (self.cmp)(&self.pq[i], &self.pq[j]).is_lt()
     }
 
     fn exch(&mut self, i: usize, j: usize) {
@@ -131,9 +196,7 @@ where
         }
         let left = 2 * k;
         let right = 2 * k + 1;
-        if left <= self.len && self.less(k, left) {
-            false
-        } else if right <= self.len && self.less(k, right) {
+        if (left <= self.len && self.less(k, left)) || (right <= self.len && self.less(k, right)) {
             false
         } else {
             self.is_max_heap_ordered(left) && self.is_max_heap_ordered(right)
@@ -141,23 +204,26 @@ where
     }
 }
 
+impl<T> Default for MaxPQ<T>
+where
+    T: Ord + Default,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<T> From<&[T]> for MaxPQ<T>
 where
     T: Ord + Default + Clone,
 {
     fn from(keys: &[T]) -> Self {
-        let n = keys.len();
-        let mut maxpq = MaxPQ::with_capacity(n + 1);
+        let mut maxpq = MaxPQ::with_capacity(keys.len() + 1);
         for x in keys {
             maxpq.pq.push(x.clone());
             maxpq.len += 1;
         }
-        let mut k = n / 2;
-        while k >= 1 {
-            maxpq.sink(k);
-            k -= 1;
-        }
-        debug_assert!(maxpq.is_max_heap());
+        maxpq.heapify();
         maxpq
     }
 }
@@ -172,46 +238,59 @@ where
             maxpq.pq.push(x.clone());
             maxpq.len += 1;
         }
-        let mut k = N / 2;
-        while k >= 1 {
-            maxpq.sink(k);
-            k -= 1;
+        maxpq.heapify();
+        maxpq
+    }
+}
+
+impl<T> FromIterator<T> for MaxPQ<T>
+where
+    T: Ord + Default,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let iter = iter.into_iter();
+        let mut maxpq = MaxPQ::with_capacity(iter.size_hint().0 + 1);
+        for x in iter {
+            maxpq.pq.push(x);
+            maxpq.len += 1;
         }
-        debug_assert!(maxpq.is_max_heap());
+        maxpq.heapify();
         maxpq
     }
 }
 
-impl<T> Clone for MaxPQ<T>
+impl<T, C> Clone for MaxPQ<T, C>
 where
-    T: Ord + Default + Clone,
+    T: Clone,
+    C: Clone,
 {
     fn clone(&self) -> Self {
         MaxPQ {
             pq: self.pq.clone(),
             len: self.len,
+            cmp: self.cmp.clone(),
         }
     }
 }
 
-pub struct MaxPQIntoIter<T> {
-    moved_pq: MaxPQ<T>,
+pub struct MaxPQIntoIter<T, C = fn(&T, &T) -> Ordering> {
+    moved_pq: MaxPQ<T, C>,
 }
 
-impl<T> IntoIterator for MaxPQ<T>
+impl<T, C> IntoIterator for MaxPQ<T, C>
 where
-    T: Ord + Default + Clone,
+    C: Fn(&T, &T) -> Ordering,
 {
     type Item = T;
-    type IntoIter = MaxPQIntoIter<T>;
+    type IntoIter = MaxPQIntoIter<T, C>;
     fn into_iter(self) -> Self::IntoIter {
         MaxPQIntoIter { moved_pq: self }
     }
 }
 
-impl<T> Iterator for MaxPQIntoIter<T>
+impl<T, C> Iterator for MaxPQIntoIter<T, C>
 where
-    T: Ord + Default + Clone,
+    C: Fn(&T, &T) -> Ordering,
 {
     type Item = T;
     fn next(&mut self) -> Option<Self::Item> {
@@ -309,4 +388,68 @@ mod tests {
         assert_eq!(itr.next(), Some(-57));
         assert_eq!(itr.next(), None);
     }
+
+    #[test]
+    fn maxpq_iter() {
+        let mut pq = MaxPQ::from([1, 5, 2, 80, 4, -57]);
+        assert_eq!(pq.iter().count(), pq.len());
+        let mut keys: Vec<i32> = pq.iter().copied().collect();
+        keys.sort();
+        assert_eq!(keys, [-57, 1, 2, 4, 5, 80]);
+
+        // the queue is still usable afterward
+        assert_eq!(pq.del_max(), Some(80));
+        pq.insert(3);
+        assert_eq!(pq.iter().count(), 6);
+        assert!(pq.iter().any(|&x| x == 3));
+        assert!(!pq.iter().any(|&x| x == 80));
+        assert_eq!(pq.del_max(), Some(5));
+        assert_eq!(MaxPQ::<i32>::new().iter().next(), None);
+    }
+
+    #[test]
+    fn maxpq_from_iter_into_sorted_vec() {
+        let pq: MaxPQ<i32> = (1..=10).collect();
+        assert_eq!(pq.len(), 10);
+        assert_eq!(pq.max(), Some(&10));
+        assert_eq!(pq.into_sorted_vec(), [10, 9, 8, 7, 6, 5, 4, 3, 2, 1]);
+
+        let pq: MaxPQ<i32> = [1, 5, 2, 80, 4, -57, 5].into_iter().collect();
+        assert_eq!(pq.into_sorted_vec(), [80, 5, 5, 4, 2, 1, -57]);
+
+        let pq: MaxPQ<i32> = std::iter::empty().collect();
+        assert!(pq.is_empty());
+        assert!(pq.into_sorted_vec().is_empty());
+    }
+
+    #[derive(Debug, Default, Clone, PartialEq)]
+    struct Job {
+        name: &'static str,
+        priority: u32,
+    }
+
+    #[test]
+    fn maxpq_with_comparator() {
+        let jobs = [("build", 2), ("test", 5), ("lint", 1), ("deploy", 3)];
+        let mut pq = MaxPQ::with_comparator(|a: &Job, b: &Job| a.priority.cmp(&b.priority));
+        for (name, priority) in jobs {
+            pq.insert(Job { name, priority });
+        }
+        assert_eq!(pq.len(), 4);
+        assert_eq!(pq.max().map(|job| job.name), Some("test"));
+        let names: Vec<&str> = pq.clone().into_iter().map(|job| job.name).collect();
+        assert_eq!(names, ["test", "deploy", "build", "lint"]);
+
+        // a min heap, without wrapping the keys in `Reverse`
+        let mut pq =
+            MaxPQ::with_capacity_and_comparator(4, |a: &Job, b: &Job| b.priority.cmp(&a.priority));
+        for (name, priority) in jobs {
+            pq.insert(Job { name, priority });
+        }
+        assert_eq!(pq.del_max().map(|job| job.name), Some("lint"));
+        assert_eq!(pq.del_max().map(|job| job.name), Some("build"));
+        assert_eq!(pq.del_max().map(|job| job.name), Some("deploy"));
+        assert_eq!(pq.del_max().map(|job| job.name), Some("test"));
+        assert_eq!(pq.del_max(), None);
+    }
 }
@@ -64,6 +64,14 @@ where
         self.pq.max().map(|reversed| &reversed.0)
     }
 
+    /// Returns an iterator over the keys on this priority queue, without removing them.
+    ///
+    /// The keys are visited in the order of the underlying heap array, which is unspecified, and
+    /// is not the sorted order.  Use `into_iter` to visit the keys from smallest to largest.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.pq.iter().map(|reversed| &reversed.0)
+    }
+
     /// Adds a new key to this priority queue.
     pub fn insert(&mut self, x: T) {
         self.pq.insert(Reverse(x));
@@ -71,8 +79,21 @@ where
 
     /// Removes and returns a smallest key on this priority queue.
     pub fn del_min(&mut self) -> Option<T> {
-        let result = self.pq.del_max().map(|reversed| reversed.0);
-        result
+        self.pq.del_max().map(|reversed| reversed.0)
+    }
+
+    /// Returns a vector of the keys on this priority queue, sorted from smallest to largest.
+    pub fn into_sorted_vec(self) -> Vec<T> {
+        self.pq.into_iter().map(|reversed| reversed.0).collect()
+    }
+}
+
+impl<T> Default for MinPQ<T>
+where
+    T: Ord + Default,
+{
+    fn default() -> Self {
+        Self::new()
     }
 }
 
@@ -81,14 +102,10 @@ where
     T: Ord + Default + Clone,
 {
     fn from(keys: &[T]) -> Self {
-        let n = keys.len();
-        let cloned_reversed_keys: Vec<Reverse<T>> =
-            keys.iter().map(|x| Reverse(x.clone())).collect();
-        let mut maxpq = MaxPQ::with_capacity(n + 1);
-        for x in cloned_reversed_keys {
-            maxpq.insert(x);
+        // heapify bottom-up, in linear time
+        MinPQ {
+            pq: keys.iter().map(|x| Reverse(x.clone())).collect(),
         }
-        MinPQ { pq: maxpq }
     }
 }
 
@@ -97,13 +114,21 @@ where
     T: Ord + Default + Clone,
 {
     fn from(keys: [T; N]) -> Self {
-        let cloned_reversed_keys: Vec<Reverse<T>> =
-            keys.iter().map(|x| Reverse(x.clone())).collect();
-        let mut maxpq = MaxPQ::with_capacity(N + 1);
-        for x in cloned_reversed_keys {
-            maxpq.insert(x);
+        // heapify bottom-up, in linear time
+        MinPQ {
+            pq: MaxPQ::from(keys.map(Reverse)),
+        }
+    }
+}
+
+impl<T> FromIterator<T> for MinPQ<T>
+where
+    T: Ord + Default,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        MinPQ {
+            pq: iter.into_iter().map(Reverse).collect(),
         }
-        MinPQ { pq: maxpq }
     }
 }
 
@@ -233,4 +258,56 @@ mod tests {
         assert_eq!(itr.next(), Some(80));
         assert_eq!(itr.next(), None);
     }
+
+    #[test]
+    fn minpq_iter() {
+        let mut pq = MinPQ::from([1, 5, 2, 80, 4, -57]);
+        assert_eq!(pq.iter().count(), pq.len());
+        let mut keys: Vec<i32> = pq.iter().copied().collect();
+        keys.sort();
+        assert_eq!(keys, [-57, 1, 2, 4, 5, 80]);
+
+        // the queue is still usable afterward
+        assert_eq!(pq.del_min(), Some(-57));
+        pq.insert(3);
+        assert_eq!(pq.iter().count(), 6);
+        assert!(pq.iter().any(|&x| x == 3));
+        assert!(!pq.iter().any(|&x| x == -57));
+        assert_eq!(pq.del_min(), Some(1));
+        assert_eq!(MinPQ::<i32>::new().iter().next(), None);
+    }
+
+    #[test]
+    fn minpq_from_iter_into_sorted_vec() {
+        let pq: MinPQ<i32> = (1..=10).rev().collect();
+        assert_eq!(pq.len(), 10);
+        assert_eq!(pq.min(), Some(&1));
+        assert_eq!(pq.into_sorted_vec(), [1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+
+        let pq: MinPQ<i32> = [1, 5, 2, 80, 4, -57, 5].into_iter().collect();
+        assert_eq!(pq.into_sorted_vec(), [-57, 1, 2, 4, 5, 5, 80]);
+
+        let pq: MinPQ<i32> = std::iter::empty().collect();
+        assert!(pq.is_empty());
+        assert!(pq.into_sorted_vec().is_empty());
+    }
+
+    #[test]
+    fn minpq_from_matches_repeated_insert() {
+        let array = [9, 3, 7, 3, -1, 12, 0, 5, 8, -20, 7, 1, 4];
+        let mut inserted = MinPQ::new();
+        for x in array {
+            inserted.insert(x);
+        }
+        let mut from_slice = MinPQ::from(&array[..]);
+        let mut from_array = MinPQ::from(array);
+        assert_eq!(from_slice.len(), inserted.len());
+        assert_eq!(from_array.len(), inserted.len());
+        while let Some(min) = inserted.del_min() {
+            assert_eq!(from_slice.del_min(), Some(min));
+            assert_eq!(from_array.del_min(), Some(min));
+        }
+        assert!(from_slice.is_empty());
+        assert!(from_array.is_empty());
+    }
 }
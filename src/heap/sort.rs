@@ -1,3 +1,5 @@
+use std::cmp::Ordering;
+
 /// Sorts a slice using <em>heapsort</em>.
 ///
 /// This implementation takes &Theta;(<em>n</em> log <em>n</em>) time to sort any array of length
@@ -11,11 +13,21 @@
 /// For additional documentation, see <a href="https://algs4.cs.princeton.edu/24pq">Section 2.4</a>
 /// of <i>Algorithms, 4th Edition</i> by Robert Sedgewick and Kevin Wayne.
 pub fn heap_sort<T: Ord>(pq: &mut [T]) {
+    heap_sort_by(pq, T::cmp);
+}
+
+/// Sorts a slice using <em>heapsort</em>, with the comparator `cmp`.
+///
+/// See [`heap_sort`] for the performance and the stability.
+pub fn heap_sort_by<T, F>(pq: &mut [T], cmp: F)
+where
+    F: Fn(&T, &T) -> Ordering,
+{
     let n = pq.len();
 
     // heapify phase
     for k in (1..=(n / 2)).rev() {
-        sink(pq, k, n);
+        sink(pq, k, n, &cmp);
     }
 
     // sortdown phase
@@ -23,17 +35,32 @@ pub fn heap_sort<T: Ord>(pq: &mut [T]) {
     while k > 1 {
         exch(pq, 1, k);
         k -= 1;
-        sink(pq, 1, k);
+        sink(pq, 1, k, &cmp);
     }
 }
 
-fn sink<T: Ord>(pq: &mut [T], mut k: usize, n: usize) {
+/// Sorts a slice using <em>heapsort</em>, with the key extraction function `key`.
+///
+/// See [`heap_sort`] for the performance and the stability.  The key function is called
+/// &Theta;(<em>n</em> log <em>n</em>) times.
+pub fn heap_sort_by_key<T, K, F>(pq: &mut [T], key: F)
+where
+    K: Ord,
+    F: Fn(&T) -> K,
+{
+    heap_sort_by(pq, |a, b| key(a).cmp(&key(b)));
+}
+
+fn sink<T, F>(pq: &mut [T], mut k: usize, n: usize, cmp: &F)
+where
+    F: Fn(&T, &T) -> Ordering,
+{
     while 2 * k <= n {
         let mut j = 2 * k;
-        if j < n && less(pq, j, j + 1) {
+        if j < n && less(pq, j, j + 1, cmp) {
             j += 1;
         }
-        if !less(pq, k, j) {
+        if !less(pq, k, j, cmp) {
             break;
         }
         exch(pq, k, j);
@@ -41,10 +68,44 @@ fn sink<T: Ord>(pq: &mut [T], mut k: usize, n: usize) {
     }
 }
 
-fn less<T: Ord>(pq: &[T], i: usize, j: usize) -> bool {
-    pq[i - 1].cmp(&pq[j - 1]).is_lt()
+fn less<T, F>(pq: &[T], i: usize, j: usize, cmp: &F) -> bool
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    cmp(&pq[i - 1], &pq[j - 1]).is_lt()
 }
 
 fn exch<T>(pq: &mut [T], i: usize, j: usize) {
     pq.swap(i - 1, j - 1);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn heap_sort_ascending() {
+        let mut a = [1, 5, 2, 80, 4, -57, 5];
+        heap_sort(&mut a);
+        assert_eq!(a, [-57, 1, 2, 4, 5, 5, 80]);
+        let mut empty: [i32; 0] = [];
+        heap_sort(&mut empty);
+        let mut one = [1];
+        heap_sort(&mut one);
+        assert_eq!(one, [1]);
+    }
+
+    #[test]
+    fn heap_sort_by_descending() {
+        let mut a = [1, 5, 2, 80, 4, -57, 5];
+        heap_sort_by(&mut a, |x, y| y.cmp(x));
+        assert_eq!(a, [80, 5, 5, 4, 2, 1, -57]);
+    }
+
+    #[test]
+    fn heap_sort_by_key_second_field() {
+        let mut a = [("c", 3), ("a", 9), ("d", -1), ("b", 4)];
+        heap_sort_by_key(&mut a, |&(_, x)| x);
+        assert_eq!(a, [("d", -1), ("c", 3), ("b", 4), ("a", 9)]);
+    }
+}
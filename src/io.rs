@@ -1,9 +1,12 @@
 use crate::primitive::{PrimFloat, PrimInt};
 use crate::scanner::Scanner;
+use std::fmt::{self, Display};
 use std::fs::File;
 use std::io::BufReader;
+use std::io::Cursor;
 use std::io::StdinLock;
 use std::io::{self, BufRead};
+use std::io::{BufWriter, StdoutLock, Write};
 use std::ops::{Deref, DerefMut};
 use std::str::FromStr;
 
@@ -77,6 +80,36 @@ impl<B: BufRead> In<B> {
         Ok(list)
     }
 
+    /// Reads all floating point numbers from the input stream using the internal scanner, consuming
+    /// all the content in the input stream, reading the content in a token-by-token streaming mode.
+    ///
+    /// # Errors
+    ///
+    /// Same as `Scanner::next_float`.
+    pub fn read_all_floats<T>(&mut self) -> io::Result<Vec<T>>
+    where
+        T: PrimFloat + FromStr,
+    {
+        let mut list = Vec::new();
+        loop {
+            if !self.scanner.has_next()? {
+                break;
+            }
+            list.push(self.scanner.next_float::<T>()?);
+        }
+        Ok(list)
+    }
+
+    /// Reads a UTF-8 character from the input stream, which can be any character including the
+    /// ASCII whitespaces.
+    ///
+    /// # Errors
+    ///
+    /// Same as `Scanner::next_char`.
+    pub fn read_char(&mut self) -> io::Result<char> {
+        self.scanner.next_char()
+    }
+
     /// Read a string token from the input stream.
     ///
     /// # Errors
@@ -102,6 +135,96 @@ impl<B: BufRead> In<B> {
         }
         Ok(list)
     }
+
+    /// Returns an iterator over the string tokens of the input stream, which yields the result of
+    /// `read_string` for each token, until the input stream has no more token.  Unlike
+    /// `read_all_strings`, an invalid UTF-8 token is yielded as an IO Error `InvalidData`.
+    ///
+    /// `&mut In` is also an iterator over the tokens, for example, `for token in &mut input`.
+    ///
+    /// After an error is yielded, the iterator yields `None`.
+    pub fn tokens(&mut self) -> Tokens<'_, B> {
+        Tokens {
+            input: self,
+            failed: false,
+        }
+    }
+
+    /// Reads all lines from the input stream using the internal scanner, consuming all the content
+    /// in the input stream, reading the content in a line-by-line streaming mode.
+    ///
+    /// The line terminators are discarded like `Scanner::next_line`.  A line terminator at the end
+    /// of the input stream does not yield an extra empty line, for example, both "a\nb" and
+    /// "a\nb\n" result in `["a", "b"]`, while "a\nb\n\n" results in `["a", "b", ""]`.
+    ///
+    /// # Errors
+    ///
+    /// Same as `Scanner::next_line`.
+    pub fn read_all_lines(&mut self) -> io::Result<Vec<String>> {
+        let mut list = Vec::new();
+        loop {
+            if !self.scanner.has_next_line()? {
+                break;
+            }
+            list.push(self.scanner.next_line()?);
+        }
+        Ok(list)
+    }
+}
+
+impl<'a> In<&'a [u8]> {
+    /// Creates an `In` that reads from the string `s`, for example, `In::from_str("1 2 3")`.
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &'a str) -> Self {
+        In::new(s.as_bytes())
+    }
+}
+
+/// An iterator over the string tokens of an [`In`], see [`In::tokens`].
+pub struct Tokens<'a, B: BufRead> {
+    input: &'a mut In<B>,
+    failed: bool,
+}
+
+impl<B: BufRead> Iterator for Tokens<'_, B> {
+    type Item = io::Result<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        match self.input.read_string() {
+            Ok(token) => Some(Ok(token)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => None,
+            Err(e) => {
+                self.failed = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+impl<'a, B: BufRead> IntoIterator for &'a mut In<B> {
+    type Item = io::Result<String>;
+    type IntoIter = Tokens<'a, B>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.tokens()
+    }
+}
+
+/// Reads from a borrowed string.
+impl<'a> From<&'a str> for In<&'a [u8]> {
+    fn from(s: &'a str) -> Self {
+        In::new(s.as_bytes())
+    }
+}
+
+/// Reads from an owned string.
+impl From<String> for In<Cursor<String>> {
+    fn from(s: String) -> Self {
+        In::new(Cursor::new(s))
+    }
 }
 
 /// Standard input of this library.
@@ -155,3 +278,225 @@ impl FileIn {
         Ok(FileIn(In::new(BufReader::new(f))))
     }
 }
+
+/// General Output (stdout, file, socket, in-memory buffer, etc.).
+///
+/// It writes to a [`Write`], for example, a `Vec<u8>`, so the output of a program can be tested.
+/// Use [`Out::flush`] or drop the underlying writer to make sure all the data reach the
+/// destination.
+pub struct Out<W: Write> {
+    writer: W,
+}
+
+impl<W: Write> Out<W> {
+    /// Creates a new instance of Out.
+    pub fn new(writer: W) -> Self {
+        Out { writer }
+    }
+
+    /// Prints `x` to the output stream.
+    pub fn print<T: Display>(&mut self, x: T) -> io::Result<()> {
+        write!(self.writer, "{}", x)
+    }
+
+    /// Prints `x` and then terminates the line.
+    pub fn println<T: Display>(&mut self, x: T) -> io::Result<()> {
+        writeln!(self.writer, "{}", x)
+    }
+
+    /// Prints an integer.
+    ///
+    /// The integer type is one of `i8`, `i16`, `i32`, `i64`, `i128`, `isize`, `u8`, `u16`, `u32`,
+    /// `u64`, `u128`, or `usize`.
+    pub fn print_int<T: PrimInt + Display>(&mut self, x: T) -> io::Result<()> {
+        self.print(x)
+    }
+
+    /// Prints a floating point number with `decimals` digits after the decimal point.
+    ///
+    /// The floating point type is one of `f32` or `f64`.
+    pub fn print_float<T: PrimFloat + Display>(&mut self, x: T, decimals: usize) -> io::Result<()> {
+        write!(self.writer, "{:.*}", decimals, x)
+    }
+
+    /// Writes the formatted arguments, so that the macros `write!` and `writeln!` can be used on
+    /// `Out`, for example, `write!(out, "{} {}", a, b)`.
+    pub fn write_fmt(&mut self, args: fmt::Arguments<'_>) -> io::Result<()> {
+        self.writer.write_fmt(args)
+    }
+
+    /// Flushes the output stream, ensuring that all the buffered data reach the destination.
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+
+    /// Unwraps this `Out`, returning the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+/// Standard output of this library.
+pub struct StdOut(Out<StdoutLock<'static>>);
+
+impl Deref for StdOut {
+    type Target = Out<StdoutLock<'static>>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for StdOut {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl StdOut {
+    pub fn new() -> Self {
+        StdOut(Out::new(io::stdout().lock()))
+    }
+}
+
+impl Default for StdOut {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// File output.
+///
+/// The output is buffered, and it is flushed when `FileOut` is dropped, ignoring any error.  Call
+/// `flush` to handle the errors.
+pub struct FileOut(Out<BufWriter<File>>);
+
+impl Deref for FileOut {
+    type Target = Out<BufWriter<File>>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for FileOut {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl FileOut {
+    /// Creates a new instance of Out, which creates the file at `path`, or truncates it if it
+    /// exists.
+    pub fn new(path: &str) -> io::Result<Self> {
+        let f = std::fs::File::create(path)?;
+        Ok(FileOut(Out::new(BufWriter::new(f))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_in_from_str() {
+        let mut input = In::from_str("1 2 3");
+        assert_eq!(input.read_all_ints::<i32>().unwrap(), [1, 2, 3]);
+        assert!(input.is_empty());
+
+        let mut input = In::from("hello world");
+        assert_eq!(input.read_all_strings().unwrap(), ["hello", "world"]);
+
+        let mut input = In::from(String::from("-1.5 7"));
+        assert_eq!(input.read_float::<f64>().unwrap(), -1.5);
+        assert_eq!(input.read_int::<u8>().unwrap(), 7);
+        assert!(input.is_empty());
+    }
+
+    #[test]
+    fn test_in_read_all_floats() {
+        let mut input = In::from_str("1.5 2.25\n3.0");
+        assert_eq!(input.read_all_floats::<f64>().unwrap(), [1.5, 2.25, 3.0]);
+        assert!(input.is_empty());
+
+        let mut input = In::from_str("1.5 x");
+        let e = input.read_all_floats::<f32>().unwrap_err();
+        assert_eq!(e.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn test_in_read_char() {
+        let mut input = In::from_str("xyz");
+        assert_eq!(input.read_char().unwrap(), 'x');
+        assert_eq!(input.read_string().unwrap(), "yz");
+        assert_eq!(
+            input.read_char().unwrap_err().kind(),
+            io::ErrorKind::NotFound
+        );
+    }
+
+    #[test]
+    fn test_in_tokens() {
+        let mut input = In::from_str(" a b\n c ");
+        let tokens: Vec<String> = input.tokens().collect::<io::Result<_>>().unwrap();
+        assert_eq!(tokens, ["a", "b", "c"]);
+        assert!(input.is_empty());
+
+        let mut input = In::from_str("1 2 3 4 5 6");
+        let sum: i32 = input
+            .tokens()
+            .take(5)
+            .map(|token| token.unwrap().parse::<i32>().unwrap())
+            .sum();
+        assert_eq!(sum, 15);
+        let mut rest = Vec::new();
+        for token in &mut input {
+            rest.push(token.unwrap());
+        }
+        assert_eq!(rest, ["6"]);
+
+        let mut input = In::new(&b"a \xFF b"[..]);
+        let mut tokens = input.tokens();
+        assert_eq!(tokens.next().unwrap().unwrap(), "a");
+        assert!(tokens.next().unwrap().is_err());
+        assert!(tokens.next().is_none());
+    }
+
+    #[test]
+    fn test_in_read_all_lines() {
+        let mut input = In::from_str("a\nb\n\nc");
+        assert_eq!(input.read_all_lines().unwrap(), ["a", "b", "", "c"]);
+
+        assert_eq!(
+            In::from_str("a\r\nb\n").read_all_lines().unwrap(),
+            ["a", "b"]
+        );
+        assert_eq!(
+            In::from_str("a\nb\n\n").read_all_lines().unwrap(),
+            ["a", "b", ""]
+        );
+        assert_eq!(In::from_str("\n").read_all_lines().unwrap(), [""]);
+        assert!(In::from_str("").read_all_lines().unwrap().is_empty());
+
+        let mut input = In::from_str("1 2\n3\n");
+        assert_eq!(input.read_int::<i32>().unwrap(), 1);
+        assert_eq!(input.read_all_lines().unwrap(), [" 2", "3"]);
+        assert!(input.is_empty());
+    }
+
+    #[test]
+    fn test_out() {
+        let mut out = Out::new(Vec::new());
+        out.print_int(1).unwrap();
+        out.print(' ').unwrap();
+        out.print_int(-20i64).unwrap();
+        out.println("").unwrap();
+        out.print_float(1.23456, 2).unwrap();
+        out.print(' ').unwrap();
+        out.print_float(2.5f32, 0).unwrap();
+        out.println(" done").unwrap();
+        let label = 'x';
+        write!(out, "{}-{:>3}", label, 7).unwrap();
+        writeln!(out).unwrap();
+        out.flush().unwrap();
+        assert_eq!(out.into_inner(), b"1 -20\n1.23 2 done\nx-  7\n");
+    }
+}
@@ -1,19 +1,35 @@
 pub(crate) mod bag;
 pub(crate) mod binary_search;
+pub(crate) mod binary_search_st;
 pub(crate) mod bst;
+pub mod compress;
+pub(crate) mod counter;
+pub(crate) mod digraph;
 pub(crate) mod error;
 pub(crate) mod graph;
+pub(crate) mod hash_st;
 pub(crate) mod heap;
 pub(crate) mod io;
 pub(crate) mod linear_regression;
+pub(crate) mod polynomial_regression;
 pub(crate) mod primitive;
 pub(crate) mod queue;
 pub(crate) mod scanner;
+pub(crate) mod sequential_search_st;
+pub mod sort;
 pub(crate) mod stack;
+pub mod stats;
+pub(crate) mod std_random;
+pub(crate) mod stopwatch;
+pub(crate) mod string;
+pub(crate) mod symbol_table;
 pub mod threesum;
 pub mod threesum_fast;
+pub(crate) mod trie_st;
 pub mod twosum;
 pub mod twosum_fast;
+pub(crate) mod unionfind;
+pub(crate) mod xorshift;
 
 #[macro_use]
 pub(crate) mod vec;
@@ -21,20 +37,55 @@ pub(crate) mod vec;
 pub use bag::linkedbag::*;
 pub use bag::resizingbag::*;
 pub use binary_search::*;
+pub use binary_search_st::*;
+pub use bst::multibst::*;
 pub use bst::*;
+pub use counter::*;
+pub use digraph::Digraph;
+pub use digraph::cycle::*;
+pub use digraph::dfs::*;
+pub use digraph::topological::*;
 pub use error::*;
 pub use graph::Graph;
+pub use graph::bipartite::*;
+pub use graph::bridges::*;
+pub use graph::cc::*;
+pub use graph::cycle::*;
+pub use graph::edge_weighted_graph::*;
+pub use graph::generator;
+pub use graph::mst::*;
 pub use graph::path::*;
+pub use graph::stats::*;
+pub use graph::symbol_graph::*;
+pub use hash_st::*;
+pub use heap::boundedmaxpq::*;
+pub use heap::index_maxpq::*;
+pub use heap::index_minpq::*;
 pub use heap::sort::*;
 pub use heap::maxpq::*;
 pub use heap::minpq::*;
 pub use io::*;
 pub use linear_regression::*;
+pub use polynomial_regression::*;
 pub use primitive::*;
+pub use queue::deque::*;
 pub use queue::linkedqueue::*;
+pub use queue::randomizedqueue::*;
 pub use queue::resizingqueue::*;
 pub use queue::svecque::*;
 pub use scanner::*;
+pub use sequential_search_st::*;
+pub use sort::adaptive::*;
 pub use stack::linkedstack::*;
 pub use stack::resizingstack::*;
+pub use stack::steque::*;
+pub use stats::Accumulator;
+pub use std_random::*;
+pub use stopwatch::*;
+pub use string::boyer_moore::*;
+pub use string::kmp::*;
+pub use string::rabin_karp::*;
+pub use symbol_table::*;
+pub use trie_st::*;
+pub use unionfind::*;
 pub use vec::*;
@@ -11,12 +11,18 @@ use std::fmt;
 ///  model.  It also computes associated statistics, including the coefficient of determination
 ///  <em>R</em><sup>2</sup> and the standard deviation of the estimates for the slope and
 ///  <em>y</em>-intercept.
+///
+///  For the goodness of fit, it also computes the adjusted <em>R</em><sup>2</sup>, the
+///  <em>F</em>-statistic, and the <em>p</em>-value of the <em>F</em>-test.
 pub struct LinearRegression {
     intercept: f64,
     slope: f64,
     r2: f64,
     svar0: f64,
     svar1: f64,
+    n: usize, // number of data points
+    rss: f64, // residual sum of squares
+    ssr: f64, // regression sum of squares
 }
 
 impl LinearRegression {
@@ -74,6 +80,9 @@ impl LinearRegression {
             r2,
             svar0,
             svar1,
+            n,
+            rss,
+            ssr,
         })
     }
 
@@ -94,6 +103,45 @@ impl LinearRegression {
         self.r2
     }
 
+    /// Returns the adjusted coefficient of determination, which is <em>R</em><sup>2</sup> adjusted
+    /// for the number of data points <em>n</em>:
+    /// 1 &minus; (1 &minus; <em>R</em><sup>2</sup>) (<em>n</em> &minus; 1) / (<em>n</em> &minus; 2).
+    ///
+    /// It is `NaN` or infinite if <em>n</em> &le; 2.
+    pub fn r2_adjusted(&self) -> f64 {
+        let n = self.n as f64;
+        1.0 - (1.0 - self.r2) * (n - 1.0) / (n - 2.0)
+    }
+
+    /// Returns the <em>F</em>-statistic of the regression, which is the ratio of the regression
+    /// sum of squares to the mean squared residual:
+    /// <em>SSR</em> / (<em>RSS</em> / (<em>n</em> &minus; 2)).
+    ///
+    /// It follows the <em>F</em>-distribution with 1 and <em>n</em> &minus; 2 degrees of freedom
+    /// if the slope is zero.  It is `NaN` or infinite if <em>n</em> &le; 2 or the fit is perfect.
+    pub fn f_statistic(&self) -> f64 {
+        self.ssr / (self.rss / (self.n as f64 - 2.0))
+    }
+
+    /// Returns the <em>p</em>-value of the <em>F</em>-test, that is, the probability of an
+    /// <em>F</em>-statistic at least as large as [`LinearRegression::f_statistic`] if the slope
+    /// is zero.
+    ///
+    /// It is approximated by the regularized incomplete beta function, which is evaluated by a
+    /// continued fraction, accurate to about 10 significant digits.  It is `NaN` if
+    /// <em>n</em> &le; 2.
+    pub fn p_value(&self) -> f64 {
+        let d = self.n as f64 - 2.0;
+        let f = self.f_statistic();
+        if d <= 0.0 || f.is_nan() {
+            return f64::NAN;
+        }
+        if f.is_infinite() {
+            return 0.0;
+        }
+        incomplete_beta(d / 2.0, 0.5, d / (d + f))
+    }
+
     /// Returns the standard error of the estimate for the intercept.
     pub fn intercept_std_err(&self) -> f64 {
         self.svar0.sqrt()
@@ -111,6 +159,123 @@ impl LinearRegression {
     pub fn predict(&self, x: f64) -> f64 {
         self.slope * x + self.intercept
     }
+
+    /// Returns the expected responses given the values of the predictor variable `xs`, that is,
+    /// the fitted values if `xs` are the data points of the regression.
+    pub fn predict_all(&self, xs: &[f64]) -> Vec<f64> {
+        xs.iter().map(|&x| self.predict(x)).collect()
+    }
+
+    /// Returns the residuals <em>y<sub>i</sub></em> &minus; `predict(`<em>x<sub>i</sub></em>`)`
+    /// of the data points `(xs[i], ys[i])`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidArgument` if the lengths of the two slices are not equal.
+    pub fn residuals(&self, xs: &[f64], ys: &[f64]) -> Result<Vec<f64>, InvalidArgument> {
+        if xs.len() != ys.len() {
+            return Err(InvalidArgument("array length are not equal".to_string()));
+        }
+        Ok(xs
+            .iter()
+            .zip(ys)
+            .map(|(&x, &y)| y - self.predict(x))
+            .collect())
+    }
+}
+
+// Returns the regularized incomplete beta function I<sub>x</sub>(a, b), where a > 0, b > 0, and
+// 0 <= x <= 1.
+//
+// See Section 6.4 of <i>Numerical Recipes in C</i>, 2nd Edition.
+fn incomplete_beta(a: f64, b: f64, x: f64) -> f64 {
+    if x <= 0.0 {
+        return 0.0;
+    }
+    if x >= 1.0 {
+        return 1.0;
+    }
+    let front =
+        (ln_gamma(a + b) - ln_gamma(a) - ln_gamma(b) + a * x.ln() + b * (1.0 - x).ln()).exp();
+    // the continued fraction converges rapidly for x < (a + 1) / (a + b + 2)
+    if x < (a + 1.0) / (a + b + 2.0) {
+        front * beta_continued_fraction(a, b, x) / a
+    } else {
+        1.0 - front * beta_continued_fraction(b, a, 1.0 - x) / b
+    }
+}
+
+// Evaluates the continued fraction for the incomplete beta function by the modified Lentz's
+// method.
+fn beta_continued_fraction(a: f64, b: f64, x: f64) -> f64 {
+    const MAX_ITERATIONS: usize = 200;
+    const EPSILON: f64 = 1e-15;
+    const TINY: f64 = 1e-300;
+
+    let (qab, qap, qam) = (a + b, a + 1.0, a - 1.0);
+    let mut c = 1.0;
+    let mut d = 1.0 - qab * x / qap;
+    if d.abs() < TINY {
+        d = TINY;
+    }
+    d = 1.0 / d;
+    let mut h = d;
+    for m in 1..=MAX_ITERATIONS {
+        let m = m as f64;
+        let m2 = 2.0 * m;
+
+        // even step
+        let aa = m * (b - m) * x / ((qam + m2) * (a + m2));
+        d = 1.0 + aa * d;
+        if d.abs() < TINY {
+            d = TINY;
+        }
+        c = 1.0 + aa / c;
+        if c.abs() < TINY {
+            c = TINY;
+        }
+        d = 1.0 / d;
+        h *= d * c;
+
+        // odd step
+        let aa = -(a + m) * (qab + m) * x / ((a + m2) * (qap + m2));
+        d = 1.0 + aa * d;
+        if d.abs() < TINY {
+            d = TINY;
+        }
+        c = 1.0 + aa / c;
+        if c.abs() < TINY {
+            c = TINY;
+        }
+        d = 1.0 / d;
+        let delta = d * c;
+        h *= delta;
+        if (delta - 1.0).abs() < EPSILON {
+            break;
+        }
+    }
+    h
+}
+
+// Returns ln(&Gamma;(x)) for x > 0, by the Lanczos approximation.
+fn ln_gamma(x: f64) -> f64 {
+    const COEFFICIENTS: [f64; 6] = [
+        76.18009172947146,
+        -86.50532032941677,
+        24.01409824083091,
+        -1.231739572450155,
+        0.1208650973866179e-2,
+        -0.5395239384953e-5,
+    ];
+    let mut y = x;
+    let tmp = x + 5.5;
+    let tmp = tmp - (x + 0.5) * tmp.ln();
+    let mut series = 1.000000000190015;
+    for c in COEFFICIENTS {
+        y += 1.0;
+        series += c / y;
+    }
+    -tmp + (2.5066282746310005 * series / x).ln()
 }
 
 impl fmt::Display for LinearRegression {
@@ -147,5 +312,61 @@ mod tests {
         assert!(log_y1 - (2.72 * x1 - 32.69) < 0.01);
         let y1 = log_y1.exp2();
         assert_eq!(y1, 3153999.1183853233); // 3_153_999 seconds ≈ 36.5 days
+
+        // hand-computed: F = SSR / (RSS / (n - 2)) = R^2 / (1 - R^2) * (n - 2)
+        assert!((lr.f_statistic() - 785.4305345565708).abs() < 1e-6);
+        assert!((lr.r2_adjusted() - 0.9961901401224054).abs() < 1e-9);
+        // F(1, 2) = t^2, where t follows the Student's t-distribution with 2 degrees of freedom,
+        // so p = 1 - t / sqrt(2 + t^2)
+        assert!((lr.p_value() - 0.0012707607089211814).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_linear_regression_residuals() {
+        let y = [0.024, 0.122, 0.88, 6.707]; // seconds of ThreeSum program
+        let x = [1000.0, 2000.0, 4000.0, 8000.0]; // number of input integers
+        let log_y = log_vectored(&y, 2.0);
+        let log_x = log_vectored(&x, 2.0);
+        let lr = LinearRegression::new(&log_x, &log_y).unwrap();
+
+        let fitted = lr.predict_all(&log_x);
+        assert_eq!(fitted.len(), 4);
+        assert_eq!(fitted[2], lr.predict(log_x[2]));
+
+        let residuals = lr.residuals(&log_x, &log_y).unwrap();
+        for i in 0..4 {
+            assert_eq!(residuals[i], log_y[i] - fitted[i]);
+        }
+        // the least squares residuals sum to zero, and their squares sum to RSS
+        assert!(residuals.iter().sum::<f64>().abs() < 1e-9);
+        let rss: f64 = residuals.iter().map(|r| r * r).sum();
+        assert!((rss - lr.rss).abs() < 1e-9);
+
+        assert!(lr.residuals(&log_x, &log_y[1..]).is_err());
+        assert!(lr.predict_all(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_linear_regression_goodness_of_fit() {
+        // SSR = 3.2, RSS = 1.8, SST = 5
+        let lr = LinearRegression::new(&[1.0, 2.0, 3.0, 4.0], &[1.0, 3.0, 2.0, 4.0]).unwrap();
+        assert!((lr.slope() - 0.8).abs() < 1e-12);
+        assert!((lr.r2() - 0.64).abs() < 1e-12);
+        assert!((lr.r2_adjusted() - 0.46).abs() < 1e-12);
+        assert!((lr.f_statistic() - 32.0 / 9.0).abs() < 1e-12);
+        assert!((lr.p_value() - 0.2).abs() < 1e-9);
+
+        // F(1, 1) = t^2, where t follows the Cauchy distribution, so p = 1 - 2 atan(t) / pi
+        let lr = LinearRegression::new(&[1.0, 2.0, 3.0], &[1.0, 3.0, 2.0]).unwrap();
+        let t = lr.f_statistic().sqrt();
+        let p = 1.0 - 2.0 * t.atan() / std::f64::consts::PI;
+        assert!((lr.p_value() - p).abs() < 1e-9);
+
+        // a perfect fit
+        let lr = LinearRegression::new(&[1.0, 2.0, 3.0], &[2.0, 4.0, 6.0]).unwrap();
+        assert_eq!(lr.p_value(), 0.0);
+
+        let lr = LinearRegression::new(&[1.0, 2.0], &[1.0, 3.0]).unwrap();
+        assert!(lr.p_value().is_nan());
     }
 }
@@ -0,0 +1,263 @@
+use crate::error::InvalidArgument;
+use std::fmt;
+
+/// Computes least squares solution to <em>y</em> = &beta;<sub>0</sub> + &beta;<sub>1</sub>
+/// <em>x</em> + &hellip; + &beta;<sub><em>d</em></sub> <em>x</em><sup><em>d</em></sup>.
+///
+/// The methods of `PolynomialRegression` struct performs a polynomial regression on an set of
+/// <em>n</em> data points (<em>y<sub>i</sub></em>, <em>x<sub>i</sub></em>).  That is, it fits a
+/// polynomial of degree <em>d</em> (where <em>y</em> is the response variable, <em>x</em> is the
+/// predictor variable, and the &beta;<sub><em>j</em></sub> are the <em>regression
+/// coefficients</em>) that minimizes the sum of squared residuals of the multiple regression
+/// model.  It also computes the associated coefficient of determination <em>R</em><sup>2</sup>.
+///
+/// This implementation performs a QR-decomposition of the underlying Vandermonde matrix by
+/// Householder reflections, which is numerically more stable than solving the normal equations.
+/// It takes &Theta;(<em>n</em> <em>d</em><sup>2</sup>) time.  If the Vandermonde matrix is rank
+/// deficient, for example when there are fewer than <em>d</em> + 1 distinct values of
+/// <em>x</em>, the degree is reduced until it is of full rank.
+///
+/// For additional documentation, see <a href="https://algs4.cs.princeton.edu/14analysis">Section
+/// 1.4</a> of <i>Algorithms, 4th Edition</i> by Robert Sedgewick and Kevin Wayne.
+pub struct PolynomialRegression {
+    beta: Vec<f64>, // beta[j] = coefficient of x^j
+    sse: f64,       // sum of squares due to error
+    sst: f64,       // total sum of squares
+}
+
+impl PolynomialRegression {
+    /// Performs a polynomial regression on the data points `(y[i], x[i])`.
+    ///
+    /// # Params
+    /// - `x`: the values of the predictor variable
+    /// - `y`: the corresponding values of the response variable
+    /// - `degree`: the degree of the polynomial to fit
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidArgument` if the lengths of the two slices are not equal, or if `degree`
+    /// is not less than the number of data points.
+    pub fn new(x: &[f64], y: &[f64], degree: usize) -> Result<Self, InvalidArgument> {
+        if x.len() != y.len() {
+            return Err(InvalidArgument("array length are not equal".to_string()));
+        }
+        let n = x.len();
+        if degree >= n {
+            return Err(InvalidArgument(format!(
+                "degree {} is not less than the number of data points {}",
+                degree, n
+            )));
+        }
+
+        // find the least squares solution of the highest degree that is of full rank
+        let mut degree = degree;
+        let beta = loop {
+            if let Some(beta) = least_squares(x, y, degree) {
+                break beta;
+            }
+            // a single column of ones is of full rank as n > 0
+            degree -= 1;
+        };
+
+        let mean = y.iter().sum::<f64>() / n as f64;
+        let sst = y.iter().map(|yi| (yi - mean) * (yi - mean)).sum();
+        let mut regression = PolynomialRegression {
+            beta,
+            sse: 0.0,
+            sst,
+        };
+        regression.sse = x
+            .iter()
+            .zip(y)
+            .map(|(&xi, &yi)| {
+                let residual = yi - regression.predict(xi);
+                residual * residual
+            })
+            .sum();
+        Ok(regression)
+    }
+
+    /// Returns the `j`th regression coefficient, that is, the coefficient of
+    /// <em>x</em><sup><em>j</em></sup>.  It is zero if `j` is greater than the degree.
+    pub fn coefficient(&self, j: usize) -> f64 {
+        self.beta.get(j).copied().unwrap_or(0.0)
+    }
+
+    /// Returns the degree of the polynomial to fit, which may be less than the requested degree
+    /// if the data points do not determine a polynomial of that degree.
+    pub fn degree(&self) -> usize {
+        self.beta.len() - 1
+    }
+
+    /// Returns the coefficient of determination <em>R</em><sup>2</sup>, a real number between 0
+    /// and 1.  It is 1 if all the values of the response variable are equal.
+    pub fn r2(&self) -> f64 {
+        if self.sst == 0.0 {
+            1.0 // constant function
+        } else {
+            1.0 - self.sse / self.sst
+        }
+    }
+
+    /// Returns the expected response `y` given the value of the predictor variable `x`.
+    ///
+    /// # Params
+    /// - `x`: the value of the predictor variable
+    pub fn predict(&self, x: f64) -> f64 {
+        // Horner's method
+        self.beta.iter().rev().fold(0.0, |y, b| y * x + b)
+    }
+}
+
+// Returns the coefficients of the polynomial of the given degree that minimizes the sum of
+// squared residuals, or `None` if the Vandermonde matrix is rank deficient.
+//
+// The Vandermonde matrix is factored in place into Householder vectors and R, as in JAMA.
+fn least_squares(x: &[f64], y: &[f64], degree: usize) -> Option<Vec<f64>> {
+    let n = x.len();
+    let m = degree + 1;
+
+    // qr[j][i] = x[i]^j, column-major
+    let mut qr: Vec<Vec<f64>> = Vec::with_capacity(m);
+    qr.push(vec![1.0; n]);
+    for j in 1..m {
+        let column = qr[j - 1].iter().zip(x).map(|(a, xi)| a * xi).collect();
+        qr.push(column);
+    }
+
+    let norms: Vec<f64> = qr.iter().map(|column| norm(column)).collect();
+    let mut r_diag = vec![0.0; m];
+    for k in 0..m {
+        // what is left of a column that depends on the previous ones is rounding error
+        let mut nrm = norm(&qr[k][k..]);
+        if nrm <= norms[k] * n as f64 * f64::EPSILON {
+            return None;
+        }
+        // form the k-th Householder vector
+        if qr[k][k] < 0.0 {
+            nrm = -nrm;
+        }
+        for a in &mut qr[k][k..] {
+            *a /= nrm;
+        }
+        qr[k][k] += 1.0;
+
+        // apply the transformation to the remaining columns
+        let (left, right) = qr.split_at_mut(k + 1);
+        let v = &left[k];
+        for column in right {
+            let s = dot(&v[k..], &column[k..]);
+            let s = -s / v[k];
+            for (a, vi) in column[k..].iter_mut().zip(&v[k..]) {
+                *a += s * vi;
+            }
+        }
+        r_diag[k] = -nrm;
+    }
+
+    // compute Q^T y
+    let mut z = y.to_vec();
+    for (k, v) in qr.iter().enumerate() {
+        let s = -dot(&v[k..], &z[k..]) / v[k];
+        for (zi, vi) in z[k..].iter_mut().zip(&v[k..]) {
+            *zi += s * vi;
+        }
+    }
+
+    // solve R beta = Q^T y by back substitution
+    let mut beta = vec![0.0; m];
+    for k in (0..m).rev() {
+        beta[k] = z[k] / r_diag[k];
+        for (zi, a) in z[..k].iter_mut().zip(&qr[k][..k]) {
+            *zi -= beta[k] * a;
+        }
+    }
+    Some(beta)
+}
+
+fn norm(a: &[f64]) -> f64 {
+    a.iter().fold(0.0, |s, x| s.hypot(*x))
+}
+
+fn dot(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+impl fmt::Display for PolynomialRegression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut terms = Vec::new();
+        for (j, b) in self.beta.iter().enumerate().rev() {
+            // skip the terms of negligible coefficients
+            if b.abs() < 1e-5 && j > 0 {
+                continue;
+            }
+            terms.push(match j {
+                0 => format!("{:.2}", b),
+                1 => format!("{:.2} n", b),
+                _ => format!("{:.2} n^{}", b, j),
+            });
+        }
+        write!(f, "{}  (R^2 = {:.3})", terms.join(" + "), self.r2())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_polynomial_regression_quadratic() {
+        // y = 3 - 2 x + 0.5 x^2
+        let x: Vec<f64> = (0..10).map(|i| i as f64).collect();
+        let y: Vec<f64> = x.iter().map(|x| 3.0 - 2.0 * x + 0.5 * x * x).collect();
+        let pr = PolynomialRegression::new(&x, &y, 2).unwrap();
+        assert_eq!(pr.degree(), 2);
+        assert!((pr.coefficient(0) - 3.0).abs() < 1e-9);
+        assert!((pr.coefficient(1) + 2.0).abs() < 1e-9);
+        assert!((pr.coefficient(2) - 0.5).abs() < 1e-9);
+        assert_eq!(pr.coefficient(3), 0.0);
+        assert!((pr.r2() - 1.0).abs() < 1e-12);
+        assert!((pr.predict(20.0) - 163.0).abs() < 1e-6);
+        assert_eq!(pr.to_string(), "0.50 n^2 + -2.00 n + 3.00  (R^2 = 1.000)");
+
+        // a cubic fit finds no cubic term
+        let pr = PolynomialRegression::new(&x, &y, 3).unwrap();
+        assert_eq!(pr.degree(), 3);
+        assert!(pr.coefficient(3).abs() < 1e-9);
+        assert!((pr.coefficient(2) - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_polynomial_regression_three_sum() {
+        let y = [0.024, 0.122, 0.88, 6.707]; // seconds of ThreeSum program
+        let x = [1000.0, 2000.0, 4000.0, 8000.0]; // number of input integers
+        let pr = PolynomialRegression::new(&x, &y, 3).unwrap();
+        // the cubic interpolates the four points exactly
+        for (xi, yi) in x.iter().zip(y) {
+            assert!((pr.predict(*xi) - yi).abs() < 1e-9);
+        }
+        assert!((pr.r2() - 1.0).abs() < 1e-12);
+
+        let pr = PolynomialRegression::new(&x, &y, 1).unwrap();
+        assert!(pr.r2() < 1.0);
+        assert!(pr.coefficient(1) > 0.0);
+    }
+
+    #[test]
+    fn test_polynomial_regression_degenerate() {
+        assert!(PolynomialRegression::new(&[1.0, 2.0], &[1.0], 0).is_err());
+        assert!(PolynomialRegression::new(&[1.0, 2.0], &[1.0, 2.0], 2).is_err());
+        assert!(PolynomialRegression::new(&[], &[], 0).is_err());
+
+        // only two distinct values of x determine at most a straight line
+        let pr =
+            PolynomialRegression::new(&[1.0, 1.0, 2.0, 2.0], &[1.0, 3.0, 5.0, 7.0], 3).unwrap();
+        assert_eq!(pr.degree(), 1);
+        assert!((pr.coefficient(1) - 4.0).abs() < 1e-9);
+        assert!((pr.coefficient(0) + 2.0).abs() < 1e-9);
+
+        let pr = PolynomialRegression::new(&[5.0], &[2.0], 0).unwrap();
+        assert_eq!(pr.predict(100.0), 2.0);
+        assert_eq!(pr.r2(), 1.0);
+    }
+}
@@ -1,4 +1,6 @@
+pub(crate) mod deque;
 pub(crate) mod linkedqueue;
+pub(crate) mod randomizedqueue;
 pub(crate) mod resizingqueue;
 pub(crate) mod svecque;
 
@@ -0,0 +1,260 @@
+use std::fmt;
+use std::ptr::NonNull;
+
+/// A double-ended queue of generic items.
+///
+/// It supports adding and removing items at both the front and the back, along with methods for
+/// peeking at the front and the back items, testing if the deque is empty, and iterating through
+/// the items from the front to the back.
+///
+/// The `push_front`, `push_back`, `pop_front`, `pop_back`, `peek_front`, `peek_back`, `len`, and
+/// `is_empty` operations all take constant time in the worst case.
+///
+/// For additional documentation, see <a href="https://algs4.cs.princeton.edu/13stacks">Section
+/// 1.3</a> of <i>Algorithms, 4th Edition</i> by Robert Sedgewick and Kevin Wayne.
+///
+/// # Implementation considerations
+///
+/// This implementation uses a doubly linked list of `Option<NonNull<Node<T>>>`, so `Deque<T>` is
+/// covariant over type `T`, like [`crate::LinkedQueue`].  See the implementation considerations of
+/// [`crate::LinkedQueue`] for the alternatives.
+pub struct Deque<T> {
+    front: Option<NonNull<Node<T>>>, // beginning of deque
+    back: Option<NonNull<Node<T>>>,  // end of deque
+    n: usize,                        // number of elements on deque
+}
+
+struct Node<T> {
+    item: T,
+    prev: Option<NonNull<Node<T>>>,
+    next: Option<NonNull<Node<T>>>,
+}
+
+impl<T> Node<T> {
+    fn new(item: T) -> Self {
+        Node {
+            item,
+            prev: None,
+            next: None,
+        }
+    }
+}
+
+impl<T> Deque<T> {
+    /// Initializes an empty deque.
+    pub fn new() -> Self {
+        let dq = Deque {
+            front: None,
+            back: None,
+            n: 0,
+        };
+        debug_assert!(dq.check());
+        dq
+    }
+
+    /// Is this deque empty?
+    pub fn is_empty(&self) -> bool {
+        self.front.is_none()
+    }
+
+    /// Returns the number of items on this deque.
+    pub fn len(&self) -> usize {
+        self.n
+    }
+
+    /// Adds the item to the front of this deque.
+    pub fn push_front(&mut self, item: T) {
+        let mut node = Box::new(Node::new(item));
+        node.next = self.front;
+        let new_front = Some(NonNull::from(Box::leak(node)));
+        match self.front {
+            None => self.back = new_front,
+            Some(mut front) => unsafe {
+                front.as_mut().prev = new_front;
+            },
+        }
+        self.front = new_front;
+        self.n += 1;
+        debug_assert!(self.check());
+    }
+
+    /// Adds the item to the back of this deque.
+    pub fn push_back(&mut self, item: T) {
+        let mut node = Box::new(Node::new(item));
+        node.prev = self.back;
+        let new_back = Some(NonNull::from(Box::leak(node)));
+        match self.back {
+            None => self.front = new_back,
+            Some(mut back) => unsafe {
+                back.as_mut().next = new_back;
+            },
+        }
+        self.back = new_back;
+        self.n += 1;
+        debug_assert!(self.check());
+    }
+
+    /// Removes and returns the item at the front of this deque, or `None` if the deque is empty.
+    pub fn pop_front(&mut self) -> Option<T> {
+        let front = unsafe { Box::from_raw(self.front?.as_ptr()) };
+        self.front = front.next;
+        match self.front {
+            None => self.back = None,
+            Some(mut new_front) => unsafe {
+                new_front.as_mut().prev = None;
+            },
+        }
+        self.n -= 1;
+        debug_assert!(self.check());
+        Some(front.item)
+    }
+
+    /// Removes and returns the item at the back of this deque, or `None` if the deque is empty.
+    pub fn pop_back(&mut self) -> Option<T> {
+        let back = unsafe { Box::from_raw(self.back?.as_ptr()) };
+        self.back = back.prev;
+        match self.back {
+            None => self.front = None,
+            Some(mut new_back) => unsafe {
+                new_back.as_mut().next = None;
+            },
+        }
+        self.n -= 1;
+        debug_assert!(self.check());
+        Some(back.item)
+    }
+
+    /// Returns (but does not remove) the item at the front of this deque.
+    pub fn peek_front(&self) -> Option<&T> {
+        unsafe { self.front.map(|non_null| &(*non_null.as_ptr()).item) }
+    }
+
+    /// Returns (but does not remove) the item at the back of this deque.
+    pub fn peek_back(&self) -> Option<&T> {
+        unsafe { self.back.map(|non_null| &(*non_null.as_ptr()).item) }
+    }
+
+    /// Returns an iterator that iterates over the items in this deque from the front to the back.
+    ///
+    /// The iterator is double-ended, so `iter().rev()` iterates from the back to the front.
+    pub fn iter(&self) -> DequeIter<'_, T> {
+        DequeIter {
+            front: unsafe { self.front.map(|non_null| &*non_null.as_ptr()) },
+            back: unsafe { self.back.map(|non_null| &*non_null.as_ptr()) },
+            len: self.n,
+        }
+    }
+
+    // Check internal invariants.
+    fn check(&self) -> bool {
+        if self.n == 0 {
+            return self.front.is_none() && self.back.is_none();
+        }
+        if self.front.is_none() || self.back.is_none() {
+            return false;
+        }
+        unsafe {
+            if (*self.front.unwrap().as_ptr()).prev.is_some() {
+                return false;
+            }
+            if (*self.back.unwrap().as_ptr()).next.is_some() {
+                return false;
+            }
+        }
+
+        // check internal consistency of instance variable n, and the prev links
+        let mut count_nodes: usize = 0;
+        let mut prev = None;
+        let mut x = self.front;
+        while let Some(node) = x {
+            if count_nodes > self.n {
+                return false;
+            }
+            unsafe {
+                if (*node.as_ptr()).prev != prev {
+                    return false;
+                }
+                prev = x;
+                x = (*node.as_ptr()).next;
+            }
+            count_nodes += 1;
+        }
+        count_nodes == self.n && prev == self.back
+    }
+}
+
+impl<T> Default for Deque<T> {
+    fn default() -> Self {
+        Deque::new()
+    }
+}
+
+impl<T> Drop for Deque<T> {
+    fn drop(&mut self) {
+        while self.pop_front().is_some() {}
+    }
+}
+
+pub struct DequeIter<'a, T> {
+    front: Option<&'a Node<T>>,
+    back: Option<&'a Node<T>>,
+    len: usize, // number of items not yet yielded
+}
+
+impl<'a, T> Iterator for DequeIter<'a, T> {
+    type Item = &'a T;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.front.map(|node| {
+            self.len -= 1;
+            unsafe {
+                self.front = node.next.map(|non_null| &*non_null.as_ptr());
+            }
+            &node.item
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for DequeIter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.back.map(|node| {
+            self.len -= 1;
+            unsafe {
+                self.back = node.prev.map(|non_null| &*non_null.as_ptr());
+            }
+            &node.item
+        })
+    }
+}
+
+impl<T: Clone> Clone for Deque<T> {
+    fn clone(&self) -> Self {
+        let mut dq = Deque::new();
+        for x in self.iter() {
+            dq.push_back(x.clone());
+        }
+        dq
+    }
+}
+
+/// Implementing `std::fmt::Display` will automatically implement the `ToString` trait for
+/// `Deque<T>`, allowing the usage of the `.to_string()` method.
+impl<T: fmt::Display> fmt::Display for Deque<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut s = String::new();
+        for x in self.iter() {
+            s.push_str(&x.to_string());
+            s.push(' ');
+        }
+        write!(f, "{}", s)
+    }
+}
@@ -1,4 +1,5 @@
 use std::fmt;
+use std::marker::PhantomData;
 use std::ptr::NonNull;
 
 /// A first-in-first-out (FIFO) queue of generic items.
@@ -95,12 +96,11 @@ impl<T> LinkedQueue<T> {
     pub fn enqueue(&mut self, item: T) {
         // Add to the back.
         let new_back = Some(NonNull::new(Box::into_raw(Box::new(Node::new(item)))).unwrap());
-        if self.back.is_none() {
-            self.front = new_back;
-        } else {
-            unsafe {
-                self.back.unwrap().as_mut().next = new_back;
-            }
+        match self.back {
+            None => self.front = new_back,
+            Some(mut back) => unsafe {
+                back.as_mut().next = new_back;
+            },
         }
         self.back = new_back;
         self.n += 1;
@@ -128,6 +128,13 @@ impl<T> LinkedQueue<T> {
         unsafe { self.front.map(|non_null| &(*non_null.as_ptr()).item) }
     }
 
+    /// Returns a mutable reference to the item least recently added to this queue, or `None` if
+    /// the queue is empty.
+    pub fn peek_mut(&mut self) -> Option<&mut T> {
+        // `&mut self` guarantees that no other reference to the front node is alive.
+        unsafe { self.front.map(|non_null| &mut (*non_null.as_ptr()).item) }
+    }
+
     /// Returns an iterator that iterates over the items in this bag in FIFO order.
     pub fn iter(&self) -> LinedQueueIter<'_, T> {
         LinedQueueIter {
@@ -135,6 +142,15 @@ impl<T> LinkedQueue<T> {
         }
     }
 
+    /// Returns an iterator that iterates over mutable references to the items in this queue in
+    /// FIFO order.
+    pub fn iter_mut(&mut self) -> LinkedQueueIterMut<'_, T> {
+        LinkedQueueIterMut {
+            current: self.front,
+            _marker: PhantomData,
+        }
+    }
+
     // Check internal invariants.
     fn check(&self) -> bool {
         if self.n == 0 {
@@ -224,6 +240,28 @@ impl<'a, T> Iterator for LinedQueueIter<'a, T> {
     }
 }
 
+// It holds raw pointers instead of `&'a mut Node<T>`, because a node's `next` must not be
+// borrowed mutably while the item of the node is lent out.  The marker ties the lifetime of the
+// yielded items to the mutable borrow of the queue.
+pub struct LinkedQueueIterMut<'a, T> {
+    current: Option<NonNull<Node<T>>>,
+    _marker: PhantomData<&'a mut Node<T>>,
+}
+
+impl<'a, T> Iterator for LinkedQueueIterMut<'a, T> {
+    type Item = &'a mut T;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.current.map(|non_null| {
+            let node = non_null.as_ptr();
+            // Each node is visited once, so the yielded references never alias.
+            unsafe {
+                self.current = (*node).next;
+                &mut (*node).item
+            }
+        })
+    }
+}
+
 impl<T: Clone> Clone for LinkedQueue<T> {
     fn clone(&self) -> Self {
         let mut newq = LinkedQueue::new();
@@ -234,6 +272,49 @@ impl<T: Clone> Clone for LinkedQueue<T> {
     }
 }
 
+pub struct LinkedQueueIntoIter<T> {
+    moved_queue: LinkedQueue<T>,
+}
+
+/// Consumes the queue, yielding the items in FIFO order.
+impl<T> IntoIterator for LinkedQueue<T> {
+    type Item = T;
+    type IntoIter = LinkedQueueIntoIter<T>;
+    fn into_iter(self) -> Self::IntoIter {
+        LinkedQueueIntoIter { moved_queue: self }
+    }
+}
+
+impl<T> Iterator for LinkedQueueIntoIter<T> {
+    type Item = T;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.moved_queue.dequeue()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.moved_queue.len();
+        (len, Some(len))
+    }
+}
+
+/// Enqueues the items in order.
+impl<T> FromIterator<T> for LinkedQueue<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut qu = LinkedQueue::new();
+        qu.extend(iter);
+        qu
+    }
+}
+
+/// Enqueues the items in order.
+impl<T> Extend<T> for LinkedQueue<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.enqueue(item);
+        }
+    }
+}
+
 /// Implementing `std::fmt::Display` will automatically implement the `ToString` trait for
 /// `LinkedQueue<T>`, allowing the usage of the `.to_string()` method.
 impl<T: fmt::Display> fmt::Display for LinkedQueue<T> {
@@ -0,0 +1,130 @@
+use crate::std_random::StdRandom;
+use crate::vec::SVec;
+use std::fmt;
+
+/// A randomized queue of generic items.
+///
+/// It is similar to a stack or queue, except that the item removed is chosen uniformly at random
+/// among items in the data structure.  It supports the usual `enqueue` and `dequeue` operations,
+/// along with methods for sampling a random item without removing it, testing if the queue is
+/// empty, and iterating through the items in a uniformly random order.
+///
+/// The `enqueue` operation takes constant amortized time, and the `dequeue`, `sample`, `len`, and
+/// `is_empty` operations take constant amortized time, too.  Creating an iterator takes time
+/// proportional to the number of items, and each iteration step takes constant time.
+///
+/// For additional documentation, see <a
+/// href="https://coursera.cs.princeton.edu/algs4/assignments/queues/specification.php">Programming
+/// Assignment 2</a> of the Coursera course <i>Algorithms, Part I</i> by Robert Sedgewick and Kevin
+/// Wayne.
+///
+/// # Implementation considerations
+///
+/// This implementation uses a resizing vector [`crate::SVec`].  `dequeue` swaps the randomly chosen
+/// item with the last one, then pops it, so no hole is left in the vector.
+///
+/// The random numbers are generated by [`crate::StdRandom`].  `new` seeds it randomly, while
+/// `with_seed` makes the random choices reproducible.
+pub struct RandomizedQueue<T> {
+    items: SVec<T>,
+    rng: StdRandom,
+}
+
+impl<T> RandomizedQueue<T> {
+    /// Initializes an empty randomized queue with a random seed.
+    pub fn new() -> Self {
+        RandomizedQueue {
+            items: SVec::new(),
+            rng: StdRandom::new(),
+        }
+    }
+
+    /// Initializes an empty randomized queue whose random choices are determined by `seed`.
+    pub fn with_seed(seed: u64) -> Self {
+        RandomizedQueue {
+            items: SVec::new(),
+            rng: StdRandom::with_seed(seed),
+        }
+    }
+
+    /// Is this randomized queue empty?
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Returns the number of items on this randomized queue.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Adds the item to this randomized queue.
+    pub fn enqueue(&mut self, item: T) {
+        self.items.push(item);
+    }
+
+    /// Removes and returns a uniformly random item, or `None` if this randomized queue is empty.
+    pub fn dequeue(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        let i = self.rng.uniform_int(self.len());
+        Some(self.items.swap_remove(i))
+    }
+
+    /// Returns (but does not remove) a uniformly random item, or `None` if this randomized queue
+    /// is empty.
+    pub fn sample(&self) -> Option<&T> {
+        if self.is_empty() {
+            return None;
+        }
+        let i = self.rng.uniform_int(self.len());
+        Some(&self.items[i])
+    }
+
+    /// Returns an iterator that iterates over the items in this randomized queue in a uniformly
+    /// random order.  Each iterator has its own order, independent of the other iterators.
+    pub fn iter(&self) -> RandomizedQueueIter<'_, T> {
+        let mut order: Vec<usize> = (0..self.len()).collect();
+        self.rng.shuffle(&mut order);
+        RandomizedQueueIter {
+            items: &self.items,
+            order,
+        }
+    }
+}
+
+impl<T> Default for RandomizedQueue<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub struct RandomizedQueueIter<'a, T> {
+    items: &'a [T],
+    order: Vec<usize>, // indices not yet yielded, yielded from the back
+}
+
+impl<'a, T> Iterator for RandomizedQueueIter<'a, T> {
+    type Item = &'a T;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.order.pop().map(|i| &self.items[i])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.order.len(), Some(self.order.len()))
+    }
+}
+
+/// Implementing `std::fmt::Display` will automatically implement the `ToString` trait for
+/// `RandomizedQueue<T>`, allowing the usage of the `.to_string()` method.  The items are written
+/// in a random order.
+impl<T: fmt::Display> fmt::Display for RandomizedQueue<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut s = String::new();
+        for x in self.iter() {
+            s.push_str(&x.to_string());
+            s.push(' ');
+        }
+        write!(f, "{}", s)
+    }
+}
@@ -61,7 +61,13 @@ impl<T> ResizingQueue<T> {
 
     /// Returns (but does not remove) the item least recently added to this queue.
     pub fn peek(&self) -> Option<&T> {
-        self.data.back()
+        self.data.front()
+    }
+
+    /// Returns a mutable reference to the item least recently added to this queue, or `None` if
+    /// the queue is empty.
+    pub fn peek_mut(&mut self) -> Option<&mut T> {
+        self.data.front_mut()
     }
 
     /// Returns an iterator that iterates over the items in this queue in FIFO order.
@@ -71,6 +77,12 @@ impl<T> ResizingQueue<T> {
             cursor: 0, // points to the front Node
         }
     }
+
+    /// Returns an iterator that iterates over mutable references to the items in this queue in
+    /// FIFO order.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.data.iter_mut()
+    }
 }
 
 impl<T> Default for ResizingQueue<T> {
@@ -97,6 +109,50 @@ impl<'a, T> Iterator for ResizingQueueIter<'a, T> {
     }
 }
 
+pub struct ResizingQueueIntoIter<T> {
+    iter: std::collections::vec_deque::IntoIter<T>,
+}
+
+/// Consumes the queue, yielding the items in FIFO order.
+impl<T> IntoIterator for ResizingQueue<T> {
+    type Item = T;
+    type IntoIter = ResizingQueueIntoIter<T>;
+    fn into_iter(self) -> Self::IntoIter {
+        ResizingQueueIntoIter {
+            iter: self.data.into_iter(),
+        }
+    }
+}
+
+impl<T> Iterator for ResizingQueueIntoIter<T> {
+    type Item = T;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// Enqueues the items in order.
+impl<T> FromIterator<T> for ResizingQueue<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut qu = ResizingQueue::new();
+        qu.extend(iter);
+        qu
+    }
+}
+
+/// Enqueues the items in order.
+impl<T> Extend<T> for ResizingQueue<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.enqueue(item);
+        }
+    }
+}
+
 /// Implementing `std::fmt::Display` will automatically implement the `ToString` trait for
 /// `ResizingQueue<T>`, allowing the usage of the `.to_string()` method.
 impl<T: fmt::Display> fmt::Display for ResizingQueue<T> {
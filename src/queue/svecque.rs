@@ -7,8 +7,12 @@ use std::ptr;
 /// It supports the usual `enqueue` and `dequeue` operations, along with methods for peeking at the first
 /// item, testing if the queue is empty, and iterating through the items in FIFO order.
 ///
-/// The `enqueue`, `dequeue`, `peek`, `len`, and `is_empty` operations all take constant time in the
-/// worst case.
+/// It can also be used as a double-ended queue: `enqueue_front` adds an item to the front,
+/// `dequeue_back` removes the item at the back, and `peek_back` peeks at the item at the back.
+///
+/// The `peek`, `peek_back`, `len`, and `is_empty` operations take constant time in the worst case;
+/// the `enqueue`, `dequeue`, `enqueue_front`, and `dequeue_back` operations take constant amortized
+/// time.
 ///
 /// For additional documentation, see <a href="https://algs4.cs.princeton.edu/13stacks">Section
 /// 1.3</a> of <i>Algorithms, 4th Edition</i> by Robert Sedgewick and Kevin Wayne.
@@ -79,16 +83,73 @@ impl<T> SVecQue<T> {
         } else {
             let elem = unsafe { Some(ptr::read(self.ptr().add(self.front))) };
             self.front += 1;
-            if self.len() == self.buf.cap / 4 {
-                self.move_to_front();
-                self.buf.shrink();
+            self.shrink_if_sparse();
+            elem
+        }
+    }
+
+    /// Adds the item to the front of this queue, so that it is the next item to be dequeued.
+    pub fn enqueue_front(&mut self, elem: T) {
+        if self.front == 0 {
+            if 2 * self.len() >= self.cap() {
+                self.buf.grow();
             }
+            self.move_to_center();
+        }
+
+        self.front -= 1;
+        unsafe {
+            ptr::write(self.ptr().add(self.front), elem);
+        }
+    }
+
+    /// Removes and returns the item most recently added to the back of this queue, or `None` if
+    /// the queue is empty.
+    pub fn dequeue_back(&mut self) -> Option<T> {
+        if self.is_empty() {
+            None
+        } else {
+            self.back -= 1;
+            let elem = unsafe { Some(ptr::read(self.ptr().add(self.back))) };
+            self.shrink_if_sparse();
             elem
         }
     }
 
     pub fn peek(&self) -> Option<&T> {
-        unsafe { Some(&*self.ptr().add(self.front)) }
+        if self.is_empty() {
+            None
+        } else {
+            unsafe { Some(&*self.ptr().add(self.front)) }
+        }
+    }
+
+    /// Returns a mutable reference to the item at the front of this queue, or `None` if the queue
+    /// is empty.
+    pub fn peek_mut(&mut self) -> Option<&mut T> {
+        if self.is_empty() {
+            None
+        } else {
+            unsafe { Some(&mut *self.ptr().add(self.front)) }
+        }
+    }
+
+    /// Returns (but does not remove) the item at the back of this queue.
+    pub fn peek_back(&self) -> Option<&T> {
+        if self.is_empty() {
+            None
+        } else {
+            unsafe { Some(&*self.ptr().add(self.back - 1)) }
+        }
+    }
+
+    // Halves the capacity when the queue is one-quarter full.  The elements are moved to the
+    // front first, so they fit in the first half, no matter which end was removed from.
+    fn shrink_if_sparse(&mut self) {
+        if self.len() == self.buf.cap / 4 {
+            self.move_to_front();
+            self.buf.shrink();
+        }
     }
 
     fn move_to_front(&mut self) {
@@ -100,6 +161,18 @@ impl<T> SVecQue<T> {
         self.back -= diff;
     }
 
+    // Moves the elements to the middle of the buffer, leaving (almost) equal room at both ends.
+    // The caller guarantees that the buffer is not full, so there is room at the front.
+    fn move_to_center(&mut self) {
+        let len = self.len();
+        let new_front = (self.cap() - len).div_ceil(2);
+        unsafe {
+            ptr::copy(self.ptr().add(self.front), self.ptr().add(new_front), len);
+        }
+        self.front = new_front;
+        self.back = new_front + len;
+    }
+
     pub fn iter(&self) -> SVecQueIter<'_, T> {
         SVecQueIter {
             buf: &self.buf,
@@ -107,6 +180,14 @@ impl<T> SVecQue<T> {
             back: self.back,
         }
     }
+
+    /// Returns an iterator that iterates over mutable references to the items in this queue in
+    /// FIFO order.
+    pub fn iter_mut(&mut self) -> impl DoubleEndedIterator<Item = &mut T> {
+        let items =
+            unsafe { std::slice::from_raw_parts_mut(self.ptr().add(self.front), self.len()) };
+        items.iter_mut()
+    }
 }
 
 pub struct SVecQueIter<'a, T> {
@@ -127,6 +208,23 @@ impl<'a, T> Iterator for SVecQueIter<'a, T> {
             None
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for SVecQueIter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            self.back -= 1;
+            let elem = unsafe { &*self.buf.ptr.as_ptr().add(self.back) };
+            Some(elem)
+        } else {
+            None
+        }
+    }
 }
 
 /// Implementing `std::fmt::Display` will automatically implement the `ToString` trait for
@@ -161,3 +259,58 @@ impl<T> Default for SVecQue<T> {
         Self::new()
     }
 }
+
+impl<T> Drop for SVecQue<T> {
+    fn drop(&mut self) {
+        unsafe {
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(
+                self.ptr().add(self.front),
+                self.len(),
+            ));
+        }
+        // deallocation is handled by RawVec
+    }
+}
+
+pub struct SVecQueIntoIter<T> {
+    moved_queue: SVecQue<T>,
+}
+
+/// Consumes the queue, yielding the items in FIFO order.
+impl<T> IntoIterator for SVecQue<T> {
+    type Item = T;
+    type IntoIter = SVecQueIntoIter<T>;
+    fn into_iter(self) -> Self::IntoIter {
+        SVecQueIntoIter { moved_queue: self }
+    }
+}
+
+impl<T> Iterator for SVecQueIntoIter<T> {
+    type Item = T;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.moved_queue.dequeue()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.moved_queue.len();
+        (len, Some(len))
+    }
+}
+
+/// Enqueues the items in order.
+impl<T> FromIterator<T> for SVecQue<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut qu = SVecQue::new();
+        qu.extend(iter);
+        qu
+    }
+}
+
+/// Enqueues the items in order.
+impl<T> Extend<T> for SVecQue<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.enqueue(item);
+        }
+    }
+}
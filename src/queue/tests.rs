@@ -1,4 +1,6 @@
+use super::deque::Deque;
 use super::linkedqueue::LinkedQueue;
+use super::randomizedqueue::RandomizedQueue;
 use super::resizingqueue::ResizingQueue;
 use super::svecque::SVecQue;
 
@@ -65,6 +67,7 @@ fn linked_queue_variance() {
     }
     fn _take_two<T>(_val1: T, _val2: T) {}
 
+    #[allow(clippy::extra_unused_lifetimes)]
     fn _bar<'a>() {
         let s: LinkedQueue<&'static str> = LinkedQueue::new();
         let _t: LinkedQueue<&'a str> = s;
@@ -97,6 +100,18 @@ fn resizing_queue_of_str() {
     assert_eq!(qu2.to_string(), "that is ");
 }
 
+#[test]
+fn resizing_queue_peek() {
+    let mut qu = ResizingQueue::new();
+    assert_eq!(qu.peek(), None);
+    qu.enqueue(1);
+    qu.enqueue(2);
+    qu.enqueue(3);
+    assert_eq!(qu.peek(), Some(&1));
+    qu.dequeue();
+    assert_eq!(qu.peek(), Some(&2));
+}
+
 #[test]
 fn svecque_of_str() {
     let mut qu = SVecQue::new();
@@ -122,3 +137,401 @@ fn svecque_of_str() {
     let qu2 = qu.clone();
     assert_eq!(qu2.to_string(), "that is ");
 }
+
+#[test]
+fn svecque_peek() {
+    assert_eq!(SVecQue::<i32>::new().peek(), None);
+    let mut qu = SVecQue::new();
+    qu.enqueue(1);
+    qu.enqueue(2);
+    assert_eq!(qu.peek(), Some(&1));
+    qu.dequeue();
+    assert_eq!(qu.peek(), Some(&2));
+    qu.dequeue();
+    assert_eq!(qu.peek(), None);
+}
+
+#[test]
+fn svecque_double_ended() {
+    let mut qu = SVecQue::new();
+    assert_eq!(qu.dequeue_back(), None);
+    assert_eq!(qu.peek_back(), None);
+
+    qu.enqueue_front(2);
+    qu.enqueue(3);
+    qu.enqueue_front(1);
+    qu.enqueue(4);
+    qu.enqueue_front(0);
+    assert_eq!(qu.len(), 5);
+    assert_eq!(qu.peek(), Some(&0));
+    assert_eq!(qu.peek_back(), Some(&4));
+    assert_eq!(qu.to_string(), "0 1 2 3 4 ");
+    assert_eq!(
+        qu.iter().rev().copied().collect::<Vec<_>>(),
+        [4, 3, 2, 1, 0]
+    );
+
+    let mut it = qu.iter();
+    assert_eq!(it.next(), Some(&0));
+    assert_eq!(it.next_back(), Some(&4));
+    assert_eq!(it.size_hint(), (3, Some(3)));
+    assert_eq!(it.next_back(), Some(&3));
+    assert_eq!(it.next(), Some(&1));
+    assert_eq!(it.next(), Some(&2));
+    assert_eq!(it.next(), None);
+    assert_eq!(it.next_back(), None);
+
+    assert_eq!(qu.dequeue_back(), Some(4));
+    assert_eq!(qu.dequeue(), Some(0));
+    assert_eq!(qu.dequeue_back(), Some(3));
+    assert_eq!(qu.dequeue_back(), Some(2));
+    assert_eq!(qu.dequeue(), Some(1));
+    assert!(qu.is_empty());
+    assert_eq!(qu.dequeue_back(), None);
+
+    // the clone keeps the order
+    qu.enqueue_front(20);
+    qu.enqueue_front(10);
+    qu.enqueue(30);
+    assert_eq!(qu.clone().to_string(), "10 20 30 ");
+}
+
+#[test]
+fn svecque_double_ended_resizing() {
+    // compare with std::collections::VecDeque, growing and shrinking at both ends
+    let mut qu = SVecQue::new();
+    let mut expected = std::collections::VecDeque::new();
+    for i in 0..1000 {
+        match i % 3 {
+            0 => {
+                qu.enqueue(i);
+                expected.push_back(i);
+            }
+            _ => {
+                qu.enqueue_front(i);
+                expected.push_front(i);
+            }
+        }
+    }
+    assert!(qu.iter().eq(expected.iter()));
+    for i in 0..990 {
+        if i % 2 == 0 {
+            assert_eq!(qu.dequeue_back(), expected.pop_back());
+        } else {
+            assert_eq!(qu.dequeue(), expected.pop_front());
+        }
+        assert_eq!(qu.len(), expected.len());
+    }
+    assert!(qu.iter().eq(expected.iter()));
+    for i in 0..100 {
+        qu.enqueue_front(i);
+        expected.push_front(i);
+        qu.enqueue(i);
+        expected.push_back(i);
+    }
+    assert!(qu.iter().rev().eq(expected.iter().rev()));
+    while let Some(x) = expected.pop_back() {
+        assert_eq!(qu.dequeue_back(), Some(x));
+    }
+    assert!(qu.is_empty());
+}
+
+#[test]
+fn deque_of_str() {
+    let mut dq: Deque<&str> = Deque::new();
+    assert_eq!(dq.iter().count(), 0);
+    let list = [
+        "to", "be", "or", "not", "to", "-", "be", "-", "-", "that", "-", "-", "-", "is",
+    ];
+    let mut popped = Vec::new();
+    for item in list {
+        if item != "-" {
+            dq.push_back(item);
+        } else if !dq.is_empty() {
+            popped.push(dq.pop_front().unwrap());
+        }
+    }
+    let popped_str = popped.join(" ");
+    let deque_len = format!("({} left on deque)", dq.len());
+    let output = format!("{} {}", popped_str, deque_len);
+    assert_eq!(output, "to be or not to be (2 left on deque)");
+    assert_eq!(dq.to_string(), "that is ");
+
+    // test clone
+    let dq2 = dq.clone();
+    assert_eq!(dq2.to_string(), "that is ");
+}
+
+#[test]
+fn deque_front_and_back() {
+    let mut dq = Deque::new();
+    assert_eq!(dq.peek_front(), None);
+    assert_eq!(dq.peek_back(), None);
+    assert_eq!(dq.pop_front(), None);
+    assert_eq!(dq.pop_back(), None);
+
+    dq.push_front(2);
+    dq.push_back(3);
+    dq.push_front(1);
+    dq.push_back(4);
+    assert_eq!(dq.len(), 4);
+    assert_eq!(dq.peek_front(), Some(&1));
+    assert_eq!(dq.peek_back(), Some(&4));
+    assert_eq!(dq.iter().copied().collect::<Vec<_>>(), [1, 2, 3, 4]);
+    assert_eq!(dq.iter().rev().copied().collect::<Vec<_>>(), [4, 3, 2, 1]);
+
+    let mut it = dq.iter();
+    assert_eq!(it.next(), Some(&1));
+    assert_eq!(it.next_back(), Some(&4));
+    assert_eq!(it.next_back(), Some(&3));
+    assert_eq!(it.next(), Some(&2));
+    assert_eq!(it.next(), None);
+    assert_eq!(it.next_back(), None);
+
+    assert_eq!(dq.pop_back(), Some(4));
+    assert_eq!(dq.pop_front(), Some(1));
+    assert_eq!(dq.pop_back(), Some(3));
+    assert_eq!(dq.peek_front(), Some(&2));
+    assert_eq!(dq.peek_back(), Some(&2));
+    assert_eq!(dq.pop_back(), Some(2));
+    assert!(dq.is_empty());
+    assert_eq!(dq.pop_front(), None);
+
+    // use as a stack at either end
+    dq.push_back(5);
+    dq.push_back(6);
+    assert_eq!(dq.pop_back(), Some(6));
+    dq.push_front(4);
+    assert_eq!(dq.pop_front(), Some(4));
+    assert_eq!(dq.to_string(), "5 ");
+}
+
+#[test]
+fn deque_drop() {
+    let mut dq: Deque<String> = Deque::new();
+    let list: Vec<String> = [
+        "to", "be", "or", "not", "to", "-", "be", "-", "-", "that", "-", "-", "-", "is",
+    ]
+    .iter()
+    .map(|x| x.to_string())
+    .collect();
+    for (i, item) in list.into_iter().enumerate() {
+        if i % 2 == 0 {
+            dq.push_back(item);
+        } else {
+            dq.push_front(item);
+        }
+    }
+    assert_eq!(dq.pop_back(), Some("-".to_string()));
+    // Drop should be good, no memory issue.
+}
+
+// The following function should compile.  Deque<T> should be covariant over T, see
+// `linked_queue_variance`.
+#[test]
+fn deque_variance() {
+    fn _two_refs<'short, 'long: 'short>(a: Deque<&'short str>, b: Deque<&'long str>) {
+        _take_two(a, b);
+    }
+    fn _take_two<T>(_val1: T, _val2: T) {}
+
+    #[allow(clippy::extra_unused_lifetimes)]
+    fn _bar<'a>() {
+        let s: Deque<&'static str> = Deque::new();
+        let _t: Deque<&'a str> = s;
+    }
+}
+
+#[test]
+fn randomized_queue_dequeue_all() {
+    let mut qu = RandomizedQueue::new();
+    assert_eq!(qu.dequeue(), None);
+    assert_eq!(qu.sample(), None);
+    let n = 1000;
+    for i in 0..n {
+        qu.enqueue(i);
+    }
+    assert_eq!(qu.len(), n);
+    let sampled = *qu.sample().unwrap();
+    assert!(sampled < n);
+    assert_eq!(qu.len(), n);
+
+    let mut seen = vec![false; n];
+    let mut in_order = true;
+    for k in 0..n {
+        let i = qu.dequeue().unwrap();
+        assert!(!seen[i], "{i} is dequeued twice");
+        seen[i] = true;
+        in_order &= i == k;
+    }
+    assert!(seen.iter().all(|&x| x));
+    assert!(!in_order, "dequeue should not be in FIFO order");
+    assert!(qu.is_empty());
+    assert_eq!(qu.dequeue(), None);
+}
+
+#[test]
+fn randomized_queue_iter() {
+    let mut qu: RandomizedQueue<String> = RandomizedQueue::with_seed(42);
+    assert_eq!(qu.iter().count(), 0);
+    let list = ["to", "be", "or", "not", "to", "be", "that", "is"];
+    for item in list {
+        qu.enqueue(item.to_string());
+    }
+    // the multiset of items is preserved by the iterator and by dequeue
+    let mut expected: Vec<&str> = list.to_vec();
+    expected.sort();
+    let mut items: Vec<&str> = qu.iter().map(|s| s.as_str()).collect();
+    assert_eq!(qu.iter().size_hint(), (8, Some(8)));
+    items.sort();
+    assert_eq!(items, expected);
+    assert_eq!(qu.to_string().split_whitespace().count(), 8);
+
+    // nested iterators are independent
+    let pairs = qu.iter().flat_map(|_| qu.iter()).count();
+    assert_eq!(pairs, 64);
+
+    let mut dequeued = Vec::new();
+    while let Some(s) = qu.dequeue() {
+        dequeued.push(s);
+    }
+    dequeued.sort();
+    assert_eq!(dequeued, expected);
+    qu.enqueue("left".to_string());
+    // Drop should be good, no memory issue.
+}
+
+#[test]
+fn randomized_queue_seed() {
+    let mut a = RandomizedQueue::with_seed(7);
+    let mut b = RandomizedQueue::with_seed(7);
+    for i in 0..100 {
+        a.enqueue(i);
+        b.enqueue(i);
+    }
+    assert!(a.iter().eq(b.iter()));
+    for _ in 0..100 {
+        assert_eq!(a.dequeue(), b.dequeue());
+    }
+
+    // zero is a valid seed, too
+    let mut qu = RandomizedQueue::with_seed(0);
+    qu.enqueue(1);
+    qu.enqueue(2);
+    assert!(qu.dequeue().is_some());
+    assert!(qu.dequeue().is_some());
+    assert!(qu.is_empty());
+}
+
+#[test]
+fn linked_queue_into_iter_from_iter() {
+    let data = ["to", "be", "or", "not"].map(String::from);
+    let qu: LinkedQueue<String> = data.clone().into_iter().collect();
+    assert_eq!(qu.len(), 4);
+    assert_eq!(qu.peek().map(String::as_str), Some("to"));
+    assert_eq!(qu.into_iter().collect::<Vec<_>>(), data);
+
+    let mut qu: LinkedQueue<i32> = (0..3).collect();
+    qu.extend(3..6);
+    let mut iter = qu.into_iter();
+    assert_eq!(iter.size_hint(), (6, Some(6)));
+    assert_eq!(iter.next(), Some(0));
+    assert_eq!(iter.collect::<Vec<_>>(), [1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn resizing_queue_into_iter_from_iter() {
+    let data = ["to", "be", "or", "not"].map(String::from);
+    let qu: ResizingQueue<String> = data.clone().into_iter().collect();
+    assert_eq!(qu.len(), 4);
+    assert_eq!(qu.peek().map(String::as_str), Some("to"));
+    assert_eq!(qu.into_iter().collect::<Vec<_>>(), data);
+
+    let mut qu: ResizingQueue<i32> = (0..3).collect();
+    qu.extend(3..6);
+    let mut iter = qu.into_iter();
+    assert_eq!(iter.size_hint(), (6, Some(6)));
+    assert_eq!(iter.next(), Some(0));
+    assert_eq!(iter.collect::<Vec<_>>(), [1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn svecque_into_iter_from_iter() {
+    let data = ["to", "be", "or", "not"].map(String::from);
+    let qu: SVecQue<String> = data.clone().into_iter().collect();
+    assert_eq!(qu.len(), 4);
+    assert_eq!(qu.peek().map(String::as_str), Some("to"));
+    assert_eq!(qu.into_iter().collect::<Vec<_>>(), data);
+
+    let mut qu: SVecQue<i32> = (0..3).collect();
+    qu.extend(3..100);
+    let mut iter = qu.into_iter();
+    assert_eq!(iter.size_hint(), (100, Some(100)));
+    assert_eq!(iter.next(), Some(0));
+    assert!(iter.eq(1..100));
+}
+
+#[test]
+fn svecque_drop() {
+    use std::rc::Rc;
+
+    let item = Rc::new(0);
+    let mut qu = SVecQue::new();
+    for _ in 0..10 {
+        qu.enqueue(Rc::clone(&item));
+    }
+    qu.dequeue();
+    assert_eq!(Rc::strong_count(&item), 10);
+
+    // dropping a partially consumed iterator drops the remaining items
+    let mut iter = qu.into_iter();
+    iter.next();
+    assert_eq!(Rc::strong_count(&item), 9);
+    drop(iter);
+    assert_eq!(Rc::strong_count(&item), 1);
+}
+
+#[test]
+fn linked_queue_peek_mut_iter_mut() {
+    let mut qu: LinkedQueue<String> = LinkedQueue::new();
+    assert_eq!(qu.peek_mut(), None);
+    qu.extend(["to", "be", "or"].map(String::from));
+    qu.peek_mut().unwrap().push('o');
+    for s in qu.iter_mut() {
+        s.make_ascii_uppercase();
+    }
+    assert_eq!(qu.dequeue().as_deref(), Some("TOO"));
+    assert_eq!(qu.to_string(), "BE OR ");
+    // the back is still linked after mutating through the iterator
+    qu.enqueue("not".to_string());
+    assert_eq!(qu.to_string(), "BE OR not ");
+}
+
+#[test]
+fn resizing_queue_peek_mut_iter_mut() {
+    let mut qu: ResizingQueue<i32> = ResizingQueue::new();
+    assert_eq!(qu.peek_mut(), None);
+    qu.extend([1, 2, 3]);
+    *qu.peek_mut().unwrap() += 10;
+    for x in qu.iter_mut() {
+        *x *= 2;
+    }
+    assert_eq!(qu.dequeue(), Some(22));
+    assert_eq!(qu.to_string(), "4 6 ");
+}
+
+#[test]
+fn svecque_peek_mut_iter_mut() {
+    let mut qu: SVecQue<i32> = SVecQue::new();
+    assert_eq!(qu.peek_mut(), None);
+    assert_eq!(qu.iter_mut().next(), None);
+    qu.extend([0, 1, 2, 3]);
+    qu.dequeue();
+    *qu.peek_mut().unwrap() += 10;
+    for x in qu.iter_mut() {
+        *x *= 2;
+    }
+    assert_eq!(qu.iter_mut().next_back(), Some(&mut 6));
+    assert_eq!(qu.dequeue(), Some(22));
+    assert_eq!(qu.to_string(), "4 6 ");
+}
@@ -1,28 +1,40 @@
 use crate::primitive::{PrimFloat, PrimInt};
 use std::io;
-use std::io::BufRead;
+use std::io::{BufRead, Cursor};
 use std::str::FromStr;
+use std::sync::Arc;
 
 /// Takes a [`BufRead`] for a *text input*, and yields the next entity until EOF is reached.
 ///
-/// A valid text input consists of delimiters and tokens.  The delimiters are ASCII whitespaces
-/// (U+0009 TAB, U+000A LF, U+000C FF, U+000D CR, or U+0020 SPACE).  The tokens consist of the other
-/// UTF-8 characters.
+/// A valid text input consists of delimiters and tokens.  By default, the delimiters are ASCII
+/// whitespaces (U+0009 TAB, U+000A LF, U+000C FF, U+000D CR, or U+0020 SPACE).  The tokens consist
+/// of the other UTF-8 characters.  Use [`Scanner::with_delimiter`] for custom delimiters.
 ///
 /// It operates in two modes, and the two modes' methods can be used together in any order.
 ///
-/// # Mode 1: token-by-token (delimiter: ASCII whitespaces)
+/// # Mode 1: token-by-token (default delimiter: ASCII whitespaces)
 /// methods:
 /// - `has_next`
+/// - `peek`
 /// - `next_token`
 /// - `next_int`
 /// - `next_float`
 /// - `next_bool`
 ///
-/// # Mode 2: line-by-line (delimiter: U+000A LF)
+/// The method `next_char` reads a single character, which may be a delimiter.
+///
+/// The method `position` returns the line number and column number of the input stream's cursor.
+/// The `InvalidData` errors of `next_int`, `next_float`, and `next_bool` report the line number and
+/// column number of the invalid token.
+///
+/// # Mode 2: line-by-line (default delimiter: U+000A LF)
 /// methods:
 /// - `has_next_line`
 /// - `next_line`
+/// - `read_line_into`
+/// - `next_fields`
+///
+/// Use [`Scanner::with_line_terminator`] for the other line terminators, see [`LineTerminator`].
 pub struct Scanner<B: BufRead> {
     bufread: B,
     buf: Vec<u8>,       // buffer for bytes read from BufRead
@@ -30,31 +42,162 @@ pub struct Scanner<B: BufRead> {
 
     token_peek_pos: usize, // the starting point in buf for the next token peeking
     next_token: Option<String>,
+    next_token_pos: usize, // the starting point in buf of next_token
     token_peeked: bool,
 
     line_peek_pos: usize, // the starting point in buf for the next line peeking
     next_line: Option<String>,
     line_peeked: bool,
+
+    delimiter: Arc<dyn Fn(u8) -> bool + Send + Sync>, // is the byte a token delimiter?
+    line_terminator: LineTerminator,
+
+    line: usize,    // 1-based line number of consume_pos
+    column: usize,  // 1-based column number (in characters) of consume_pos
+    after_cr: bool, // is the byte before consume_pos a Carriage Return?
+}
+
+/// The line terminators that separate the lines of a [`Scanner`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LineTerminator {
+    /// U+000A LF ("\n" on Unix-like OS), or "\r\n" (on Windows).  A lone U+000D CR is part of the
+    /// line.
+    #[default]
+    LineFeed,
+    /// U+000D CR (classic Mac OS).  A U+000A LF is part of the line.
+    CarriageReturn,
+    /// Any of "\n", "\r\n", and a lone "\r" (which is not followed by "\n").
+    Any,
+}
+
+impl LineTerminator {
+    // Strips the line terminator at the end of the line `s` if any.
+    fn strip(self, s: &str) -> &str {
+        match self {
+            LineTerminator::LineFeed => match s.strip_suffix('\n') {
+                Some(s) => s.strip_suffix('\r').unwrap_or(s),
+                None => s,
+            },
+            LineTerminator::CarriageReturn => s.strip_suffix('\r').unwrap_or(s),
+            LineTerminator::Any => {
+                let s = s.strip_suffix('\n').unwrap_or(s);
+                s.strip_suffix('\r').unwrap_or(s)
+            }
+        }
+    }
 }
 
 impl<B: BufRead> Scanner<B> {
+    /// Creates a new `Scanner` whose token delimiters are ASCII whitespaces.
     pub fn new(bufread: B) -> Self {
+        Self::with_delimiter(bufread, |x: u8| x.is_ascii_whitespace())
+    }
+
+    /// Creates a new `Scanner` whose token delimiters are the bytes that satisfy the predicate
+    /// `delimiter`, for example, `|x| x == b','` for comma-separated values.
+    ///
+    /// Consecutive delimiters are treated as one, so there is no empty token.  For example, the
+    /// tokens of "a,b,,c" are "a", "b", and "c".
+    ///
+    /// The predicate should only accept ASCII bytes, because the bytes of a non-ASCII UTF-8
+    /// character are not ASCII.
+    pub fn with_delimiter<P>(bufread: B, delimiter: P) -> Self
+    where
+        P: Fn(u8) -> bool + Send + Sync + 'static,
+    {
         Scanner {
             bufread,
             buf: Vec::new(),
             consume_pos: 0,
             token_peek_pos: 0, // invariant: token_peek_pos >= consume_pos
             next_token: None,
+            next_token_pos: 0,
             token_peeked: false,
             line_peek_pos: 0, // invariant: line_peek_pos >= consume_pos
             next_line: None,
             line_peeked: false,
+            delimiter: Arc::new(delimiter),
+            line_terminator: LineTerminator::LineFeed,
+            line: 1,
+            column: 1,
+            after_cr: false,
         }
     }
 
+    /// Sets the line terminator of the line-by-line mode, which is [`LineTerminator::LineFeed`] by
+    /// default.  For example, `Scanner::new(bufread).with_line_terminator(LineTerminator::Any)`
+    /// reads the text with the line terminators of any OS.
+    ///
+    /// The line terminator also determines how `position` counts the lines.
+    pub fn with_line_terminator(mut self, line_terminator: LineTerminator) -> Self {
+        self.line_terminator = line_terminator;
+        self
+    }
+
+    /// Returns the 1-based line number and column number of the input stream's cursor, that is, the
+    /// position of the next character to read.
+    ///
+    /// The line number is one plus the number of consumed line terminators.  The column number
+    /// counts UTF-8 characters, not bytes.
+    pub fn position(&self) -> (usize, usize) {
+        (self.line, self.column)
+    }
+
+    // Returns the 1-based line number and column number of `self.buf[index]`, where `index` is not
+    // less than `self.consume_pos`.
+    fn position_at(&self, index: usize) -> (usize, usize) {
+        let (line, column, _) = self.advance_position(index);
+        (line, column)
+    }
+
+    // Returns the position of `self.buf[index]` like `position_at`, and whether the byte before it
+    // is a Carriage Return.
+    fn advance_position(&self, index: usize) -> (usize, usize, bool) {
+        let (mut line, mut column, mut after_cr) = (self.line, self.column, self.after_cr);
+        for &b in &self.buf[self.consume_pos..index] {
+            let is_terminator = match self.line_terminator {
+                LineTerminator::LineFeed => b == b'\n',
+                LineTerminator::CarriageReturn => b == b'\r',
+                LineTerminator::Any => b == b'\r' || (b == b'\n' && !after_cr),
+            };
+            if is_terminator {
+                line += 1;
+                column = 1;
+            } else if b == b'\n' && self.line_terminator == LineTerminator::Any {
+                // the end of "\r\n", which has been counted
+            } else if b & 0xC0 != 0x80 {
+                // not a UTF-8 continuation byte
+                column += 1;
+            }
+            after_cr = b == b'\r';
+        }
+        (line, column, after_cr)
+    }
+
+    // Moves the input stream's cursor to `index` in the internal buffer, updates the position.
+    fn consume_to(&mut self, index: usize) {
+        (self.line, self.column, self.after_cr) = self.advance_position(index);
+        self.consume_pos = index;
+    }
+
+    // Returns IO Error `InvalidData`, reporting that the next token is not a valid value of the
+    // type `type_name`, and where the token is.
+    fn invalid_token_error(&self, type_name: &str) -> io::Error {
+        let token = self.next_token.as_deref().unwrap_or_default();
+        let (line, column) = self.position_at(self.next_token_pos);
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "attempts to read a '{}' value from the input stream, but the next token is \"{}\" (line {}, column {})",
+                type_name, token, line, column
+            ),
+        )
+    }
+
     /// Checks if there is next token available.
     ///
-    /// A token is a sequence of non-ascii-whitespace UTF-8 characters.
+    /// A token is a sequence of non-delimiter UTF-8 characters (ASCII whitespace by default; see
+    /// [`Scanner::with_delimiter`]).
     ///
     /// If such a token is found, return `Ok(true)`, otherwise, return `Ok(false)`.
     ///
@@ -94,6 +237,25 @@ impl<B: BufRead> Scanner<B> {
         }
     }
 
+    /// Returns the next token without consuming it, or `Ok(None)` if there is no next token.
+    ///
+    /// The input stream's cursor is not changed, so the next call to `next_token` returns the same
+    /// token.  It helps to decide which `next_*` method to call upon the upcoming token.
+    ///
+    /// # Errors
+    ///
+    /// If the next token has any invalid UTF-8 character, return IO Error `InvalidData`.
+    ///
+    /// If any IO Errors is encountered, return it as `Err`.  This method does not return IO Error
+    /// `Interrupted`, because it is handled (ignored) in this method.
+    pub fn peek(&mut self) -> io::Result<Option<&str>> {
+        match self.peek_next() {
+            Ok(_) => Ok(self.next_token.as_deref()),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
     // Reads new data from the underlying BufRead, consumes the new data, and extends the internal
     // buffer, returns the length of the new data.
     //
@@ -145,6 +307,37 @@ impl<B: BufRead> Scanner<B> {
         }
     }
 
+    // Scans internal buffer from `begin` to find the end of the line, which is the index after the
+    // line terminator, or the end of the buffer if the line terminator is not found before EOF.
+    //
+    // If any IO Errors is encountered, return it as `Err`.  This method does not return IO Error
+    // `Interrupted`, because it is handled (ignored) in this method.
+    fn find_line_end(&mut self, begin: usize) -> io::Result<usize> {
+        let line_terminator = self.line_terminator;
+        let j_opt = self.peek_until(
+            |x: u8| match line_terminator {
+                LineTerminator::LineFeed => x == b'\n',
+                LineTerminator::CarriageReturn => x == b'\r',
+                LineTerminator::Any => x == b'\n' || x == b'\r',
+            },
+            begin,
+        )?;
+        match j_opt {
+            None => Ok(self.buf.len()),
+            Some(j) => {
+                if line_terminator == LineTerminator::Any
+                    && self.buf[j] == b'\r'
+                    && self.fill_at_least(j + 2 - self.consume_pos)?
+                    && self.buf[j + 1] == b'\n'
+                {
+                    Ok(j + 2)
+                } else {
+                    Ok(j + 1)
+                }
+            }
+        }
+    }
+
     fn drop_consumed_part(&mut self) {
         self.buf.drain(..self.consume_pos);
         self.token_peek_pos -= self.consume_pos;
@@ -166,16 +359,18 @@ impl<B: BufRead> Scanner<B> {
         }
         self.token_peeked = true;
 
-        // Find the first non-whitespace.
-        let i_result = self.peek_until(|x: u8| !x.is_ascii_whitespace(), self.token_peek_pos);
+        let is_delimiter = Arc::clone(&self.delimiter);
+
+        // Find the first non-delimiter.
+        let i_result = self.peek_until(|x: u8| !is_delimiter(x), self.token_peek_pos);
         let i_opt = i_result?;
         if i_opt.is_none() {
             return Err(io::Error::from(io::ErrorKind::NotFound));
         }
         let i = i_opt.unwrap();
 
-        // Find the next whitespace
-        let j_result = self.peek_until(|x: u8| x.is_ascii_whitespace(), i + 1);
+        // Find the next delimiter
+        let j_result = self.peek_until(|x: u8| is_delimiter(x), i + 1);
         let j_opt = j_result?;
         let j = if let Some(jj) = j_opt {
             jj
@@ -188,13 +383,23 @@ impl<B: BufRead> Scanner<B> {
             Ok(s) => {
                 self.token_peek_pos = j;
                 self.next_token = Some(s.to_string());
+                self.next_token_pos = i;
                 Ok(())
             }
-            Err(_e) => Err(io::Error::from(io::ErrorKind::InvalidData)),
+            Err(_e) => {
+                let (line, column) = self.position_at(i);
+                Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!(
+                        "the next token is not valid UTF-8 (line {}, column {})",
+                        line, column
+                    ),
+                ))
+            }
         }
     }
 
-    // Peeks the next line.  Read until the next Line Feed or the end of the input stream.
+    // Peeks the next line.  Read until the next line terminator or the end of the input stream.
     //
     // If the next line is found, it is stored in `self.next_line`.
     // The line separator is included if it is found.
@@ -211,14 +416,7 @@ impl<B: BufRead> Scanner<B> {
         }
         self.line_peeked = true;
 
-        // Find the next Line Feed
-        let j_result = self.peek_until(|x: u8| x == b'\n', self.line_peek_pos);
-        let j_opt = j_result?;
-        let new_line_peek_pos = if let Some(j) = j_opt {
-            j + 1
-        } else {
-            self.buf.len()
-        };
+        let new_line_peek_pos = self.find_line_end(self.line_peek_pos)?;
 
         if self.line_peek_pos == new_line_peek_pos {
             return Err(io::Error::from(io::ErrorKind::NotFound));
@@ -235,7 +433,7 @@ impl<B: BufRead> Scanner<B> {
     }
 
     fn mark_token_consumed(&mut self) {
-        self.consume_pos = self.token_peek_pos;
+        self.consume_to(self.token_peek_pos);
         self.token_peeked = false;
         self.next_token = None;
 
@@ -247,7 +445,7 @@ impl<B: BufRead> Scanner<B> {
     }
 
     fn mark_line_consumed(&mut self) {
-        self.consume_pos = self.line_peek_pos;
+        self.consume_to(self.line_peek_pos);
         self.line_peeked = false;
         self.next_line = None;
 
@@ -258,9 +456,42 @@ impl<B: BufRead> Scanner<B> {
         self.drop_consumed_part();
     }
 
+    fn mark_char_consumed(&mut self, width: usize) {
+        self.consume_to(self.consume_pos + width);
+
+        self.token_peek_pos = self.consume_pos;
+        self.next_token = None;
+        self.token_peeked = false;
+
+        self.line_peek_pos = self.consume_pos;
+        self.next_line = None;
+        self.line_peeked = false;
+
+        self.drop_consumed_part();
+    }
+
+    // Extends the internal buffer until there are at least `n` unconsumed bytes, or EOF is reached.
+    //
+    // Returns `Ok(true)` if there are at least `n` unconsumed bytes, `Ok(false)` otherwise.
+    //
+    // If any IO Errors is encountered, return it as `Err`.  This method does not return IO Error
+    // `Interrupted`, because it is handled (ignored) in this method.
+    fn fill_at_least(&mut self, n: usize) -> io::Result<bool> {
+        while self.buf.len() - self.consume_pos < n {
+            match self.read_new_data() {
+                Ok(0) => return Ok(false),
+                Ok(_) => {}
+                Err(ref e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(true)
+    }
+
     /// Reads the next token as a `String`.
     ///
-    /// A token is a sequence of non-ascii-whitespace UTF-8 characters.
+    /// A token is a sequence of non-delimiter UTF-8 characters (ASCII whitespace by default; see
+    /// [`Scanner::with_delimiter`]).
     ///
     /// # Errors
     ///
@@ -274,8 +505,8 @@ impl<B: BufRead> Scanner<B> {
     /// If any Error (including `NotFound`) is returned, then the input stream's cursor is not
     /// changed, which means that if the client calls another `next_*` method immediately, the next
     /// entity (if fetched successfully) may contain the characters of this invalid token.  For
-    /// example, in the upcoming input stream all characters are whitespaces, so no valid next token
-    /// is found, however, if you call `next_line`, these whitespaces will be included in the next
+    /// example, in the upcoming input stream all characters are delimiters, so no valid next token
+    /// is found, however, if you call `next_line`, these delimiters will be included in the next
     /// line because they form a valid line.
     pub fn next_token(&mut self) -> io::Result<String> {
         if !self.token_peeked {
@@ -324,7 +555,7 @@ impl<B: BufRead> Scanner<B> {
                     self.mark_token_consumed();
                     Ok(v)
                 }
-                Err(_e) => Err(std::io::Error::from(std::io::ErrorKind::InvalidData)),
+                Err(_e) => Err(self.invalid_token_error(std::any::type_name::<T>())),
             }
         }
     }
@@ -362,7 +593,7 @@ impl<B: BufRead> Scanner<B> {
                     self.mark_token_consumed();
                     Ok(v)
                 }
-                Err(_e) => Err(std::io::Error::from(std::io::ErrorKind::InvalidData)),
+                Err(_e) => Err(self.invalid_token_error(std::any::type_name::<T>())),
             }
         }
     }
@@ -406,19 +637,49 @@ impl<B: BufRead> Scanner<B> {
                     self.mark_token_consumed();
                     Ok(false)
                 }
-                _ => Err(std::io::Error::new(
-                    std::io::ErrorKind::InvalidData,
-                    format!(
-                        "attempts to read a 'bool' value from the input stream, but the next token is \"{}\"",
-                        token
-                    ),
-                )),
+                _ => Err(self.invalid_token_error("bool")),
             }
         }
     }
 
-    /// Reads until the next Line Feed or the end of the input stream, returns the line string
-    /// disgarging the line separator ('\n' on Unix-like OS, "\r\n" on Windows) if any.
+    /// Reads the next UTF-8 character, which can be any character including the delimiters.
+    ///
+    /// # Errors
+    ///
+    /// If no more character is found (no more input data), return IO Error `NotFound`.
+    ///
+    /// If the next bytes are not a valid UTF-8 sequence, return IO Error `InvalidData`.
+    ///
+    /// If any IO Errors is encountered, return it as `Err`.  This method does not return IO Error
+    /// `Interrupted`, because it is handled (ignored) in this method.
+    ///
+    /// If any Error (including `NotFound`) is returned, then the input stream's cursor is not
+    /// changed.
+    pub fn next_char(&mut self) -> io::Result<char> {
+        if !self.fill_at_least(1)? {
+            return Err(io::Error::from(io::ErrorKind::NotFound));
+        }
+        let width = utf8_char_width(self.buf[self.consume_pos]);
+        if width == 0 || !self.fill_at_least(width)? {
+            return Err(io::Error::from(io::ErrorKind::InvalidData));
+        }
+        let bytes = &self.buf[self.consume_pos..self.consume_pos + width];
+        match std::str::from_utf8(bytes) {
+            Ok(s) => {
+                let c = s
+                    .chars()
+                    .next()
+                    .expect("a valid UTF-8 sequence is not empty");
+                self.mark_char_consumed(width);
+                Ok(c)
+            }
+            Err(_e) => Err(io::Error::from(io::ErrorKind::InvalidData)),
+        }
+    }
+
+    /// Reads until the next line terminator or the end of the input stream, returns the line string
+    /// disgarging the line terminator if any.  By default, the line terminator is '\n' (on
+    /// Unix-like OS) or "\r\n" (on Windows), see [`Scanner::with_line_terminator`].
     ///
     /// # Errors
     ///
@@ -442,17 +703,91 @@ impl<B: BufRead> Scanner<B> {
         if self.next_line.is_none() {
             Err(std::io::Error::from(std::io::ErrorKind::NotFound))
         } else {
-            let mut result = self.next_line.as_ref().unwrap().clone();
-            if result.ends_with('\n') {
-                result.pop();
-                if result.ends_with('\r') {
-                    result.pop();
-                }
-            }
+            let line = self.next_line.as_deref().unwrap();
+            let result = self.line_terminator.strip(line).to_string();
             self.mark_line_consumed();
             Ok(result)
         }
     }
+
+    /// Reads until the next line terminator or the end of the input stream, like `next_line`, but
+    /// appends the line to `buf` instead of allocating a new `String`.  The contents of `buf` are
+    /// cleared first.
+    ///
+    /// Returns the number of bytes read, including the line separator if any, so `Ok(0)` means the
+    /// end of the input stream is reached, while a blank line returns `Ok(1)` or `Ok(2)`.
+    ///
+    /// # Errors
+    ///
+    /// If the next line has any invalid UTF-8 character, return IO Error `InvalidData`.
+    ///
+    /// If any IO Errors is encountered, return it as `Err`.  This method does not return IO Error
+    /// `Interrupted`, because it is handled (ignored) in this method.
+    ///
+    /// If any Error is returned, then `buf` is empty and the input stream's cursor is not changed.
+    pub fn read_line_into(&mut self, buf: &mut String) -> io::Result<usize> {
+        buf.clear();
+        let end = self.find_line_end(self.consume_pos)?;
+        let n = end - self.consume_pos;
+        if n == 0 {
+            return Ok(0);
+        }
+
+        match std::str::from_utf8(&self.buf[self.consume_pos..end]) {
+            Ok(s) => {
+                buf.push_str(self.line_terminator.strip(s));
+                self.line_peek_pos = end;
+                self.mark_line_consumed();
+                Ok(n)
+            }
+            Err(_e) => Err(io::Error::from(io::ErrorKind::InvalidData)),
+        }
+    }
+
+    /// Reads until the next line terminator or the end of the input stream, like `next_line`, and
+    /// splits the line into the fields separated by the token delimiters (ASCII whitespaces by
+    /// default).
+    ///
+    /// Like the tokens, consecutive delimiters are treated as one.  A blank line results in an
+    /// empty `Vec`.
+    ///
+    /// # Errors
+    ///
+    /// Same as `next_line`.
+    pub fn next_fields(&mut self) -> io::Result<Vec<String>> {
+        let line = self.next_line()?;
+        Ok(line
+            .split(|c: char| c.is_ascii() && (self.delimiter)(c as u8))
+            .filter(|field| !field.is_empty())
+            .map(String::from)
+            .collect())
+    }
+}
+
+/// Reads from a borrowed string, for example, `Scanner::from("1 2 3")`.
+impl<'a> From<&'a str> for Scanner<&'a [u8]> {
+    fn from(s: &'a str) -> Self {
+        Scanner::new(s.as_bytes())
+    }
+}
+
+/// Reads from an owned string.
+impl From<String> for Scanner<Cursor<String>> {
+    fn from(s: String) -> Self {
+        Scanner::new(Cursor::new(s))
+    }
+}
+
+// Returns the number of bytes of the UTF-8 character that starts with the byte `b`, or 0 if `b`
+// cannot be the first byte of a UTF-8 character.
+fn utf8_char_width(b: u8) -> usize {
+    match b {
+        0x00..=0x7F => 1,
+        0xC2..=0xDF => 2,
+        0xE0..=0xEF => 3,
+        0xF0..=0xF4 => 4,
+        _ => 0,
+    }
 }
 
 // Finds the target in buf starting at position `begin`, returns the
@@ -583,4 +918,237 @@ mod tests {
         let line = r.unwrap();
         assert_eq!(line, "final_token");
     }
+
+    #[test]
+    fn test_scanner_next_fields() {
+        let cursor = std::io::Cursor::new("1 2 3\n4 5\n");
+        let mut scanner = Scanner::new(cursor);
+        assert_eq!(scanner.next_fields().unwrap(), ["1", "2", "3"]);
+        assert_eq!(scanner.next_fields().unwrap(), ["4", "5"]);
+        let r = scanner.next_fields();
+        assert_eq!(r.unwrap_err().kind(), io::ErrorKind::NotFound);
+
+        let cursor = std::io::Cursor::new("10 20\n\n  hello \t world\r\nlast");
+        let mut scanner = Scanner::new(cursor);
+        assert_eq!(scanner.next_int::<i32>().unwrap(), 10);
+        assert_eq!(scanner.next_fields().unwrap(), ["20"]);
+        assert!(scanner.next_fields().unwrap().is_empty());
+        assert_eq!(scanner.next_fields().unwrap(), ["hello", "world"]);
+        assert_eq!(scanner.next_token().unwrap(), "last");
+        assert!(!scanner.has_next_line().unwrap());
+    }
+
+    #[test]
+    fn test_scanner_next_char() {
+        let cursor = std::io::Cursor::new("héllo 世界\n42");
+        let mut scanner = Scanner::new(cursor);
+        for expected in ['h', 'é', 'l', 'l', 'o', ' ', '世', '界', '\n'] {
+            assert_eq!(scanner.next_char().unwrap(), expected);
+        }
+        assert_eq!(scanner.next_int::<i32>().unwrap(), 42);
+        let r = scanner.next_char();
+        assert_eq!(r.unwrap_err().kind(), io::ErrorKind::NotFound);
+
+        // mixed with token-by-token mode
+        let cursor = std::io::Cursor::new("ab cd");
+        let mut scanner = Scanner::new(cursor);
+        assert!(scanner.has_next().unwrap());
+        assert_eq!(scanner.next_char().unwrap(), 'a');
+        assert_eq!(scanner.next_token().unwrap(), "b");
+        assert_eq!(scanner.next_char().unwrap(), ' ');
+        assert_eq!(scanner.next_line().unwrap(), "cd");
+
+        // malformed sequences: a truncated 2-byte sequence, and a lone continuation byte
+        let cursor = std::io::Cursor::new(vec![b'a', 0xC3]);
+        let mut scanner = Scanner::new(cursor);
+        assert_eq!(scanner.next_char().unwrap(), 'a');
+        let r = scanner.next_char();
+        assert_eq!(r.unwrap_err().kind(), io::ErrorKind::InvalidData);
+
+        let cursor = std::io::Cursor::new(vec![0x80, b'a']);
+        let mut scanner = Scanner::new(cursor);
+        let r = scanner.next_char();
+        assert_eq!(r.unwrap_err().kind(), io::ErrorKind::InvalidData);
+
+        let cursor = std::io::Cursor::new(vec![0xE4, b'a', b'b']);
+        let mut scanner = Scanner::new(cursor);
+        let r = scanner.next_char();
+        assert_eq!(r.unwrap_err().kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn test_scanner_with_delimiter() {
+        // Consecutive delimiters are treated as one, so the empty field is skipped.
+        let cursor = std::io::Cursor::new("a,b,,c");
+        let mut scanner = Scanner::with_delimiter(cursor, |x| x == b',');
+        assert_eq!(scanner.next_token().unwrap(), "a");
+        assert_eq!(scanner.next_token().unwrap(), "b");
+        assert_eq!(scanner.next_token().unwrap(), "c");
+        assert!(!scanner.has_next().unwrap());
+
+        let cursor = std::io::Cursor::new("1, 2,3\n4,5\nx y,z\n");
+        let mut scanner = Scanner::with_delimiter(cursor, |x| x == b',' || x == b'\n');
+        assert_eq!(scanner.next_int::<i32>().unwrap(), 1);
+        assert!(scanner.next_int::<i32>().is_err()); // " 2"
+        assert_eq!(scanner.next_token().unwrap(), " 2");
+        assert_eq!(scanner.next_int::<i32>().unwrap(), 3);
+        assert_eq!(scanner.next_fields().unwrap(), Vec::<String>::new()); // rest of "3\n"
+        assert_eq!(scanner.next_fields().unwrap(), ["4", "5"]);
+        assert_eq!(scanner.next_fields().unwrap(), ["x y", "z"]);
+    }
+
+    #[test]
+    fn test_scanner_peek() {
+        let mut scanner = Scanner::new(" 42 true\n".as_bytes());
+        assert_eq!(scanner.peek().unwrap(), Some("42"));
+        assert_eq!(scanner.peek().unwrap(), Some("42"));
+        assert_eq!(scanner.next_int::<i32>().unwrap(), 42);
+        assert_eq!(scanner.peek().unwrap(), Some("true"));
+        assert_eq!(scanner.next_token().unwrap(), "true");
+        assert_eq!(scanner.peek().unwrap(), None);
+        assert!(!scanner.has_next().unwrap());
+
+        let mut scanner = Scanner::new("ab cd\nef".as_bytes());
+        assert_eq!(scanner.peek().unwrap(), Some("ab"));
+        assert_eq!(scanner.next_line().unwrap(), "ab cd");
+        assert_eq!(scanner.peek().unwrap(), Some("ef"));
+        assert_eq!(scanner.next_char().unwrap(), 'e');
+        assert_eq!(scanner.peek().unwrap(), Some("f"));
+    }
+
+    #[test]
+    fn test_scanner_position() {
+        let mut scanner = Scanner::new("1 2\nx".as_bytes());
+        assert_eq!(scanner.position(), (1, 1));
+        assert_eq!(scanner.next_int::<i32>().unwrap(), 1);
+        assert_eq!(scanner.position(), (1, 2));
+        assert_eq!(scanner.next_int::<i32>().unwrap(), 2);
+        assert_eq!(scanner.position(), (1, 4));
+        let e = scanner.next_int::<i32>().unwrap_err();
+        assert_eq!(e.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(
+            e.to_string(),
+            "attempts to read a 'i32' value from the input stream, but the next token is \"x\" (line 2, column 1)"
+        );
+        // the cursor is not changed on error
+        assert_eq!(scanner.position(), (1, 4));
+        let e = scanner.next_bool().unwrap_err();
+        assert!(e.to_string().ends_with("(line 2, column 1)"));
+        let e = scanner.next_float::<f64>().unwrap_err();
+        assert!(e.to_string().ends_with("(line 2, column 1)"));
+        assert_eq!(scanner.next_token().unwrap(), "x");
+        assert_eq!(scanner.position(), (2, 2));
+
+        // columns count characters, not bytes
+        let mut scanner = Scanner::new("é\t中 y\n\n  z".as_bytes());
+        assert_eq!(scanner.next_char().unwrap(), 'é');
+        assert_eq!(scanner.position(), (1, 2));
+        assert_eq!(scanner.next_token().unwrap(), "中");
+        assert_eq!(scanner.position(), (1, 4));
+        assert_eq!(scanner.next_line().unwrap(), " y");
+        assert_eq!(scanner.position(), (2, 1));
+        let e = scanner.next_int::<u8>().unwrap_err();
+        assert!(e.to_string().ends_with("\"z\" (line 3, column 3)"));
+    }
+
+    #[test]
+    fn test_scanner_read_line_into() {
+        let mut scanner = Scanner::new("first line\r\n\nthird 中文".as_bytes());
+        let mut line = String::from("garbage");
+        assert_eq!(scanner.read_line_into(&mut line).unwrap(), 12);
+        assert_eq!(line, "first line");
+        assert_eq!(scanner.read_line_into(&mut line).unwrap(), 1);
+        assert_eq!(line, "");
+        assert_eq!(scanner.read_line_into(&mut line).unwrap(), 12);
+        assert_eq!(line, "third 中文");
+        assert_eq!(scanner.read_line_into(&mut line).unwrap(), 0);
+        assert_eq!(line, "");
+        assert!(!scanner.has_next_line().unwrap());
+
+        // works together with the other modes
+        let mut scanner = Scanner::new("1 2\n3\n".as_bytes());
+        assert_eq!(scanner.next_int::<i32>().unwrap(), 1);
+        assert_eq!(scanner.read_line_into(&mut line).unwrap(), 3);
+        assert_eq!(line, " 2");
+        assert_eq!(scanner.position(), (2, 1));
+        assert!(scanner.has_next_line().unwrap());
+        assert_eq!(scanner.read_line_into(&mut line).unwrap(), 2);
+        assert_eq!(line, "3");
+
+        let mut scanner = Scanner::new(&b"ab\xFFcd\nef"[..]);
+        let e = scanner.read_line_into(&mut line).unwrap_err();
+        assert_eq!(e.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(line, "");
+    }
+
+    #[test]
+    fn test_scanner_line_terminator() {
+        let mut scanner =
+            Scanner::new("a\rb\rc".as_bytes()).with_line_terminator(LineTerminator::CarriageReturn);
+        let mut lines = Vec::new();
+        while scanner.has_next_line().unwrap() {
+            lines.push(scanner.next_line().unwrap());
+        }
+        assert_eq!(lines, ["a", "b", "c"]);
+        assert_eq!(scanner.position(), (3, 2));
+
+        // by default, a lone CR is part of the line
+        let mut scanner = Scanner::new("a\rb\rc".as_bytes());
+        assert_eq!(scanner.next_line().unwrap(), "a\rb\rc");
+        assert!(!scanner.has_next_line().unwrap());
+
+        let mut scanner =
+            Scanner::new("a\r\nb\rc\n\rd\r".as_bytes()).with_line_terminator(LineTerminator::Any);
+        assert_eq!(scanner.next_line().unwrap(), "a");
+        assert_eq!(scanner.position(), (2, 1));
+        assert_eq!(scanner.next_line().unwrap(), "b");
+        let mut line = String::new();
+        assert_eq!(scanner.read_line_into(&mut line).unwrap(), 2);
+        assert_eq!(line, "c");
+        assert_eq!(scanner.next_line().unwrap(), "");
+        assert_eq!(scanner.next_token().unwrap(), "d");
+        assert_eq!(scanner.position(), (5, 2));
+        assert_eq!(scanner.next_line().unwrap(), "");
+        assert_eq!(scanner.position(), (6, 1));
+        assert!(!scanner.has_next_line().unwrap());
+
+        // "\r\n" split by the internal buffer of BufRead
+        let bufread = io::BufReader::with_capacity(2, "x\r\ny".as_bytes());
+        let mut scanner = Scanner::new(bufread).with_line_terminator(LineTerminator::Any);
+        assert_eq!(scanner.next_line().unwrap(), "x");
+        assert_eq!(scanner.next_line().unwrap(), "y");
+    }
+
+    #[test]
+    fn test_scanner_from_string() {
+        let mut scanner = Scanner::from("1 2\nthree");
+        assert_eq!(scanner.next_int::<i32>().unwrap(), 1);
+        assert_eq!(scanner.next_line().unwrap(), " 2");
+        assert_eq!(scanner.next_token().unwrap(), "three");
+
+        let mut scanner = Scanner::from(String::from("中文 true"));
+        assert_eq!(scanner.next_char().unwrap(), '中');
+        assert_eq!(scanner.next_token().unwrap(), "文");
+        assert!(scanner.next_bool().unwrap());
+        assert!(!scanner.has_next().unwrap());
+    }
+
+    #[test]
+    fn test_scanner_generic_numbers() {
+        let input_data = "4294967295 -170141183460469231731687303715884105728 2.5 x 7";
+        let cursor = std::io::Cursor::new(input_data);
+        let mut scanner = Scanner::new(cursor);
+        assert_eq!(scanner.next_int::<usize>().unwrap(), 4294967295);
+        assert_eq!(scanner.next_int::<i128>().unwrap(), i128::MIN);
+        // parse failure keeps the cursor
+        let r = scanner.next_int::<u8>();
+        assert_eq!(r.unwrap_err().kind(), io::ErrorKind::InvalidData);
+        assert_eq!(scanner.next_float::<f32>().unwrap(), 2.5f32);
+        let r = scanner.next_float::<f64>();
+        assert_eq!(r.unwrap_err().kind(), io::ErrorKind::InvalidData);
+        assert_eq!(scanner.next_token().unwrap(), "x");
+        assert_eq!(scanner.next_int::<u64>().unwrap(), 7);
+        let r = scanner.next_int::<u64>();
+        assert_eq!(r.unwrap_err().kind(), io::ErrorKind::NotFound);
+    }
 }
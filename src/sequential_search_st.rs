@@ -0,0 +1,203 @@
+/// An (unordered) symbol table of generic key-value pairs, implemented with a linked list.
+///
+/// It supports the usual `put`, `get`, `contains`, `delete`, `size`, and `is_empty` methods.  It
+/// also provides a `keys` method for iterating over all of the keys.  The keys need only be
+/// compared for equality, so they must implement [`Eq`].
+///
+/// A symbol table implements the *associative array* abstraction: when associating a value with a
+/// key that is already in the symbol table, the convention is to replace the old value with the new
+/// value.
+///
+/// This implementation uses a *singly linked list*, where a new key is added to the front.  The
+/// `put`, `get`, `contains`, and `delete` operations take &Theta;(<em>n</em>) time in the worst
+/// case, where <em>n</em> is the number of key-value pairs, because the list is searched
+/// sequentially.  The `size` and `is_empty` operations take &Theta;(1) time.  Construction takes
+/// &Theta;(1) time.
+///
+/// It is efficient only for small symbol tables, such as the chains of
+/// [`crate::SeparateChainingHashST`].
+///
+/// For additional documentation, see <a href="https://algs4.cs.princeton.edu/31elementary">Section
+/// 3.1</a> of <i>Algorithms, 4th Edition</i> by Robert Sedgewick and Kevin Wayne.
+#[derive(Debug, Clone)]
+pub struct SequentialSearchST<K, V> {
+    first: Link<K, V>, // the linked list of key-value pairs
+    n: usize,          // number of key-value pairs
+}
+
+type Link<K, V> = Option<Box<Node<K, V>>>;
+
+#[derive(Debug, Clone)]
+struct Node<K, V> {
+    key: K,
+    val: V,
+    next: Link<K, V>,
+}
+
+impl<K, V> SequentialSearchST<K, V>
+where
+    K: Eq,
+{
+    /// Initializes an empty symbol table.
+    pub fn new() -> Self {
+        SequentialSearchST { first: None, n: 0 }
+    }
+
+    /// Returns the number of key-value pairs in this symbol table.
+    pub fn size(&self) -> usize {
+        self.n
+    }
+
+    /// Returns true if this symbol table is empty, returns false otherwise.
+    pub fn is_empty(&self) -> bool {
+        self.size() == 0
+    }
+
+    /// Does this symbol table contain the given key?
+    pub fn contains(&self, key: &K) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Returns the value associated with the given key, or `None` if the key is not in the symbol
+    /// table.
+    pub fn get(&self, key: &K) -> Option<&V> {
+        let mut x = self.first.as_deref();
+        while let Some(node) = x {
+            if node.key == *key {
+                return Some(&node.val);
+            }
+            x = node.next.as_deref();
+        }
+        None
+    }
+
+    /// Inserts the specified key-value pair into the symbol table, overwriting the old value with
+    /// the new value if the symbol table already contains the specified key.
+    pub fn put(&mut self, key: K, val: V) {
+        let mut x = self.first.as_deref_mut();
+        while let Some(node) = x {
+            if node.key == key {
+                node.val = val;
+                return;
+            }
+            x = node.next.as_deref_mut();
+        }
+        self.first = Some(Box::new(Node {
+            key,
+            val,
+            next: self.first.take(),
+        }));
+        self.n += 1;
+    }
+
+    /// Removes the specified key and its associated value from this symbol table (if the key is in
+    /// this symbol table), and returns the value.
+    pub fn delete(&mut self, key: &K) -> Option<V> {
+        let mut link = &mut self.first;
+        while link.as_ref().is_some_and(|node| node.key != *key) {
+            link = &mut link.as_mut().unwrap().next;
+        }
+        let node = *link.take()?;
+        *link = node.next;
+        self.n -= 1;
+        Some(node.val)
+    }
+
+    /// Returns an iterator over the keys in the symbol table, from the most recently added one.
+    pub fn keys(&self) -> SequentialSearchSTKeys<'_, K, V> {
+        SequentialSearchSTKeys {
+            current: self.first.as_deref(),
+        }
+    }
+}
+
+impl<K, V> Default for SequentialSearchST<K, V>
+where
+    K: Eq,
+{
+    fn default() -> Self {
+        SequentialSearchST::new()
+    }
+}
+
+impl<K, V> Drop for SequentialSearchST<K, V> {
+    fn drop(&mut self) {
+        while self.first.is_some() {
+            let first = *self.first.take().unwrap();
+            self.first = first.next;
+        }
+    }
+}
+
+pub struct SequentialSearchSTKeys<'a, K, V> {
+    current: Option<&'a Node<K, V>>,
+}
+
+impl<'a, K, V> Iterator for SequentialSearchSTKeys<'a, K, V> {
+    type Item = &'a K;
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.current?;
+        self.current = node.next.as_deref();
+        Some(&node.key)
+    }
+}
+
+impl<K, V> IntoIterator for SequentialSearchST<K, V> {
+    type Item = (K, V);
+    type IntoIter = SequentialSearchSTIntoIter<K, V>;
+
+    /// Moves the key-value pairs out of the symbol table, from the most recently added one.
+    fn into_iter(self) -> Self::IntoIter {
+        SequentialSearchSTIntoIter { moved_st: self }
+    }
+}
+
+pub struct SequentialSearchSTIntoIter<K, V> {
+    moved_st: SequentialSearchST<K, V>,
+}
+
+impl<K, V> Iterator for SequentialSearchSTIntoIter<K, V> {
+    type Item = (K, V);
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = *self.moved_st.first.take()?;
+        self.moved_st.first = first.next;
+        self.moved_st.n -= 1;
+        Some((first.key, first.val))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.moved_st.n, Some(self.moved_st.n))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sequential_search_st() {
+        let mut st = SequentialSearchST::new();
+        assert!(st.is_empty());
+        assert_eq!(st.get(&'S'), None);
+        for (i, key) in "SEARCHEXAMPLE".chars().enumerate() {
+            st.put(key, i);
+        }
+        assert_eq!(st.size(), 10);
+        assert_eq!(st.get(&'E'), Some(&12));
+        assert_eq!(st.get(&'S'), Some(&0));
+        assert!(!st.contains(&'Z'));
+        // the most recently added key is in front
+        assert_eq!(st.keys().collect::<String>(), "LPMXHCRAES");
+
+        assert_eq!(st.delete(&'L'), Some(11)); // the first node
+        assert_eq!(st.delete(&'A'), Some(8));
+        assert_eq!(st.delete(&'S'), Some(0)); // the last node
+        assert_eq!(st.delete(&'S'), None);
+        assert_eq!(st.size(), 7);
+        assert_eq!(st.keys().collect::<String>(), "PMXHCRE");
+
+        let pairs: Vec<_> = st.into_iter().collect();
+        assert_eq!(pairs[0], ('P', 10));
+        assert_eq!(pairs.len(), 7);
+    }
+}
@@ -0,0 +1,28 @@
+//! Sorting algorithms.
+//!
+//! For additional documentation, see <a href="https://algs4.cs.princeton.edu/20sorting">Chapter
+//! 2</a> of <i>Algorithms, 4th Edition</i> by Robert Sedgewick and Kevin Wayne.
+
+use std::cmp::Ordering;
+
+pub mod adaptive;
+pub mod insertion;
+pub mod merge;
+pub mod quick;
+pub mod radix;
+pub mod selection;
+pub mod shell;
+
+/// Returns true if the slice is sorted in non-descending order.
+pub fn is_sorted<T: Ord>(a: &[T]) -> bool {
+    is_sorted_by(a, T::cmp)
+}
+
+/// Returns true if the slice is sorted in non-descending order according to the comparator
+/// function.
+pub fn is_sorted_by<T, F>(a: &[T], mut compare: F) -> bool
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    a.windows(2).all(|w| compare(&w[0], &w[1]).is_le())
+}
@@ -0,0 +1,186 @@
+//! An adaptive (natural) merge sort, a simplified Timsort.
+
+use std::cmp::Ordering;
+
+/// Sorts a slice using an adaptive <em>natural merge sort</em>.
+///
+/// It first scans the slice for the existing <em>runs</em>: maximal ascending (non-descending)
+/// sequences, or strictly descending sequences which are reversed in place.  Then it merges the
+/// adjacent runs bottom-up until there is only one run.  This is a simplified Timsort without the
+/// galloping mode and the minimum run length.
+///
+/// This sorting algorithm is stable.
+///
+/// It takes &Theta;(<em>n</em> log <em>r</em>) time, where <em>n</em> is the length of the slice
+/// and <em>r</em> is the number of the runs, and uses &Theta;(<em>n</em>) extra memory.  On an
+/// already sorted (or reversely sorted) slice, it makes exactly <em>n</em> - 1 compares.
+///
+/// For additional documentation, see <a href="https://algs4.cs.princeton.edu/22mergesort">Section
+/// 2.2</a> of <i>Algorithms, 4th Edition</i> by Robert Sedgewick and Kevin Wayne.
+pub fn adaptive_merge_sort<T: Ord + Clone>(a: &mut [T]) {
+    adaptive_merge_sort_by(a, |x, y| x.cmp(y));
+}
+
+/// Sorts a slice with a comparator function using an adaptive <em>natural merge sort</em>.
+///
+/// See [`adaptive_merge_sort`].
+pub fn adaptive_merge_sort_by<T, F>(a: &mut [T], mut compare: F)
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let n = a.len();
+    if n < 2 {
+        return;
+    }
+
+    // find the runs; runs[i] is the start index of the i-th run
+    let mut runs = Vec::new();
+    let mut lo = 0;
+    while lo < n {
+        runs.push(lo);
+        let mut hi = lo + 1;
+        if hi < n {
+            if compare(&a[hi], &a[lo]).is_lt() {
+                // strictly descending, so that reversing it keeps the sort stable
+                hi += 1;
+                while hi < n && compare(&a[hi], &a[hi - 1]).is_lt() {
+                    hi += 1;
+                }
+                a[lo..hi].reverse();
+            } else {
+                hi += 1;
+                while hi < n && compare(&a[hi], &a[hi - 1]).is_ge() {
+                    hi += 1;
+                }
+            }
+        }
+        lo = hi;
+    }
+    runs.push(n);
+
+    // merge the adjacent runs bottom-up
+    let mut aux: Vec<T> = Vec::with_capacity(n);
+    while runs.len() > 2 {
+        let mut merged = Vec::with_capacity(runs.len() / 2 + 1);
+        let mut i = 0;
+        while i + 2 < runs.len() {
+            merge(a, &mut aux, runs[i], runs[i + 1], runs[i + 2], &mut compare);
+            merged.push(runs[i]);
+            i += 2;
+        }
+        if i + 1 < runs.len() {
+            merged.push(runs[i]); // the odd run out
+        }
+        merged.push(n);
+        runs = merged;
+    }
+}
+
+// Stably merges a[lo..mid] with a[mid..hi] using the auxiliary buffer `aux`.
+//
+// Precondition: a[lo..mid] and a[mid..hi] are sorted.
+fn merge<T, F>(a: &mut [T], aux: &mut Vec<T>, lo: usize, mid: usize, hi: usize, compare: &mut F)
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    aux.clear();
+    aux.extend_from_slice(&a[lo..hi]);
+    let (left, right) = aux.split_at(mid - lo);
+    let (mut i, mut j) = (0, 0);
+    for x in a[lo..hi].iter_mut() {
+        if i == left.len() {
+            *x = right[j].clone();
+            j += 1;
+        } else if j == right.len() || compare(&right[j], &left[i]).is_ge() {
+            *x = left[i].clone();
+            i += 1;
+        } else {
+            *x = right[j].clone();
+            j += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_adaptive_merge_sort() {
+        let mut a: [i32; 0] = [];
+        adaptive_merge_sort(&mut a);
+
+        let mut a = [1];
+        adaptive_merge_sort(&mut a);
+        assert_eq!(a, [1]);
+
+        let mut a = ["S", "O", "R", "T", "E", "X", "A", "M", "P", "L", "E"];
+        adaptive_merge_sort(&mut a);
+        assert_eq!(a, ["A", "E", "E", "L", "M", "O", "P", "R", "S", "T", "X"]);
+
+        let mut a: Vec<i32> = (0..1000).map(|_| rand::random_range(-100..100)).collect();
+        let mut expected = a.clone();
+        expected.sort();
+        adaptive_merge_sort(&mut a);
+        assert_eq!(a, expected);
+    }
+
+    #[test]
+    fn test_adaptive_merge_sort_stable() {
+        // descending and ascending runs with equal keys
+        let mut a = [
+            (3, 'a'),
+            (2, 'a'),
+            (1, 'a'),
+            (1, 'b'),
+            (2, 'b'),
+            (3, 'b'),
+            (2, 'c'),
+        ];
+        adaptive_merge_sort_by(&mut a, |x, y| x.0.cmp(&y.0));
+        assert_eq!(
+            a,
+            [
+                (1, 'a'),
+                (1, 'b'),
+                (2, 'a'),
+                (2, 'b'),
+                (2, 'c'),
+                (3, 'a'),
+                (3, 'b')
+            ]
+        );
+    }
+
+    #[test]
+    fn test_adaptive_merge_sort_compares() {
+        let n = 1000;
+        let mut compares = 0;
+        let mut a: Vec<i32> = (0..n).collect();
+        adaptive_merge_sort_by(&mut a, |x, y| {
+            compares += 1;
+            x.cmp(y)
+        });
+        assert_eq!(compares, n - 1);
+
+        let mut compares = 0;
+        let mut a: Vec<i32> = (0..n).rev().collect();
+        adaptive_merge_sort_by(&mut a, |x, y| {
+            compares += 1;
+            x.cmp(y)
+        });
+        assert_eq!(compares, n - 1);
+        assert_eq!(a, (0..n).collect::<Vec<i32>>());
+
+        let mut compares = 0;
+        let mut a: Vec<i32> = (0..n).map(|_| rand::random_range(0..n)).collect();
+        adaptive_merge_sort_by(&mut a, |x, y| {
+            compares += 1;
+            x.cmp(y)
+        });
+        assert!(a.is_sorted());
+        assert!(compares > 4 * n);
+    }
+}
@@ -0,0 +1,79 @@
+//! Insertion sort.
+
+use std::cmp::Ordering;
+
+/// Sorts a slice using <em>insertion sort</em>.
+///
+/// This implementation makes ~ 1/2 <em>n</em><sup>2</sup> compares and exchanges in the worst
+/// case, so it is not suitable for sorting large arbitrary slices.  More precisely, the number of
+/// exchanges is exactly equal to the number of inversions.  So, for example, it sorts a
+/// partially-sorted slice in linear time.
+///
+/// This sorting algorithm is stable.
+///
+/// It uses &Theta;(1) extra memory (not including the input slice).
+///
+/// For additional documentation, see <a href="https://algs4.cs.princeton.edu/21elementary">Section
+/// 2.1</a> of <i>Algorithms, 4th Edition</i> by Robert Sedgewick and Kevin Wayne.
+pub fn sort<T: Ord>(a: &mut [T]) {
+    sort_by(a, T::cmp);
+}
+
+/// Sorts a slice with a comparator function using <em>insertion sort</em>.
+///
+/// See [`sort`].
+pub fn sort_by<T, F>(a: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    for i in 1..a.len() {
+        let mut j = i;
+        while j > 0 && compare(&a[j], &a[j - 1]).is_lt() {
+            a.swap(j, j - 1);
+            j -= 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sort::is_sorted;
+
+    #[test]
+    fn test_insertion_sort() {
+        let mut a: [i32; 0] = [];
+        sort(&mut a);
+        assert!(is_sorted(&a));
+
+        let mut a = [1];
+        sort(&mut a);
+        assert_eq!(a, [1]);
+
+        let mut a = ["S", "O", "R", "T", "E", "X", "A", "M", "P", "L", "E"];
+        sort(&mut a);
+        assert_eq!(a, ["A", "E", "E", "L", "M", "O", "P", "R", "S", "T", "X"]);
+
+        let mut a: Vec<i32> = (0..1000).map(|_| rand::random_range(-100..100)).collect();
+        sort(&mut a);
+        assert!(is_sorted(&a));
+
+        let mut a: Vec<i32> = (0..1000).collect();
+        sort(&mut a);
+        assert_eq!(a, (0..1000).collect::<Vec<i32>>());
+    }
+
+    #[test]
+    fn test_insertion_sort_stable() {
+        let mut a = [(2, 'a'), (1, 'a'), (2, 'b'), (1, 'b'), (0, 'a'), (2, 'c')];
+        sort_by(&mut a, |x, y| x.0.cmp(&y.0));
+        assert_eq!(
+            a,
+            [(0, 'a'), (1, 'a'), (1, 'b'), (2, 'a'), (2, 'b'), (2, 'c')]
+        );
+
+        let mut a = [5, 3, 9, 1];
+        sort_by(&mut a, |x, y| y.cmp(x));
+        assert_eq!(a, [9, 5, 3, 1]);
+    }
+}
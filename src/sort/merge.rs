@@ -0,0 +1,186 @@
+//! Top-down and bottom-up mergesort.
+
+use std::cmp::Ordering;
+
+/// Sorts a slice using a top-down, recursive <em>mergesort</em>.
+///
+/// This implementation takes &Theta;(<em>n</em> log <em>n</em>) time to sort any slice of length
+/// <em>n</em> (assuming comparisons take constant time).  It makes between ~ 1/2 <em>n</em>
+/// log<sub>2</sub> <em>n</em> and ~ 1 <em>n</em> log<sub>2</sub> <em>n</em> compares.
+///
+/// This sorting algorithm is stable.
+///
+/// It uses &Theta;(<em>n</em>) extra memory (not including the input slice).
+///
+/// For additional documentation, see <a href="https://algs4.cs.princeton.edu/22mergesort">Section
+/// 2.2</a> of <i>Algorithms, 4th Edition</i> by Robert Sedgewick and Kevin Wayne.
+pub fn sort<T: Ord + Clone>(a: &mut [T]) {
+    sort_by(a, T::cmp);
+}
+
+/// Sorts a slice with a comparator function using a top-down, recursive <em>mergesort</em>.
+///
+/// See [`sort`].
+pub fn sort_by<T, F>(a: &mut [T], mut compare: F)
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let mut aux = a.to_vec();
+    sort_range(a, &mut aux, 0, a.len(), &mut compare);
+}
+
+/// Sorts a slice using a bottom-up, nonrecursive <em>mergesort</em>.
+///
+/// This implementation takes &Theta;(<em>n</em> log <em>n</em>) time to sort any slice of length
+/// <em>n</em> (assuming comparisons take constant time).  It makes between ~ 1/2 <em>n</em>
+/// log<sub>2</sub> <em>n</em> and ~ 1 <em>n</em> log<sub>2</sub> <em>n</em> compares.
+///
+/// This sorting algorithm is stable.
+///
+/// It uses &Theta;(<em>n</em>) extra memory (not including the input slice).
+pub fn sort_bu<T: Ord + Clone>(a: &mut [T]) {
+    sort_bu_by(a, T::cmp);
+}
+
+/// Sorts a slice with a comparator function using a bottom-up, nonrecursive <em>mergesort</em>.
+///
+/// See [`sort_bu`].
+pub fn sort_bu_by<T, F>(a: &mut [T], mut compare: F)
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let n = a.len();
+    let mut aux = a.to_vec();
+    let mut len = 1;
+    while len < n {
+        let mut lo = 0;
+        while lo < n - len {
+            let mid = lo + len;
+            let hi = usize::min(lo + 2 * len, n);
+            merge(a, &mut aux, lo, mid, hi, &mut compare);
+            lo += 2 * len;
+        }
+        len *= 2;
+    }
+}
+
+// mergesort a[lo..hi] using auxiliary slice aux[lo..hi]
+fn sort_range<T, F>(a: &mut [T], aux: &mut [T], lo: usize, hi: usize, compare: &mut F)
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    if hi - lo < 2 {
+        return;
+    }
+    let mid = lo + (hi - lo) / 2;
+    sort_range(a, aux, lo, mid, compare);
+    sort_range(a, aux, mid, hi, compare);
+    merge(a, aux, lo, mid, hi, compare);
+}
+
+// Stably merges a[lo..mid] with a[mid..hi] using the auxiliary slice aux[lo..hi].
+//
+// Precondition: a[lo..mid] and a[mid..hi] are sorted.
+fn merge<T, F>(a: &mut [T], aux: &mut [T], lo: usize, mid: usize, hi: usize, compare: &mut F)
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    // copy to aux[]
+    aux[lo..hi].clone_from_slice(&a[lo..hi]);
+
+    // merge back to a[]
+    let (mut i, mut j) = (lo, mid);
+    for x in a[lo..hi].iter_mut() {
+        if i == mid {
+            *x = aux[j].clone();
+            j += 1;
+        } else if j == hi {
+            *x = aux[i].clone();
+            i += 1;
+        } else if compare(&aux[j], &aux[i]).is_lt() {
+            *x = aux[j].clone();
+            j += 1;
+        } else {
+            *x = aux[i].clone();
+            i += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sort::is_sorted;
+
+    #[test]
+    fn test_merge_sort() {
+        for sort in [sort::<i32>, sort_bu::<i32>] {
+            let mut a: [i32; 0] = [];
+            sort(&mut a);
+
+            let mut a = [1];
+            sort(&mut a);
+            assert_eq!(a, [1]);
+
+            let mut a = [2, 1];
+            sort(&mut a);
+            assert_eq!(a, [1, 2]);
+
+            let mut a: Vec<i32> = (0..1000).map(|_| rand::random_range(-100..100)).collect();
+            let mut expected = a.clone();
+            expected.sort();
+            sort(&mut a);
+            assert_eq!(a, expected);
+
+            let mut a: Vec<i32> = (0..1001).rev().collect();
+            sort(&mut a);
+            assert!(is_sorted(&a));
+        }
+
+        let mut a = [
+            "M", "E", "R", "G", "E", "S", "O", "R", "T", "E", "X", "A", "M", "P", "L", "E",
+        ];
+        sort(&mut a);
+        let sorted = [
+            "A", "E", "E", "E", "E", "G", "L", "M", "M", "O", "P", "R", "R", "S", "T", "X",
+        ];
+        assert_eq!(a, sorted);
+    }
+
+    #[test]
+    fn test_merge_sort_stable() {
+        let a = [
+            (3, 'a'),
+            (1, 'a'),
+            (2, 'a'),
+            (3, 'b'),
+            (1, 'b'),
+            (2, 'b'),
+            (1, 'c'),
+        ];
+        let expected = [
+            (1, 'a'),
+            (1, 'b'),
+            (1, 'c'),
+            (2, 'a'),
+            (2, 'b'),
+            (3, 'a'),
+            (3, 'b'),
+        ];
+        let mut b = a;
+        sort_by(&mut b, |x, y| x.0.cmp(&y.0));
+        assert_eq!(b, expected);
+        let mut b = a;
+        sort_bu_by(&mut b, |x, y| x.0.cmp(&y.0));
+        assert_eq!(b, expected);
+
+        // the tags of equal keys keep their original order, even for a large input
+        let mut a: Vec<(i32, usize)> = (0..1000).map(|i| (rand::random_range(0..10), i)).collect();
+        sort_by(&mut a, |x, y| x.0.cmp(&y.0));
+        assert!(a.windows(2).all(|w| w[0] < w[1]));
+    }
+}
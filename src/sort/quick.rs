@@ -0,0 +1,173 @@
+//! Quicksort with 3-way partitioning, and quickselect.
+
+use crate::error::InvalidArgument;
+use crate::std_random::StdRandom;
+use std::cmp::Ordering;
+
+/// Sorts a slice using <em>quicksort</em> with Dijkstra's 3-way partitioning.
+///
+/// The slice is shuffled first, so that the running time does not depend on the order of the
+/// input.  The expected running time is &Theta;(<em>n</em> log <em>n</em>), where <em>n</em> is
+/// the length of the slice, and it is linear when there are only a constant number of distinct
+/// keys.  It takes &Theta;(<em>n</em><sup>2</sup>) time in the worst case, which is extremely
+/// unlikely.
+///
+/// This sorting algorithm is not stable.
+///
+/// It uses &Theta;(log <em>n</em>) extra memory (not including the input slice) for the
+/// recursion, with high probability.
+///
+/// For additional documentation, see <a href="https://algs4.cs.princeton.edu/23quicksort">Section
+/// 2.3</a> of <i>Algorithms, 4th Edition</i> by Robert Sedgewick and Kevin Wayne.
+pub fn sort<T: Ord>(a: &mut [T]) {
+    sort_with_seed(a, random_seed());
+}
+
+/// Sorts a slice using <em>quicksort</em> with Dijkstra's 3-way partitioning, shuffling it with a
+/// pseudo-random number generator seeded by `seed`, so that the sorting is reproducible.
+///
+/// See [`sort`].
+pub fn sort_with_seed<T: Ord>(a: &mut [T], seed: u64) {
+    shuffle(a, seed);
+    sort_range(a, 0, a.len());
+}
+
+/// Rearranges the slice so that `a[k]` contains the <em>k</em>th smallest key (counting from 0);
+/// `a[0]` through `a[k-1]` are less than (or equal to) `a[k]`; and `a[k+1]` through `a[n-1]` are
+/// greater than (or equal to) `a[k]`, and returns a reference to `a[k]`.
+///
+/// It uses <em>quickselect</em> with 3-way partitioning on a shuffled slice, and takes linear time
+/// in expectation.
+///
+/// It returns an error if `k` is not less than the length of the slice.
+pub fn select<T: Ord>(a: &mut [T], k: usize) -> Result<&T, InvalidArgument> {
+    select_with_seed(a, k, random_seed())
+}
+
+/// Rearranges the slice like [`select`], shuffling it with a pseudo-random number generator
+/// seeded by `seed`, so that the selection is reproducible.
+pub fn select_with_seed<T: Ord>(a: &mut [T], k: usize, seed: u64) -> Result<&T, InvalidArgument> {
+    if k >= a.len() {
+        return Err(InvalidArgument(format!(
+            "index {} is not less than the length {}",
+            k,
+            a.len()
+        )));
+    }
+    shuffle(a, seed);
+    let (mut lo, mut hi) = (0, a.len());
+    loop {
+        let (lt, gt) = partition(a, lo, hi);
+        if k < lt {
+            hi = lt;
+        } else if k > gt {
+            lo = gt + 1;
+        } else {
+            return Ok(&a[k]);
+        }
+    }
+}
+
+// quicksort a[lo..hi] using 3-way partitioning
+fn sort_range<T: Ord>(a: &mut [T], lo: usize, hi: usize) {
+    if hi - lo < 2 {
+        return;
+    }
+    let (lt, gt) = partition(a, lo, hi);
+    sort_range(a, lo, lt);
+    sort_range(a, gt + 1, hi);
+}
+
+// Partitions a[lo..hi] on the key v = a[lo], and returns (lt, gt) such that
+// a[lo..lt] < v = a[lt..=gt] < a[gt+1..hi].
+//
+// Precondition: lo < hi.
+fn partition<T: Ord>(a: &mut [T], lo: usize, hi: usize) -> (usize, usize) {
+    // the partitioning item stays at a[lt]
+    let (mut lt, mut gt, mut i) = (lo, hi - 1, lo + 1);
+    while i <= gt {
+        match a[i].cmp(&a[lt]) {
+            Ordering::Less => {
+                a.swap(lt, i);
+                lt += 1;
+                i += 1;
+            }
+            Ordering::Greater => {
+                a.swap(i, gt);
+                gt -= 1;
+            }
+            Ordering::Equal => i += 1,
+        }
+    }
+    (lt, gt)
+}
+
+pub(super) fn shuffle<T>(a: &mut [T], seed: u64) {
+    StdRandom::with_seed(seed).shuffle(a);
+}
+
+pub(super) fn random_seed() -> u64 {
+    StdRandom::new().seed()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quick_sort() {
+        let mut a: [i32; 0] = [];
+        sort(&mut a);
+
+        let mut a = [1];
+        sort(&mut a);
+        assert_eq!(a, [1]);
+
+        let mut a = [
+            "Q", "U", "I", "C", "K", "S", "O", "R", "T", "E", "X", "A", "M", "P", "L", "E",
+        ];
+        sort_with_seed(&mut a, 42);
+        let sorted = [
+            "A", "C", "E", "E", "I", "K", "L", "M", "O", "P", "Q", "R", "S", "T", "U", "X",
+        ];
+        assert_eq!(a, sorted);
+
+        for seed in 0..10 {
+            let mut a: Vec<i32> = (0..1000).map(|_| rand::random_range(-1000..1000)).collect();
+            let mut expected = a.clone();
+            expected.sort();
+            sort_with_seed(&mut a, seed);
+            assert_eq!(a, expected);
+        }
+
+        // many duplicates, and already sorted input
+        let mut a: Vec<i32> = (0..10000).map(|_| rand::random_range(0..3)).collect();
+        let mut expected = a.clone();
+        expected.sort();
+        sort(&mut a);
+        assert_eq!(a, expected);
+        sort(&mut a);
+        assert_eq!(a, expected);
+    }
+
+    #[test]
+    fn test_quick_select() {
+        let a: Vec<i32> = (0..500).map(|_| rand::random_range(-50..50)).collect();
+        let mut sorted = a.clone();
+        sorted.sort();
+        for k in [0, 1, 17, 250, 498, 499] {
+            let mut b = a.clone();
+            assert_eq!(select_with_seed(&mut b, k, k as u64).unwrap(), &sorted[k]);
+            assert!(b[..k].iter().all(|x| *x <= b[k]));
+            assert!(b[k + 1..].iter().all(|x| *x >= b[k]));
+            let mut b = a.clone();
+            assert_eq!(select(&mut b, k).unwrap(), &sorted[k]);
+        }
+
+        let mut a = [7];
+        assert_eq!(select(&mut a, 0).unwrap(), &7);
+        assert!(select(&mut a, 1).is_err());
+        let mut a: [i32; 0] = [];
+        assert!(select(&mut a, 0).is_err());
+    }
+}
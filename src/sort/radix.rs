@@ -0,0 +1,383 @@
+//! Key-indexed counting, LSD and MSD radix sorts, and 3-way string quicksort.
+//!
+//! The strings are sorted by their UTF-8 bytes, so that the characters are compared by their byte
+//! values, with the radix <em>R</em> = 256.  This is the same as the natural ordering of
+//! [`String`], which orders the strings lexicographically by their bytes, and the same as the
+//! ordering of their Unicode code points.
+
+use super::quick::{random_seed, shuffle};
+use crate::error::InvalidArgument;
+use std::mem;
+
+const R: usize = 256; // radix, the number of distinct byte values
+const CUTOFF: usize = 15; // cutoff to insertion sort
+
+/// Stably sorts a slice by the integer keys returned by the function `key`, which must be less
+/// than `r`, using <em>key-indexed counting</em>.
+///
+/// It takes &Theta;(<em>n</em> + <em>r</em>) time and uses &Theta;(<em>n</em> + <em>r</em>)
+/// extra memory, where <em>n</em> is the length of the slice.  The function `key` is called twice
+/// for each item.  The items are moved, not cloned, with the help of [`Default`].
+///
+/// It returns an error, before changing the slice, if any key is not less than `r`.
+///
+/// For additional documentation, see <a href="https://algs4.cs.princeton.edu/51radix">Section
+/// 5.1</a> of <i>Algorithms, 4th Edition</i> by Robert Sedgewick and Kevin Wayne.
+pub fn key_indexed_counting<T, F>(a: &mut [T], r: usize, key: F) -> Result<(), InvalidArgument>
+where
+    T: Default,
+    F: Fn(&T) -> usize,
+{
+    // compute frequency counts
+    let mut count = vec![0; r + 1];
+    for x in a.iter() {
+        let k = key(x);
+        if k >= r {
+            return Err(InvalidArgument(format!(
+                "key {} is not less than the radix {}",
+                k, r
+            )));
+        }
+        count[k + 1] += 1;
+    }
+
+    // transform counts to indices
+    for i in 0..r {
+        count[i + 1] += count[i];
+    }
+
+    // distribute
+    let mut aux: Vec<T> = (0..a.len()).map(|_| T::default()).collect();
+    for x in a.iter_mut() {
+        let k = key(x);
+        aux[count[k]] = mem::take(x);
+        count[k] += 1;
+    }
+
+    // copy back
+    for (x, y) in a.iter_mut().zip(aux) {
+        *x = y;
+    }
+    Ok(())
+}
+
+/// Sorts a slice of strings on their leading `w` bytes, in ascending order, using <em>LSD
+/// (least-significant-digit first) radix sort</em>.
+///
+/// It is meant for fixed-width strings, such as license plates or fixed-length codes: the bytes
+/// after the leading `w` bytes are ignored, and the strings with equal leading `w` bytes keep their
+/// relative order.  This sorting algorithm is stable.
+///
+/// It takes &Theta;(<em>w</em> (<em>n</em> + <em>R</em>)) time and uses &Theta;(<em>n</em> +
+/// <em>R</em>) extra memory, where <em>n</em> is the length of the slice.
+///
+/// It returns an error, before changing the slice, if any string is shorter than `w` bytes.
+///
+/// For additional documentation, see <a href="https://algs4.cs.princeton.edu/51radix">Section
+/// 5.1</a> of <i>Algorithms, 4th Edition</i> by Robert Sedgewick and Kevin Wayne.
+pub fn lsd_sort(a: &mut [String], w: usize) -> Result<(), InvalidArgument> {
+    if let Some(s) = a.iter().find(|s| s.len() < w) {
+        return Err(InvalidArgument(format!(
+            "string {:?} is shorter than {} bytes",
+            s, w
+        )));
+    }
+    // sort by key-indexed counting on the d-th byte
+    for d in (0..w).rev() {
+        key_indexed_counting(a, R, |s| s.as_bytes()[d] as usize)
+            .expect("a byte should be less than the radix");
+    }
+    Ok(())
+}
+
+/// Sorts a slice of strings in ascending order, using <em>MSD (most-significant-digit first) radix
+/// sort</em>, with a cutoff to insertion sort for small subarrays.
+///
+/// It handles strings of variable lengths: a string that is a prefix of another string is less
+/// than it.
+///
+/// It examines only the bytes needed to distinguish the strings, which is sublinear in the total
+/// length of the strings for random inputs, but sorting every subarray takes &Theta;(<em>R</em>)
+/// time for its counting array.  It uses &Theta;(<em>n</em> + <em>R</em>) extra memory: the
+/// subarrays left to sort are kept on an explicit stack, rather than the call stack, so long
+/// common prefixes do not overflow it.
+///
+/// This sorting algorithm is stable.
+///
+/// For additional documentation, see <a href="https://algs4.cs.princeton.edu/51radix">Section
+/// 5.1</a> of <i>Algorithms, 4th Edition</i> by Robert Sedgewick and Kevin Wayne.
+pub fn msd_sort(a: &mut [String]) {
+    let mut aux: Vec<String> = vec![String::new(); a.len()];
+    msd_sort_range(a, &mut aux, 0, a.len(), 0);
+}
+
+/// Sorts a slice of strings in ascending order, using <em>3-way string quicksort</em>, with a
+/// cutoff to insertion sort for small subarrays.
+///
+/// It partitions the strings into three parts on the byte at the current depth: the strings whose
+/// byte is less than, equal to, or greater than the byte of the partitioning string, and only the
+/// middle part moves on to the next byte.  A string that ends before the current depth is less
+/// than any string that has a byte there.
+///
+/// The slice is shuffled first.  It takes ~ 2 <em>n</em> ln <em>n</em> byte compares on average
+/// for random strings, and it adapts well to inputs with long common prefixes, without the
+/// counting arrays of [`msd_sort`].  It uses &Theta;(log <em>n</em> + <em>D</em>) extra memory
+/// for the recursion, where <em>D</em> is the length of the longest common prefix of two strings.
+///
+/// This sorting algorithm is not stable.
+///
+/// For additional documentation, see <a href="https://algs4.cs.princeton.edu/51radix">Section
+/// 5.1</a> of <i>Algorithms, 4th Edition</i> by Robert Sedgewick and Kevin Wayne.
+pub fn three_way_string_quicksort(a: &mut [String]) {
+    shuffle(a, random_seed());
+    quick3string_range(a, 0, a.len(), 0);
+}
+
+// Returns the key of the d-th byte of s: 0 if s has no d-th byte, or the byte value plus 1.
+fn byte_key(s: &str, d: usize) -> usize {
+    s.as_bytes().get(d).map_or(0, |&b| b as usize + 1)
+}
+
+// sort a[lo..hi], starting at the d-th byte
+//
+// Precondition: the strings in a[lo..hi] share the same leading d bytes.
+//
+// The subarrays left to sort are kept on an explicit stack, and one counting array is reused for
+// all of them, rather than recursing, since the depth of the recursion would be the length of the
+// longest common prefix, which can overflow the call stack.
+fn msd_sort_range(a: &mut [String], aux: &mut [String], lo: usize, hi: usize, d: usize) {
+    let mut count = vec![0; R + 2];
+    let mut stack = vec![(lo, hi, d)];
+    while let Some((lo, hi, d)) = stack.pop() {
+        // cutoff to insertion sort for small subarrays
+        if hi - lo <= CUTOFF {
+            insertion(a, lo, hi, d);
+            continue;
+        }
+
+        // compute frequency counts
+        count.fill(0);
+        for s in &a[lo..hi] {
+            count[byte_key(s, d) + 1] += 1;
+        }
+
+        // transform counts to indices
+        for r in 0..=R {
+            count[r + 1] += count[r];
+        }
+
+        // distribute
+        for s in a[lo..hi].iter_mut() {
+            let c = byte_key(s, d);
+            aux[count[c]] = mem::take(s);
+            count[c] += 1;
+        }
+
+        // copy back
+        for (s, t) in a[lo..hi].iter_mut().zip(aux.iter_mut()) {
+            *s = mem::take(t);
+        }
+
+        // sort for each byte value (excludes the strings that end at the d-th byte); the
+        // subarrays are disjoint, so the order in which they are sorted does not matter
+        for r in 0..R {
+            if count[r + 1] - count[r] > 1 {
+                stack.push((lo + count[r], lo + count[r + 1], d + 1));
+            }
+        }
+    }
+}
+
+// 3-way string quicksort a[lo..hi] using the d-th byte as the key
+//
+// Precondition: the strings in a[lo..hi] share the same leading d bytes.
+fn quick3string_range(a: &mut [String], lo: usize, hi: usize, d: usize) {
+    // cutoff to insertion sort for small subarrays
+    if hi - lo <= CUTOFF {
+        insertion(a, lo, hi, d);
+        return;
+    }
+
+    let v = byte_key(&a[lo], d);
+    let (mut lt, mut gt, mut i) = (lo, hi - 1, lo + 1);
+    while i <= gt {
+        let t = byte_key(&a[i], d);
+        if t < v {
+            a.swap(lt, i);
+            lt += 1;
+            i += 1;
+        } else if t > v {
+            a.swap(i, gt);
+            gt -= 1;
+        } else {
+            i += 1;
+        }
+    }
+
+    // a[lo..lt] < v = a[lt..=gt] < a[gt+1..hi]
+    quick3string_range(a, lo, lt, d);
+    if v > 0 {
+        quick3string_range(a, lt, gt + 1, d + 1);
+    }
+    quick3string_range(a, gt + 1, hi, d);
+}
+
+// insertion sort a[lo..hi], starting at the d-th byte
+fn insertion(a: &mut [String], lo: usize, hi: usize, d: usize) {
+    for i in lo..hi {
+        let mut j = i;
+        while j > lo && a[j].as_bytes()[d..] < a[j - 1].as_bytes()[d..] {
+            a.swap(j, j - 1);
+            j -= 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // many shared prefixes, an empty string, a non-ASCII string, and an upper case string
+    const WORDS: &[&str] = &[
+        "she",
+        "sells",
+        "seashells",
+        "by",
+        "the",
+        "sea",
+        "shore",
+        "the",
+        "shells",
+        "she",
+        "sells",
+        "are",
+        "surely",
+        "seashells",
+        "",
+        "s",
+        "é",
+        "zebra",
+        "Zebra",
+    ];
+
+    fn strings(a: &[&str]) -> Vec<String> {
+        a.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_key_indexed_counting() {
+        // students sorted by their sections, keeping the order of the names in each section
+        let mut a = [
+            (2, "Anderson"),
+            (3, "Brown"),
+            (3, "Davis"),
+            (4, "Garcia"),
+            (1, "Harris"),
+            (3, "Jackson"),
+            (4, "Johnson"),
+            (3, "Jones"),
+            (1, "Martin"),
+            (2, "Martinez"),
+        ];
+        key_indexed_counting(&mut a, 5, |x| x.0).unwrap();
+        assert_eq!(
+            a.map(|x| x.1),
+            [
+                "Harris", "Martin", "Anderson", "Martinez", "Brown", "Davis", "Jackson", "Jones",
+                "Garcia", "Johnson"
+            ]
+        );
+
+        let mut a = [1, 0, 5];
+        assert!(key_indexed_counting(&mut a, 5, |&x| x).is_err());
+        assert_eq!(a, [1, 0, 5]);
+    }
+
+    #[test]
+    fn test_lsd_sort() {
+        let mut a = strings(&[
+            "4PGC938", "2IYE230", "3CIO720", "1ICK750", "1OHV845", "4JZY524", "1ICK750", "3CIO720",
+            "1OHV845", "1OHV845", "2RLA629", "2RLA629", "3ATW723",
+        ]);
+        let mut expected = a.clone();
+        expected.sort();
+        lsd_sort(&mut a, 7).unwrap();
+        assert_eq!(a, expected);
+
+        // only the leading w bytes are sorted on, stably
+        let mut a = strings(&["bz", "ab", "by", "aa"]);
+        lsd_sort(&mut a, 1).unwrap();
+        assert_eq!(a, ["ab", "aa", "bz", "by"]);
+
+        let mut a = strings(&["abc", "ab"]);
+        assert!(lsd_sort(&mut a, 3).is_err());
+        assert_eq!(a, ["abc", "ab"]);
+        let mut a: Vec<String> = Vec::new();
+        lsd_sort(&mut a, 3).unwrap();
+    }
+
+    #[test]
+    fn test_msd_sort() {
+        let mut a = strings(WORDS);
+        let mut expected = a.clone();
+        expected.sort();
+        msd_sort(&mut a);
+        assert_eq!(a, expected);
+
+        let mut a: Vec<String> = (0..2000)
+            .map(|_| {
+                let len = rand::random_range(0..8);
+                (0..len)
+                    .map(|_| rand::random_range(b'a'..=b'd') as char)
+                    .collect()
+            })
+            .collect();
+        let mut expected = a.clone();
+        expected.sort();
+        msd_sort(&mut a);
+        assert_eq!(a, expected);
+
+        // long common prefixes, which would overflow the call stack with a recursive call per byte
+        let mut a: Vec<String> = vec!["a".repeat(10_000); 20];
+        a.extend((0..20).map(|i| format!("{}{}", "b".repeat(10_000), 19 - i)));
+        let mut expected = a.clone();
+        expected.sort();
+        msd_sort(&mut a);
+        assert_eq!(a, expected);
+
+        let mut a: Vec<String> = Vec::new();
+        msd_sort(&mut a);
+        let mut a = strings(&["x"]);
+        msd_sort(&mut a);
+        assert_eq!(a, ["x"]);
+    }
+
+    #[test]
+    fn test_three_way_string_quicksort() {
+        let mut a = strings(WORDS);
+        let mut expected = a.clone();
+        expected.sort();
+        three_way_string_quicksort(&mut a);
+        assert_eq!(a, expected);
+
+        // many long common prefixes
+        let mut a: Vec<String> = (0..2000)
+            .map(|_| {
+                let mut s = "seashells".repeat(rand::random_range(0..3));
+                let len = rand::random_range(0..4);
+                s.extend((0..len).map(|_| rand::random_range(b'a'..=b'c') as char));
+                s
+            })
+            .collect();
+        let mut expected = a.clone();
+        expected.sort();
+        three_way_string_quicksort(&mut a);
+        assert_eq!(a, expected);
+
+        let mut a: Vec<String> = Vec::new();
+        three_way_string_quicksort(&mut a);
+        let mut a = strings(&["x"]);
+        three_way_string_quicksort(&mut a);
+        assert_eq!(a, ["x"]);
+    }
+}
@@ -0,0 +1,71 @@
+//! Selection sort.
+
+use std::cmp::Ordering;
+
+/// Sorts a slice using <em>selection sort</em>.
+///
+/// This implementation makes ~ 1/2 <em>n</em><sup>2</sup> compares to sort any slice of length
+/// <em>n</em>, so it is not suitable for sorting large slices.  It performs exactly <em>n</em>
+/// exchanges.
+///
+/// This sorting algorithm is not stable.
+///
+/// It uses &Theta;(1) extra memory (not including the input slice).
+///
+/// For additional documentation, see <a href="https://algs4.cs.princeton.edu/21elementary">Section
+/// 2.1</a> of <i>Algorithms, 4th Edition</i> by Robert Sedgewick and Kevin Wayne.
+pub fn sort<T: Ord>(a: &mut [T]) {
+    sort_by(a, T::cmp);
+}
+
+/// Sorts a slice with a comparator function using <em>selection sort</em>.
+///
+/// See [`sort`].
+pub fn sort_by<T, F>(a: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let n = a.len();
+    for i in 0..n {
+        let mut min = i;
+        for j in (i + 1)..n {
+            if compare(&a[j], &a[min]).is_lt() {
+                min = j;
+            }
+        }
+        a.swap(i, min);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sort::is_sorted;
+
+    #[test]
+    fn test_selection_sort() {
+        let mut a: [i32; 0] = [];
+        sort(&mut a);
+        assert!(is_sorted(&a));
+
+        let mut a = [1];
+        sort(&mut a);
+        assert_eq!(a, [1]);
+
+        let mut a = ["S", "O", "R", "T", "E", "X", "A", "M", "P", "L", "E"];
+        sort(&mut a);
+        assert_eq!(a, ["A", "E", "E", "L", "M", "O", "P", "R", "S", "T", "X"]);
+
+        let mut a: Vec<i32> = (0..1000).map(|_| rand::random_range(-100..100)).collect();
+        sort(&mut a);
+        assert!(is_sorted(&a));
+
+        let mut a: Vec<i32> = (0..1000).collect();
+        sort(&mut a);
+        assert_eq!(a, (0..1000).collect::<Vec<i32>>());
+
+        let mut a = [5, 3, 9, 1];
+        sort_by(&mut a, |x, y| y.cmp(x));
+        assert_eq!(a, [9, 5, 3, 1]);
+    }
+}
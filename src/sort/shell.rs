@@ -0,0 +1,86 @@
+//! Shellsort.
+
+use std::cmp::Ordering;
+
+/// Sorts a slice using <em>Shellsort</em> with Knuth's increment sequence (1, 4, 13, 40, ...).
+///
+/// In the worst case, this implementation makes &Theta;(<em>n</em><sup>3/2</sup>) compares and
+/// exchanges to sort a slice of length <em>n</em>.
+///
+/// This sorting algorithm is not stable.
+///
+/// It uses &Theta;(1) extra memory (not including the input slice).
+///
+/// For additional documentation, see <a href="https://algs4.cs.princeton.edu/21elementary">Section
+/// 2.1</a> of <i>Algorithms, 4th Edition</i> by Robert Sedgewick and Kevin Wayne.
+pub fn sort<T: Ord>(a: &mut [T]) {
+    sort_by(a, T::cmp);
+}
+
+/// Sorts a slice with a comparator function using <em>Shellsort</em>.
+///
+/// See [`sort`].
+pub fn sort_by<T, F>(a: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let n = a.len();
+
+    // 3x+1 increment sequence:  1, 4, 13, 40, 121, 364, 1093, ...
+    let mut h = 1;
+    while h < n / 3 {
+        h = 3 * h + 1;
+    }
+
+    while h >= 1 {
+        // h-sort the slice
+        for i in h..n {
+            let mut j = i;
+            while j >= h && compare(&a[j], &a[j - h]).is_lt() {
+                a.swap(j, j - h);
+                j -= h;
+            }
+        }
+        h /= 3;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sort::is_sorted;
+
+    #[test]
+    fn test_shell_sort() {
+        let mut a: [i32; 0] = [];
+        sort(&mut a);
+        assert!(is_sorted(&a));
+
+        let mut a = [1];
+        sort(&mut a);
+        assert_eq!(a, [1]);
+
+        let mut a = [
+            "S", "H", "E", "L", "L", "S", "O", "R", "T", "E", "X", "A", "M", "P", "L", "E",
+        ];
+        sort(&mut a);
+        assert_eq!(
+            a,
+            [
+                "A", "E", "E", "E", "H", "L", "L", "L", "M", "O", "P", "R", "S", "S", "T", "X"
+            ]
+        );
+
+        let mut a: Vec<i32> = (0..1000).map(|_| rand::random_range(-100..100)).collect();
+        sort(&mut a);
+        assert!(is_sorted(&a));
+
+        let mut a: Vec<i32> = (0..1000).collect();
+        sort(&mut a);
+        assert_eq!(a, (0..1000).collect::<Vec<i32>>());
+
+        let mut a = [5, 3, 9, 1];
+        sort_by(&mut a, |x, y| y.cmp(x));
+        assert_eq!(a, [9, 5, 3, 1]);
+    }
+}
@@ -1,5 +1,6 @@
 pub(crate) mod linkedstack;
 pub(crate) mod resizingstack;
+pub(crate) mod steque;
 
 #[cfg(test)]
 mod tests;
@@ -84,6 +84,12 @@ impl<T> LinkedStack<T> {
         Some(&self.first.as_ref().unwrap().item)
     }
 
+    /// Returns a mutable reference to the item most recently added to this stack, or `None` if the
+    /// stack is empty.
+    pub fn peek_mut(&mut self) -> Option<&mut T> {
+        self.first.as_mut().map(|node| &mut node.item)
+    }
+
     /// Returns an iterator that iterates over the items in this bag in LIFO order.
     pub fn iter(&self) -> LinedStackIter<'_, T> {
         LinedStackIter {
@@ -91,6 +97,14 @@ impl<T> LinkedStack<T> {
         }
     }
 
+    /// Returns an iterator that iterates over mutable references to the items in this stack in
+    /// LIFO order.
+    pub fn iter_mut(&mut self) -> LinkedStackIterMut<'_, T> {
+        LinkedStackIterMut {
+            current: self.first.as_deref_mut(),
+        }
+    }
+
     // Check internal invariants.
     fn check(&self) -> bool {
         if self.n == 0 {
@@ -163,6 +177,58 @@ impl<'a, T> Iterator for LinedStackIter<'a, T> {
     }
 }
 
+pub struct LinkedStackIntoIter<T> {
+    moved_stack: LinkedStack<T>,
+}
+
+/// Consumes the stack, yielding the items in LIFO order.
+impl<T> IntoIterator for LinkedStack<T> {
+    type Item = T;
+    type IntoIter = LinkedStackIntoIter<T>;
+    fn into_iter(self) -> Self::IntoIter {
+        LinkedStackIntoIter { moved_stack: self }
+    }
+}
+
+impl<T> Iterator for LinkedStackIntoIter<T> {
+    type Item = T;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.moved_stack.pop()
+    }
+}
+
+/// Pushes the items in order, so the last item becomes the top of the stack.
+impl<T> FromIterator<T> for LinkedStack<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut st = LinkedStack::new();
+        st.extend(iter);
+        st
+    }
+}
+
+/// Pushes the items in order, so the last item becomes the top of the stack.
+impl<T> Extend<T> for LinkedStack<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.push(item);
+        }
+    }
+}
+
+pub struct LinkedStackIterMut<'a, T> {
+    current: Option<&'a mut Node<T>>,
+}
+
+impl<'a, T> Iterator for LinkedStackIterMut<'a, T> {
+    type Item = &'a mut T;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.current.take().map(|node| {
+            self.current = node.next.as_deref_mut();
+            &mut node.item
+        })
+    }
+}
+
 /// Implementing `std::fmt::Display` will automatically implement the `ToString` trait for
 /// `LinkedStack<T>`, allowing the usage of the `.to_string()` method.
 impl<T: fmt::Display> fmt::Display for LinkedStack<T> {
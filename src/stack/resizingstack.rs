@@ -52,6 +52,12 @@ impl<T> ResizingStack<T> {
         self.data.last()
     }
 
+    /// Returns a mutable reference to the item most recently added to this stack, or `None` if the
+    /// stack is empty.
+    pub fn peek_mut(&mut self) -> Option<&mut T> {
+        self.data.last_mut()
+    }
+
     /// Returns an iterator that iterates over the items in this stack in LIFO order.
     pub fn iter(&self) -> ResizingStackIter<'_, T> {
         ResizingStackIter {
@@ -59,6 +65,12 @@ impl<T> ResizingStack<T> {
             cursor: self.data.len(), // points to the next of the top (end) Node
         }
     }
+
+    /// Returns an iterator that iterates over mutable references to the items in this stack in
+    /// LIFO order.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.data.iter_mut().rev()
+    }
 }
 
 impl<T> Default for ResizingStack<T> {
@@ -85,6 +97,44 @@ impl<'a, T> Iterator for ResizingStackIter<'a, T> {
     }
 }
 
+pub struct ResizingStackIntoIter<T> {
+    moved_stack: ResizingStack<T>,
+}
+
+/// Consumes the stack, yielding the items in LIFO order.
+impl<T> IntoIterator for ResizingStack<T> {
+    type Item = T;
+    type IntoIter = ResizingStackIntoIter<T>;
+    fn into_iter(self) -> Self::IntoIter {
+        ResizingStackIntoIter { moved_stack: self }
+    }
+}
+
+impl<T> Iterator for ResizingStackIntoIter<T> {
+    type Item = T;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.moved_stack.pop()
+    }
+}
+
+/// Pushes the items in order, so the last item becomes the top of the stack.
+impl<T> FromIterator<T> for ResizingStack<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut st = ResizingStack::new();
+        st.extend(iter);
+        st
+    }
+}
+
+/// Pushes the items in order, so the last item becomes the top of the stack.
+impl<T> Extend<T> for ResizingStack<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.push(item);
+        }
+    }
+}
+
 /// Implementing `std::fmt::Display` will automatically implement the `ToString` trait for
 /// `ResizingStack<T>`, allowing the usage of the `.to_string()` method.
 impl<T: fmt::Display> fmt::Display for ResizingStack<T> {
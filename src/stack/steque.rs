@@ -0,0 +1,192 @@
+use std::fmt;
+use std::ptr::NonNull;
+
+/// A stack-ended queue (steque) of generic items.
+///
+/// It is a stack that also supports adding items at the bottom: `push` adds an item to the top,
+/// `pop` removes the item at the top, and `enqueue` adds an item to the bottom.  So the items
+/// pushed are popped in last-in-first-out (LIFO) order, while the items enqueued are popped in
+/// first-in-first-out (FIFO) order.  It also provides methods for peeking at the top item, testing
+/// if the steque is empty, and iterating through the items from the top to the bottom.
+///
+/// The `push`, `pop`, `enqueue`, `peek`, `len`, and `is_empty` operations all take constant time
+/// in the worst case.
+///
+/// For additional documentation, see <a href="https://algs4.cs.princeton.edu/13stacks">Section
+/// 1.3</a> (Exercise 1.3.32) of <i>Algorithms, 4th Edition</i> by Robert Sedgewick and Kevin
+/// Wayne.
+///
+/// # Implementation considerations
+///
+/// This implementation uses a singly linked list with pointers to both ends, which are
+/// `Option<NonNull<Node<T>>>`, like [`crate::LinkedQueue`].  See the implementation
+/// considerations of [`crate::LinkedQueue`] for why.
+pub struct Steque<T> {
+    first: Option<NonNull<Node<T>>>, // top of steque
+    last: Option<NonNull<Node<T>>>,  // bottom of steque
+    n: usize,                        // number of elements on steque
+}
+
+struct Node<T> {
+    item: T,
+    next: Option<NonNull<Node<T>>>,
+}
+
+impl<T> Node<T> {
+    fn new(item: T) -> Self {
+        Node { item, next: None }
+    }
+}
+
+impl<T> Steque<T> {
+    /// Initializes an empty steque.
+    pub fn new() -> Self {
+        let st = Steque {
+            first: None,
+            last: None,
+            n: 0,
+        };
+        debug_assert!(st.check());
+        st
+    }
+
+    /// Is this steque empty?
+    pub fn is_empty(&self) -> bool {
+        self.first.is_none()
+    }
+
+    /// Returns the number of items on this steque.
+    pub fn len(&self) -> usize {
+        self.n
+    }
+
+    /// Adds the item to the top of this steque.
+    pub fn push(&mut self, item: T) {
+        let mut node = Node::new(item);
+        node.next = self.first;
+        let new_first = Some(NonNull::new(Box::into_raw(Box::new(node))).unwrap());
+        if self.last.is_none() {
+            self.last = new_first;
+        }
+        self.first = new_first;
+        self.n += 1;
+        debug_assert!(self.check());
+    }
+
+    /// Adds the item to the bottom of this steque.
+    pub fn enqueue(&mut self, item: T) {
+        let new_last = Some(NonNull::new(Box::into_raw(Box::new(Node::new(item)))).unwrap());
+        match self.last {
+            None => self.first = new_last,
+            Some(mut last) => unsafe {
+                last.as_mut().next = new_last;
+            },
+        }
+        self.last = new_last;
+        self.n += 1;
+        debug_assert!(self.check());
+    }
+
+    /// Removes and returns the item at the top of this steque, or `None` if the steque is empty.
+    pub fn pop(&mut self) -> Option<T> {
+        self.first?; // if it is_none(), return None
+        let first = unsafe { Box::from_raw(self.first.unwrap().as_ptr()) };
+        self.first = first.next;
+        if self.first.is_none() {
+            self.last = None;
+        }
+        self.n -= 1;
+        debug_assert!(self.check());
+        Some(first.item)
+    }
+
+    /// Returns (but does not remove) the item at the top of this steque.
+    pub fn peek(&self) -> Option<&T> {
+        unsafe { self.first.map(|non_null| &(*non_null.as_ptr()).item) }
+    }
+
+    /// Returns an iterator that iterates over the items in this steque from the top to the bottom.
+    pub fn iter(&self) -> StequeIter<'_, T> {
+        StequeIter {
+            current: unsafe { self.first.map(|non_null| &*non_null.as_ptr()) },
+        }
+    }
+
+    // Check internal invariants.
+    fn check(&self) -> bool {
+        if self.n == 0 {
+            return self.first.is_none() && self.last.is_none();
+        }
+        if self.first.is_none() || self.last.is_none() {
+            return false;
+        }
+        if self.n == 1 && self.first != self.last {
+            return false;
+        }
+
+        // check internal consistency of instance variable n, and that `last` is the last node
+        let mut count_nodes: usize = 0;
+        let mut last = None;
+        let mut x = self.first;
+        while let Some(node) = x {
+            if count_nodes > self.n {
+                return false;
+            }
+            last = x;
+            x = unsafe { (*node.as_ptr()).next };
+            count_nodes += 1;
+        }
+        count_nodes == self.n && last == self.last
+    }
+}
+
+impl<T> Default for Steque<T> {
+    fn default() -> Self {
+        Steque::new()
+    }
+}
+
+impl<T> Drop for Steque<T> {
+    fn drop(&mut self) {
+        while self.pop().is_some() {}
+    }
+}
+
+pub struct StequeIter<'a, T> {
+    current: Option<&'a Node<T>>,
+}
+
+impl<'a, T> Iterator for StequeIter<'a, T> {
+    type Item = &'a T;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.current.map(|node| {
+            unsafe {
+                self.current = node.next.map(|non_null| &*non_null.as_ptr());
+            }
+            &node.item
+        })
+    }
+}
+
+impl<T: Clone> Clone for Steque<T> {
+    fn clone(&self) -> Self {
+        let mut st = Steque::new();
+        for x in self.iter() {
+            st.enqueue(x.clone());
+        }
+        st
+    }
+}
+
+/// Implementing `std::fmt::Display` will automatically implement the `ToString` trait for
+/// `Steque<T>`, allowing the usage of the `.to_string()` method.
+impl<T: fmt::Display> fmt::Display for Steque<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut s = String::new();
+        for x in self.iter() {
+            s.push_str(&x.to_string());
+            s.push(' ');
+        }
+        write!(f, "{}", s)
+    }
+}
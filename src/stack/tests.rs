@@ -1,5 +1,6 @@
 use super::linkedstack::LinkedStack;
 use super::resizingstack::ResizingStack;
+use super::steque::Steque;
 
 #[test]
 fn linked_stack_of_str() {
@@ -52,3 +53,128 @@ fn resizing_stack_of_str() {
     let st2 = st.clone();
     assert_eq!(st2.to_string(), "is to ");
 }
+
+#[test]
+fn steque_of_str() {
+    let mut st = Steque::new();
+    assert_eq!(st.iter().count(), 0);
+    assert_eq!(st.peek(), None);
+    assert_eq!(st.pop(), None);
+
+    // "+x" enqueues x, "-" pops, others push
+    let list = [
+        "to", "be", "+or", "not", "-", "+to", "-", "be", "+that", "-", "-", "is", "+not", "-", "-",
+    ];
+    let mut popped = Vec::new();
+    for item in list {
+        if item == "-" {
+            popped.push(st.pop().unwrap());
+        } else if let Some(x) = item.strip_prefix('+') {
+            st.enqueue(x);
+        } else {
+            st.push(item);
+        }
+    }
+    let popped_str = popped.join(" ");
+    let steque_len = format!("({} left on steque)", st.len());
+    let output = format!("{} {}", popped_str, steque_len);
+    assert_eq!(output, "not be be to is or (3 left on steque)");
+    assert_eq!(st.to_string(), "to that not ");
+    assert_eq!(st.peek(), Some(&"to"));
+
+    // test clone
+    let st2 = st.clone();
+    assert_eq!(st2.to_string(), "to that not ");
+
+    assert_eq!(st.pop(), Some("to"));
+    assert_eq!(st.pop(), Some("that"));
+    assert_eq!(st.pop(), Some("not"));
+    assert!(st.is_empty());
+    assert_eq!(st.pop(), None);
+
+    // enqueue on an empty steque, then push
+    st.enqueue("b");
+    st.push("a");
+    st.enqueue("c");
+    assert_eq!(st.iter().copied().collect::<Vec<_>>(), ["a", "b", "c"]);
+}
+
+#[test]
+fn steque_drop() {
+    let mut st: Steque<String> = Steque::new();
+    for i in 0..10 {
+        if i % 3 == 0 {
+            st.enqueue(i.to_string());
+        } else {
+            st.push(i.to_string());
+        }
+    }
+    assert_eq!(st.pop(), Some("8".to_string()));
+    assert_eq!(st.len(), 9);
+    // Drop should be good, no memory issue.
+}
+
+#[test]
+fn linked_stack_into_iter_from_iter() {
+    let st: LinkedStack<i32> = (0..5).collect();
+    assert_eq!(st.len(), 5);
+    assert_eq!(st.peek(), Some(&4));
+    // collecting then draining reverses a sequence
+    assert_eq!(st.into_iter().collect::<Vec<_>>(), [4, 3, 2, 1, 0]);
+
+    let mut st = LinkedStack::new();
+    st.push("to".to_string());
+    st.extend(["be", "or"].map(String::from));
+    let mut consumed = Vec::new();
+    for item in st {
+        consumed.push(item);
+    }
+    assert_eq!(consumed, ["or", "be", "to"]);
+    assert_eq!(LinkedStack::<i32>::new().into_iter().next(), None);
+}
+
+#[test]
+fn resizing_stack_into_iter_from_iter() {
+    let st: ResizingStack<i32> = (0..5).collect();
+    assert_eq!(st.len(), 5);
+    assert_eq!(st.peek(), Some(&4));
+    // collecting then draining reverses a sequence
+    assert_eq!(st.into_iter().collect::<Vec<_>>(), [4, 3, 2, 1, 0]);
+
+    let mut st = ResizingStack::new();
+    st.push("to".to_string());
+    st.extend(["be", "or"].map(String::from));
+    let mut consumed = Vec::new();
+    for item in st {
+        consumed.push(item);
+    }
+    assert_eq!(consumed, ["or", "be", "to"]);
+    assert_eq!(ResizingStack::<i32>::new().into_iter().next(), None);
+}
+
+#[test]
+fn linked_stack_peek_mut_iter_mut() {
+    let mut st: LinkedStack<i32> = LinkedStack::new();
+    assert_eq!(st.peek_mut(), None);
+    st.extend([1, 2, 3]);
+    *st.peek_mut().unwrap() += 10;
+    for x in st.iter_mut() {
+        *x *= 2;
+    }
+    assert_eq!(st.pop(), Some(26));
+    assert_eq!(st.iter().copied().collect::<Vec<_>>(), [4, 2]);
+}
+
+#[test]
+fn resizing_stack_peek_mut_iter_mut() {
+    let mut st: ResizingStack<i32> = ResizingStack::new();
+    assert_eq!(st.peek_mut(), None);
+    st.extend([1, 2, 3]);
+    *st.peek_mut().unwrap() += 10;
+    assert_eq!(st.iter_mut().next(), Some(&mut 13));
+    for x in st.iter_mut() {
+        *x *= 2;
+    }
+    assert_eq!(st.pop(), Some(26));
+    assert_eq!(st.iter().copied().collect::<Vec<_>>(), [4, 2]);
+}
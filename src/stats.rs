@@ -0,0 +1,181 @@
+//! Basic statistics of real numbers: mean, sample standard deviation, and median.
+//!
+//! The [`Accumulator`] computes them over a stream of values without storing the values, and the
+//! free functions compute them over a slice.
+//!
+//! For additional documentation, see <a href="https://algs4.cs.princeton.edu/12oop">Section
+//! 1.2</a> of <i>Algorithms, 4th Edition</i> by Robert Sedgewick and Kevin Wayne.
+
+use std::fmt;
+
+/// A data type that computes the running mean, sample variance, and sample standard deviation of
+/// a stream of real numbers.
+///
+/// This implementation uses Welford's one-pass algorithm, which is numerically more stable than
+/// accumulating the sum of squares.  Each operation takes &Theta;(1) time and the data type uses
+/// &Theta;(1) space.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Accumulator {
+    n: usize, // number of data values
+    sum: f64, // sum of squared deviations from the mean
+    mu: f64,  // mean of data values
+}
+
+impl Accumulator {
+    /// Initializes an accumulator with no data values.
+    pub fn new() -> Self {
+        Accumulator::default()
+    }
+
+    /// Adds the specified data value to the accumulator.
+    pub fn add(&mut self, x: f64) {
+        self.n += 1;
+        let delta = x - self.mu;
+        self.mu += delta / self.n as f64;
+        self.sum += delta * (x - self.mu);
+    }
+
+    /// Returns the mean of the data values, or `NaN` if there is no data value.
+    pub fn mean(&self) -> f64 {
+        if self.n == 0 { f64::NAN } else { self.mu }
+    }
+
+    /// Returns the sample variance of the data values, or `NaN` if there are fewer than two data
+    /// values.
+    pub fn var(&self) -> f64 {
+        if self.n <= 1 {
+            f64::NAN
+        } else {
+            self.sum / (self.n - 1) as f64
+        }
+    }
+
+    /// Returns the sample standard deviation of the data values, or `NaN` if there are fewer than
+    /// two data values.
+    pub fn stddev(&self) -> f64 {
+        self.var().sqrt()
+    }
+
+    /// Returns the number of data values.
+    pub fn count(&self) -> usize {
+        self.n
+    }
+}
+
+impl Extend<f64> for Accumulator {
+    fn extend<I: IntoIterator<Item = f64>>(&mut self, iter: I) {
+        for x in iter {
+            self.add(x);
+        }
+    }
+}
+
+impl FromIterator<f64> for Accumulator {
+    fn from_iter<I: IntoIterator<Item = f64>>(iter: I) -> Self {
+        let mut acc = Accumulator::new();
+        acc.extend(iter);
+        acc
+    }
+}
+
+impl fmt::Display for Accumulator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "n = {}, mean = {:.5}, stddev = {:.5}",
+            self.n,
+            self.mean(),
+            self.stddev()
+        )
+    }
+}
+
+/// Returns the average value of `a`, or `NaN` if `a` is empty.
+pub fn mean(a: &[f64]) -> f64 {
+    if a.is_empty() {
+        return f64::NAN;
+    }
+    a.iter().sum::<f64>() / a.len() as f64
+}
+
+/// Returns the sample variance of `a`, or `NaN` if `a` has fewer than two values.
+pub fn var(a: &[f64]) -> f64 {
+    if a.len() <= 1 {
+        return f64::NAN;
+    }
+    let mu = mean(a);
+    a.iter().map(|x| (x - mu) * (x - mu)).sum::<f64>() / (a.len() - 1) as f64
+}
+
+/// Returns the sample standard deviation of `a`, or `NaN` if `a` has fewer than two values.
+pub fn stddev(a: &[f64]) -> f64 {
+    var(a).sqrt()
+}
+
+/// Returns the median of `a`, that is, the middle value in sorted order, or the average of the two
+/// middle values if the length of `a` is even.  It returns `NaN` if `a` is empty.
+///
+/// The values are ordered by [`f64::total_cmp`], and `a` is left unchanged.  It takes
+/// &Theta;(<em>n</em> log <em>n</em>) time.
+pub fn median(a: &[f64]) -> f64 {
+    let n = a.len();
+    if n == 0 {
+        return f64::NAN;
+    }
+    let mut sorted = a.to_vec();
+    sorted.sort_unstable_by(f64::total_cmp);
+    if n % 2 == 1 {
+        sorted[n / 2]
+    } else {
+        (sorted[n / 2 - 1] + sorted[n / 2]) / 2.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_accumulator() {
+        let mut acc = Accumulator::new();
+        assert_eq!(acc.count(), 0);
+        assert!(acc.mean().is_nan());
+        acc.add(4.0);
+        assert_eq!(acc.mean(), 4.0);
+        assert!(acc.var().is_nan());
+
+        acc.extend([2.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]);
+        assert_eq!(acc.count(), 8);
+        assert_eq!(acc.mean(), 5.0);
+        // the sum of squared deviations is 32
+        assert!((acc.var() - 32.0 / 7.0).abs() < 1e-12);
+        assert!((acc.stddev() - (32.0f64 / 7.0).sqrt()).abs() < 1e-12);
+        assert_eq!(acc.to_string(), "n = 8, mean = 5.00000, stddev = 2.13809");
+    }
+
+    #[test]
+    fn test_accumulator_matches_two_pass() {
+        // a large offset makes the naive sum of squares inaccurate
+        let a: Vec<f64> = (0..1000)
+            .map(|_| 1e6 + rand::random_range(0.0..1.0))
+            .collect();
+        let acc: Accumulator = a.iter().copied().collect();
+        assert_eq!(acc.count(), 1000);
+        assert!((acc.mean() - mean(&a)).abs() < 1e-6);
+        assert!((acc.var() - var(&a)).abs() < 1e-6);
+        assert!((acc.stddev() - stddev(&a)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_slice_statistics() {
+        let a = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+        assert_eq!(mean(&a), 5.0);
+        assert!((var(&a) - 32.0 / 7.0).abs() < 1e-12);
+        assert_eq!(median(&a), 4.5);
+        assert_eq!(median(&[3.0, -1.0, 2.0]), 2.0);
+        assert_eq!(median(&[1.0]), 1.0);
+        assert!(mean(&[]).is_nan());
+        assert!(stddev(&[1.0]).is_nan());
+        assert!(median(&[]).is_nan());
+    }
+}
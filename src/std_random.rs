@@ -0,0 +1,153 @@
+use crate::xorshift::XorShift;
+use std::collections::hash_map::RandomState;
+use std::hash::BuildHasher;
+
+/// A seeded source of random numbers of various distributions, and random permutations.
+///
+/// It supports generating uniformly distributed integers and real numbers, Bernoulli trials, and
+/// uniformly random shuffles of slices.  Two `StdRandom` created by `with_seed` with the same seed
+/// generate the same sequence of random values, which makes experiments reproducible.
+///
+/// For additional documentation, see <a href="https://introcs.cs.princeton.edu/22library">Section
+/// 2.2</a> of <i>Computer Science: An Interdisciplinary Approach</i> by Robert Sedgewick and
+/// Kevin Wayne.
+///
+/// # Implementation considerations
+///
+/// To stay free of dependencies, the random numbers are generated by a xorshift64* generator,
+/// which is fast but not cryptographically secure.  `new` seeds it from the standard library's
+/// per-process random hasher keys.  Each operation takes &Theta;(1) time, except `shuffle`, which
+/// takes &Theta;(<em>n</em>) time.
+pub struct StdRandom {
+    seed: u64, // the seed of the generator
+    rng: XorShift,
+}
+
+impl StdRandom {
+    /// Initializes a random number generator with a random seed.
+    pub fn new() -> Self {
+        StdRandom::with_seed(RandomState::new().hash_one(0u64))
+    }
+
+    /// Initializes a random number generator whose sequence of random values is determined by
+    /// `seed`.
+    pub fn with_seed(seed: u64) -> Self {
+        StdRandom {
+            seed,
+            rng: XorShift::new(seed),
+        }
+    }
+
+    /// Returns the seed of this random number generator, so that its sequence of random values
+    /// can be reproduced by `with_seed`.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Returns a random integer uniformly in `[0, n)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is `0`.
+    pub fn uniform_int(&self, n: usize) -> usize {
+        assert!(n > 0, "argument must be positive: {}", n);
+        self.rng.uniform(n)
+    }
+
+    /// Returns a random real number uniformly in `[0, 1)`.
+    pub fn uniform_f64(&self) -> f64 {
+        self.rng.uniform_f64()
+    }
+
+    /// Returns a random boolean from a Bernoulli distribution with success probability `p`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `p` is not between `0.0` and `1.0`.
+    pub fn bernoulli(&self, p: f64) -> bool {
+        assert!(
+            (0.0..=1.0).contains(&p),
+            "probability p must be between 0.0 and 1.0: {}",
+            p
+        );
+        self.uniform_f64() < p
+    }
+
+    /// Rearranges the elements of `a` in uniformly random order, by the Knuth (Fisher-Yates)
+    /// shuffle.
+    pub fn shuffle<T>(&self, a: &mut [T]) {
+        for i in (1..a.len()).rev() {
+            a.swap(i, self.rng.uniform(i + 1));
+        }
+    }
+}
+
+impl Default for StdRandom {
+    fn default() -> Self {
+        StdRandom::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shuffle_with_seed() {
+        let mut a: Vec<usize> = (0..100).collect();
+        let mut b = a.clone();
+        StdRandom::with_seed(42).shuffle(&mut a);
+        StdRandom::with_seed(42).shuffle(&mut b);
+        assert_eq!(a, b);
+        assert_ne!(a, (0..100).collect::<Vec<_>>());
+
+        // a permutation of the same elements
+        a.sort_unstable();
+        assert_eq!(a, (0..100).collect::<Vec<_>>());
+
+        let mut empty: [i32; 0] = [];
+        StdRandom::new().shuffle(&mut empty);
+        let mut one = [1];
+        StdRandom::new().shuffle(&mut one);
+        assert_eq!(one, [1]);
+    }
+
+    #[test]
+    fn test_seed() {
+        let r = StdRandom::new();
+        let s = StdRandom::with_seed(r.seed());
+        assert!((0..100).all(|_| r.uniform_int(1000) == s.uniform_int(1000)));
+        assert_eq!(StdRandom::with_seed(42).seed(), 42);
+    }
+
+    #[test]
+    fn test_uniform() {
+        let r = StdRandom::with_seed(7);
+        let mut counts = [0; 6];
+        for _ in 0..6000 {
+            counts[r.uniform_int(6)] += 1;
+        }
+        assert!(counts.iter().all(|&c| c > 800 && c < 1200));
+        assert_eq!(r.uniform_int(1), 0);
+
+        for _ in 0..1000 {
+            let x = r.uniform_f64();
+            assert!((0.0..1.0).contains(&x));
+        }
+    }
+
+    #[test]
+    fn test_bernoulli() {
+        let r = StdRandom::with_seed(7);
+        assert!(!r.bernoulli(0.0));
+        assert!(r.bernoulli(1.0));
+        let heads = (0..10000).filter(|_| r.bernoulli(0.3)).count();
+        assert!(heads > 2700 && heads < 3300);
+    }
+
+    #[test]
+    #[should_panic(expected = "argument must be positive")]
+    fn test_uniform_int_zero() {
+        StdRandom::new().uniform_int(0);
+    }
+}
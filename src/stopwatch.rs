@@ -0,0 +1,132 @@
+use std::time::{Duration, Instant};
+
+/// A data type for measuring the elapsed time (in seconds) since it was created.
+///
+/// It uses the monotonic clock of [`Instant`], rather than the wall clock, so the elapsed time
+/// never decreases, even if the system time is changed.  It measures real time; see
+/// [`StopwatchCPU`] for the CPU time of the current thread.
+///
+/// For additional documentation, see <a href="https://algs4.cs.princeton.edu/14analysis">Section
+/// 1.4</a> of <i>Algorithms, 4th Edition</i> by Robert Sedgewick and Kevin Wayne.
+#[derive(Debug, Clone, Copy)]
+pub struct Stopwatch {
+    start: Instant,
+}
+
+impl Stopwatch {
+    /// Initializes a new stopwatch, which starts timing.
+    pub fn new() -> Self {
+        Stopwatch {
+            start: Instant::now(),
+        }
+    }
+
+    /// Returns the elapsed time since the stopwatch was created.
+    pub fn elapsed(&self) -> Duration {
+        self.start.elapsed()
+    }
+
+    /// Returns the elapsed time (in seconds) since the stopwatch was created.
+    pub fn elapsed_seconds(&self) -> f64 {
+        self.elapsed().as_secs_f64()
+    }
+}
+
+impl Default for Stopwatch {
+    fn default() -> Self {
+        Stopwatch::new()
+    }
+}
+
+/// A data type for measuring the CPU time (in seconds) that the current thread has used since it
+/// was created.
+///
+/// Unlike [`Stopwatch`], it does not count the time that the thread is sleeping, blocked, or
+/// waiting for a CPU, so it is less sensitive to the other processes on the machine.  It should be
+/// read on the thread that created it.
+///
+/// The CPU time is read from `/proc/thread-self/schedstat`, which Linux provides.  Where it is not
+/// available, the stopwatch falls back to measuring real time, as [`Stopwatch`] does.
+///
+/// For additional documentation, see <a href="https://algs4.cs.princeton.edu/14analysis">Section
+/// 1.4</a> of <i>Algorithms, 4th Edition</i> by Robert Sedgewick and Kevin Wayne.
+#[derive(Debug, Clone, Copy)]
+pub struct StopwatchCPU {
+    start_cpu: Option<Duration>, // CPU time of the thread at creation, if available
+    start: Instant,              // for the fallback to real time
+}
+
+impl StopwatchCPU {
+    /// Initializes a new stopwatch, which starts timing.
+    pub fn new() -> Self {
+        StopwatchCPU {
+            start_cpu: thread_cpu_time(),
+            start: Instant::now(),
+        }
+    }
+
+    /// Returns the CPU time used by the current thread since the stopwatch was created.
+    pub fn elapsed(&self) -> Duration {
+        match (self.start_cpu, thread_cpu_time()) {
+            (Some(start), Some(now)) => now.saturating_sub(start),
+            _ => self.start.elapsed(),
+        }
+    }
+
+    /// Returns the CPU time (in seconds) used by the current thread since the stopwatch was
+    /// created.
+    pub fn elapsed_seconds(&self) -> f64 {
+        self.elapsed().as_secs_f64()
+    }
+}
+
+impl Default for StopwatchCPU {
+    fn default() -> Self {
+        StopwatchCPU::new()
+    }
+}
+
+// Returns the CPU time used by the current thread, or `None` if it is not available.  The first
+// field of the schedstat file is the time spent on the CPU, in nanoseconds.
+fn thread_cpu_time() -> Option<Duration> {
+    let schedstat = std::fs::read_to_string("/proc/thread-self/schedstat").ok()?;
+    let nanos = schedstat.split_whitespace().next()?.parse().ok()?;
+    Some(Duration::from_nanos(nanos))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stopwatch() {
+        let stopwatch = Stopwatch::new();
+        let t1 = stopwatch.elapsed_seconds();
+        assert!(t1 >= 0.0);
+
+        // busy loop
+        let mut sum = 0u64;
+        while stopwatch.elapsed_seconds() < t1 + 0.01 {
+            sum = std::hint::black_box(sum.wrapping_add(1));
+        }
+        let t2 = stopwatch.elapsed_seconds();
+        assert!(t2 >= t1 + 0.01);
+        assert!(stopwatch.elapsed_seconds() >= t2);
+    }
+    #[test]
+    fn test_stopwatch_cpu() {
+        let stopwatch = StopwatchCPU::new();
+        let t1 = stopwatch.elapsed_seconds();
+        assert!(t1 >= 0.0);
+
+        // busy loop, which uses the CPU for at least part of the real time
+        let real = Stopwatch::new();
+        let mut sum = 0u64;
+        while real.elapsed_seconds() < 0.05 {
+            sum = std::hint::black_box(sum.wrapping_add(1));
+        }
+        let t2 = stopwatch.elapsed_seconds();
+        assert!(t2 > t1);
+        assert!(stopwatch.elapsed_seconds() >= t2);
+    }
+}
@@ -0,0 +1,17 @@
+//! Substring search: find the first occurrence of a pattern string in a text string.
+//!
+//! [`KMP`], [`BoyerMoore`], and [`RabinKarp`] preprocess the pattern, so that the same pattern can
+//! be searched for in many texts.  They compare the bytes of the UTF-8 encodings of the strings,
+//! so the index of a match is a byte index, which is always at a char boundary of the text.
+//!
+//! For additional documentation, see <a href="https://algs4.cs.princeton.edu/53substring">Section
+//! 5.3</a> of <i>Algorithms, 4th Edition</i> by Robert Sedgewick and Kevin Wayne.
+
+pub(crate) mod boyer_moore;
+pub(crate) mod kmp;
+pub(crate) mod rabin_karp;
+
+const R: usize = 256; // the radix, for the bytes of UTF-8
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,57 @@
+use crate::string::R;
+
+/// Finds the first occurrence of a pattern string in a text string, using the bad-character rule
+/// part of the Boyer-Moore algorithm.
+///
+/// It scans the pattern from right to left, and on a mismatch skips ahead in the text so that the
+/// mismatched byte of the text lines up with its rightmost occurrence in the pattern.  It does not
+/// implement the strong good suffix rule.
+///
+/// It takes &Theta;(<em>m</em> + <em>R</em>) time and &Theta;(<em>R</em>) space to preprocess the
+/// pattern, where <em>m</em> is the length of the pattern and <em>R</em> = 256 is the alphabet
+/// size of bytes.  The `search` method takes &Theta;(<em>m</em> <em>n</em>) time in the worst case,
+/// where <em>n</em> is the length of the text, but it typically takes about <em>n</em> /
+/// <em>m</em> byte compares.
+///
+/// For additional documentation, see <a href="https://algs4.cs.princeton.edu/53substring">Section
+/// 5.3</a> of <i>Algorithms, 4th Edition</i> by Robert Sedgewick and Kevin Wayne.
+pub struct BoyerMoore {
+    pat: Vec<u8>,              // the pattern
+    right: [Option<usize>; R], // the rightmost occurrence of each byte in the pattern
+}
+
+impl BoyerMoore {
+    /// Preprocesses the pattern string.
+    pub fn new(pattern: &str) -> Self {
+        let pat = pattern.as_bytes().to_vec();
+        let mut right = [None; R];
+        for (j, &c) in pat.iter().enumerate() {
+            right[c as usize] = Some(j);
+        }
+        BoyerMoore { pat, right }
+    }
+
+    /// Returns the index of the first occurrence of the pattern string in the text string, or
+    /// `None` if there is no such occurrence.
+    pub fn search(&self, text: &str) -> Option<usize> {
+        let txt = text.as_bytes();
+        let m = self.pat.len();
+        let n = txt.len();
+        let mut i = 0;
+        while i + m <= n {
+            // the rightmost mismatch, if any
+            let Some(j) = (0..m).rev().find(|&j| self.pat[j] != txt[i + j]) else {
+                return Some(i); // found
+            };
+            // align the mismatched byte with its rightmost occurrence in the pattern, if it is to
+            // the left of j, and otherwise shift by one
+            let skip = match self.right[txt[i + j] as usize] {
+                Some(r) if r < j => j - r,
+                Some(_) => 1,
+                None => j + 1,
+            };
+            i += skip;
+        }
+        None // not found
+    }
+}
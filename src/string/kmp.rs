@@ -0,0 +1,54 @@
+use crate::string::R;
+
+/// Finds the first occurrence of a pattern string in a text string, using the Knuth-Morris-Pratt
+/// algorithm.
+///
+/// This implementation builds the deterministic finite-state automaton (DFA) that recognizes the
+/// pattern.  It takes &Theta;(<em>m</em> <em>R</em>) time and space to build the DFA, where
+/// <em>m</em> is the length of the pattern and <em>R</em> = 256 is the alphabet size of bytes.  The
+/// `search` method takes &Theta;(<em>n</em>) time in the worst case, where <em>n</em> is the
+/// length of the text, and it never backs up in the text.
+///
+/// For additional documentation, see <a href="https://algs4.cs.princeton.edu/53substring">Section
+/// 5.3</a> of <i>Algorithms, 4th Edition</i> by Robert Sedgewick and Kevin Wayne.
+pub struct KMP {
+    m: usize,             // length of the pattern
+    dfa: Vec<Vec<usize>>, // dfa[c][j] is the next state after reading byte c in state j
+}
+
+impl KMP {
+    /// Preprocesses the pattern string.
+    pub fn new(pattern: &str) -> Self {
+        let pat = pattern.as_bytes();
+        let m = pat.len();
+        let mut dfa = vec![vec![0; m]; R];
+        if m > 0 {
+            dfa[pat[0] as usize][0] = 1;
+            let mut x = 0; // the restart state
+            for j in 1..m {
+                for row in dfa.iter_mut() {
+                    row[j] = row[x]; // copy mismatch cases
+                }
+                dfa[pat[j] as usize][j] = j + 1; // set match case
+                x = dfa[pat[j] as usize][x]; // update restart state
+            }
+        }
+        KMP { m, dfa }
+    }
+
+    /// Returns the index of the first occurrence of the pattern string in the text string, or
+    /// `None` if there is no such occurrence.
+    pub fn search(&self, text: &str) -> Option<usize> {
+        if self.m == 0 {
+            return Some(0);
+        }
+        let mut j = 0;
+        for (i, &c) in text.as_bytes().iter().enumerate() {
+            j = self.dfa[c as usize][j];
+            if j == self.m {
+                return Some(i + 1 - self.m); // found
+            }
+        }
+        None // not found
+    }
+}
@@ -0,0 +1,133 @@
+use crate::std_random::StdRandom;
+use crate::string::R;
+
+/// Finds the first occurrence of a pattern string in a text string, using the Rabin-Karp
+/// algorithm.
+///
+/// It compares the hash of the pattern with a rolling hash of each substring of the text of the
+/// same length, modulo a random 31-bit prime <em>Q</em>.  The Monte Carlo version, made by `new`,
+/// reports a match as soon as the hashes are equal, so it may (with probability about
+/// <em>n</em> / <em>Q</em>) return a wrong index, but it takes &Theta;(<em>n</em>) time in the
+/// worst case, where <em>n</em> is the length of the text.  The Las Vegas version, made by
+/// `las_vegas`, checks the bytes when the hashes are equal, so it is always correct, but it may
+/// (with tiny probability) take &Theta;(<em>m</em> <em>n</em>) time, where <em>m</em> is the length
+/// of the pattern.
+///
+/// For additional documentation, see <a href="https://algs4.cs.princeton.edu/53substring">Section
+/// 5.3</a> of <i>Algorithms, 4th Edition</i> by Robert Sedgewick and Kevin Wayne.
+pub struct RabinKarp {
+    pat: Vec<u8>,    // the pattern, needed only for Las Vegas
+    pat_hash: u64,   // pattern hash value
+    q: u64,          // a large prime, small enough to avoid overflow
+    rm: u64,         // R^(m-1) % q
+    las_vegas: bool, // check the bytes when the hashes match?
+}
+
+impl RabinKarp {
+    /// Preprocesses the pattern string, for the Monte Carlo version.
+    pub fn new(pattern: &str) -> Self {
+        RabinKarp::with_prime(pattern, random_prime(), false)
+    }
+
+    /// Preprocesses the pattern string, for the Las Vegas version.
+    pub fn las_vegas(pattern: &str) -> Self {
+        RabinKarp::with_prime(pattern, random_prime(), true)
+    }
+
+    fn with_prime(pattern: &str, q: u64, las_vegas: bool) -> Self {
+        let pat = pattern.as_bytes().to_vec();
+        // precompute R^(m-1) % q for use in removing leading byte
+        let rm = (1..pat.len()).fold(1, |rm, _| (R as u64 * rm) % q);
+        RabinKarp {
+            pat_hash: hash(&pat, q),
+            pat,
+            q,
+            rm,
+            las_vegas,
+        }
+    }
+
+    // Las Vegas version: does pat[] match txt[i..i+m]?
+    // Monte Carlo version: always return true
+    fn check(&self, txt: &[u8], i: usize) -> bool {
+        !self.las_vegas || self.pat[..] == txt[i..i + self.pat.len()]
+    }
+
+    /// Returns the index of the first occurrence of the pattern string in the text string, or
+    /// `None` if there is no such occurrence.
+    pub fn search(&self, text: &str) -> Option<usize> {
+        let txt = text.as_bytes();
+        let m = self.pat.len();
+        let n = txt.len();
+        if n < m {
+            return None;
+        }
+        let mut txt_hash = hash(&txt[..m], self.q);
+
+        // check for match at offset 0
+        if self.pat_hash == txt_hash && self.check(txt, 0) {
+            return Some(0);
+        }
+
+        // check for hash match; if hash match, check for exact match
+        for i in m..n {
+            // remove leading byte, add trailing byte, check for match
+            let leading = self.rm * txt[i - m] as u64 % self.q;
+            txt_hash = (txt_hash + self.q - leading) % self.q;
+            txt_hash = (txt_hash * R as u64 + txt[i] as u64) % self.q;
+
+            // match
+            let offset = i + 1 - m;
+            if self.pat_hash == txt_hash && self.check(txt, offset) {
+                return Some(offset);
+            }
+        }
+        None // no match
+    }
+}
+
+// Compute hash for key[0..m).
+fn hash(key: &[u8], q: u64) -> u64 {
+    key.iter().fold(0, |h, &c| (R as u64 * h + c as u64) % q)
+}
+
+// a random 31-bit prime; the gaps between 31-bit primes are far smaller than 2^16
+fn random_prime() -> u64 {
+    let mut q = (1 << 30) + StdRandom::new().uniform_int((1 << 30) - (1 << 16)) as u64;
+    while !is_prime(q) {
+        q += 1;
+    }
+    q
+}
+
+// trial division, which is fast enough for 31-bit numbers
+fn is_prime(q: u64) -> bool {
+    q >= 2
+        && (2..)
+            .take_while(|d| d * d <= q)
+            .all(|d| !q.is_multiple_of(d))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rabin_karp_monte_carlo_and_las_vegas() {
+        // with q = 2, every hash is the parity of the last byte, so hash matches are common
+        let monte_carlo = RabinKarp::with_prime("ab", 2, false);
+        let las_vegas = RabinKarp::with_prime("ab", 2, true);
+        assert_eq!(monte_carlo.search("cdab"), Some(0)); // a false match
+        assert_eq!(las_vegas.search("cdab"), Some(2));
+        assert_eq!(las_vegas.search("cdcd"), None);
+    }
+
+    #[test]
+    fn test_random_prime() {
+        let q = random_prime();
+        assert!((1 << 30..1 << 31).contains(&q));
+        assert!(is_prime(q));
+        assert!(is_prime(2_147_483_647));
+        assert!(!is_prime(1) && !is_prime(2_147_483_649));
+    }
+}
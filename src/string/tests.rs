@@ -0,0 +1,78 @@
+use super::boyer_moore::BoyerMoore;
+use super::kmp::KMP;
+use super::rabin_karp::RabinKarp;
+
+// the index of the first occurrence of pat in txt, by brute force
+fn brute_force(pat: &str, txt: &str) -> Option<usize> {
+    let (pat, txt) = (pat.as_bytes(), txt.as_bytes());
+    (0..=txt.len().checked_sub(pat.len())?).find(|&i| txt[i..i + pat.len()] == *pat)
+}
+
+fn check(pat: &str, txt: &str) {
+    let expected = brute_force(pat, txt);
+    assert_eq!(
+        KMP::new(pat).search(txt),
+        expected,
+        "KMP: {pat:?} in {txt:?}"
+    );
+    assert_eq!(
+        BoyerMoore::new(pat).search(txt),
+        expected,
+        "BoyerMoore: {pat:?} in {txt:?}"
+    );
+    assert_eq!(
+        RabinKarp::new(pat).search(txt),
+        expected,
+        "RabinKarp: {pat:?} in {txt:?}"
+    );
+    assert_eq!(
+        RabinKarp::las_vegas(pat).search(txt),
+        expected,
+        "RabinKarp: {pat:?} in {txt:?}"
+    );
+}
+
+#[test]
+fn test_substring_search() {
+    let txt = "abacadabrabracabracadabrabrabracad";
+    assert_eq!(KMP::new("abracadabra").search(txt), Some(14));
+    assert_eq!(BoyerMoore::new("abracadabra").search(txt), Some(14));
+    assert_eq!(RabinKarp::new("abracadabra").search(txt), Some(14));
+    assert_eq!(RabinKarp::las_vegas("rab").search(txt), Some(8));
+
+    let pairs = [
+        ("abracadabra", txt),
+        ("rab", txt),
+        ("bcara", txt),       // no match
+        ("rabrabracad", txt), // match at the end
+        ("abacad", txt),      // match at the start
+        (txt, txt),           // the full text
+        ("needle", "haystack with a needle in it"),
+        ("needles", "haystack with a needle"),
+        ("aaab", "aaaaaaaaaaaaaaaab"),
+        ("aaaa", "aaa"), // pattern longer than text
+        ("a", "a"),
+        ("a", ""),
+        ("", "abc"),
+        ("", ""),
+        ("café", "un café au lait"), // multi-byte chars
+        ("é", "cafe"),
+    ];
+    for (pat, txt) in pairs {
+        check(pat, txt);
+    }
+}
+
+#[test]
+fn test_substring_search_random() {
+    for _ in 0..200 {
+        // a small alphabet makes partial matches common
+        let txt: String = (0..rand::random_range(0..50))
+            .map(|_| ['a', 'b', 'c'][rand::random_range(0..3)])
+            .collect();
+        let pat: String = (0..rand::random_range(1..5))
+            .map(|_| ['a', 'b', 'c'][rand::random_range(0..3)])
+            .collect();
+        check(&pat, &txt);
+    }
+}
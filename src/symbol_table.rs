@@ -0,0 +1,186 @@
+use crate::binary_search_st::BinarySearchST;
+use crate::bst::BST;
+use crate::hash_st::SeparateChainingHashST;
+use std::hash::Hash;
+
+/// The symbol table API, so that clients can be generic over the implementation.
+///
+/// A symbol table implements the *associative array* abstraction: when associating a value with a
+/// key that is already in the symbol table, the convention is to replace the old value with the new
+/// value.
+///
+/// It is implemented by [`BST`] and [`BinarySearchST`], which are ordered symbol tables, so their
+/// `keys` are in ascending order.  Their ordered operations, such as `min`, `floor`, and `rank`,
+/// are not part of this trait.  It is also implemented by [`SeparateChainingHashST`], whose `keys`
+/// are in no particular order.
+///
+/// For additional documentation, see <a href="https://algs4.cs.princeton.edu/31elementary">Section
+/// 3.1</a> of <i>Algorithms, 4th Edition</i> by Robert Sedgewick and Kevin Wayne.
+pub trait SymbolTable<K, V> {
+    /// Inserts the specified key-value pair into the symbol table, overwriting the old value with
+    /// the new value if the symbol table already contains the specified key.
+    fn put(&mut self, key: K, val: V);
+
+    /// Returns the value associated with the given key, or `None` if the key is not in the symbol
+    /// table.
+    fn get(&self, key: &K) -> Option<&V>;
+
+    /// Removes the specified key and its associated value from this symbol table (if the key is in
+    /// this symbol table).
+    fn delete(&mut self, key: &K);
+
+    /// Returns the number of key-value pairs in this symbol table.
+    fn size(&self) -> usize;
+
+    /// Returns an iterator over the keys in the symbol table.
+    fn keys<'a>(&'a self) -> impl Iterator<Item = &'a K>
+    where
+        K: 'a;
+
+    /// Does this symbol table contain the given key?
+    fn contains(&self, key: &K) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Returns true if this symbol table is empty, returns false otherwise.
+    fn is_empty(&self) -> bool {
+        self.size() == 0
+    }
+}
+
+impl<K: Ord, V> SymbolTable<K, V> for BST<K, V> {
+    fn put(&mut self, key: K, val: V) {
+        BST::put(self, key, val);
+    }
+
+    fn get(&self, key: &K) -> Option<&V> {
+        BST::get(self, key)
+    }
+
+    fn delete(&mut self, key: &K) {
+        BST::delete(self, key);
+    }
+
+    fn size(&self) -> usize {
+        BST::size(self)
+    }
+
+    fn keys<'a>(&'a self) -> impl Iterator<Item = &'a K>
+    where
+        K: 'a,
+    {
+        BST::keys(self)
+    }
+}
+
+impl<K: Ord, V> SymbolTable<K, V> for BinarySearchST<K, V> {
+    fn put(&mut self, key: K, val: V) {
+        BinarySearchST::put(self, key, val);
+    }
+
+    fn get(&self, key: &K) -> Option<&V> {
+        BinarySearchST::get(self, key)
+    }
+
+    fn delete(&mut self, key: &K) {
+        BinarySearchST::delete(self, key);
+    }
+
+    fn size(&self) -> usize {
+        BinarySearchST::size(self)
+    }
+
+    fn keys<'a>(&'a self) -> impl Iterator<Item = &'a K>
+    where
+        K: 'a,
+    {
+        BinarySearchST::keys(self)
+    }
+}
+
+impl<K: Hash + Eq, V> SymbolTable<K, V> for SeparateChainingHashST<K, V> {
+    fn put(&mut self, key: K, val: V) {
+        SeparateChainingHashST::put(self, key, val);
+    }
+
+    fn get(&self, key: &K) -> Option<&V> {
+        SeparateChainingHashST::get(self, key)
+    }
+
+    fn delete(&mut self, key: &K) {
+        SeparateChainingHashST::delete(self, key);
+    }
+
+    fn size(&self) -> usize {
+        SeparateChainingHashST::size(self)
+    }
+
+    fn keys<'a>(&'a self) -> impl Iterator<Item = &'a K>
+    where
+        K: 'a,
+    {
+        SeparateChainingHashST::keys(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Counts the words, the same way as FrequencyCounter of algs4.
+    fn frequency_counter(st: &mut impl SymbolTable<String, usize>, words: &str) {
+        for word in words.split_whitespace() {
+            let count = st.get(&word.to_string()).map_or(1, |c| c + 1);
+            st.put(word.to_string(), count);
+        }
+    }
+
+    fn check_symbol_table(mut st: impl SymbolTable<String, usize>) {
+        assert!(st.is_empty());
+        assert_eq!(st.keys().count(), 0);
+        frequency_counter(
+            &mut st,
+            "it was the best of times it was the worst of times",
+        );
+        assert_eq!(st.size(), 7);
+        assert!(!st.is_empty());
+        assert_eq!(st.get(&"it".to_string()), Some(&2));
+        assert_eq!(st.get(&"best".to_string()), Some(&1));
+        assert_eq!(st.get(&"age".to_string()), None);
+        // the ordered symbol tables iterate over the keys in ascending order
+        assert!(
+            st.keys()
+                .eq(&["best", "it", "of", "the", "times", "was", "worst"].map(String::from))
+        );
+
+        st.delete(&"the".to_string());
+        st.delete(&"age".to_string());
+        assert_eq!(st.size(), 6);
+        assert!(!st.contains(&"the".to_string()));
+        assert!(st.contains(&"was".to_string()));
+    }
+
+    #[test]
+    fn test_symbol_table_bst() {
+        check_symbol_table(BST::new());
+    }
+
+    #[test]
+    fn test_symbol_table_binary_search_st() {
+        check_symbol_table(BinarySearchST::new());
+    }
+
+    #[test]
+    fn test_symbol_table_separate_chaining_hash_st() {
+        let mut st = SeparateChainingHashST::new();
+        frequency_counter(
+            &mut st,
+            "it was the best of times it was the worst of times",
+        );
+        assert_eq!(SymbolTable::size(&st), 7);
+        assert_eq!(SymbolTable::get(&st, &"was".to_string()), Some(&2));
+        let mut keys: Vec<_> = SymbolTable::keys(&st).collect();
+        keys.sort_unstable();
+        assert_eq!(keys, ["best", "it", "of", "the", "times", "was", "worst"]);
+    }
+}
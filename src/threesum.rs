@@ -1,30 +1,42 @@
 //! Take n integers and counts the number of triples that sum to exactly 0.
 //!
-//! ### Limitations
-//! - We ignore integer overflow
+//! ### Overflow
+//! - The sums are computed in `i64`, so they never overflow, even for the extreme `i32` values.
+//! - The count is a `usize`, so it does not overflow when there are more than `i32::MAX` triples.
 
 /// O(n^3)
 pub fn print_all(a: &[i32]) {
+    for (x, y, z) in triples(a) {
+        println!("{} {} {}", x, y, z);
+    }
+}
+
+/// Returns the triples that sum to exactly 0, in the order they are found.
+///
+/// O(n^3)
+pub fn triples(a: &[i32]) -> Vec<(i32, i32, i32)> {
     let n = a.len();
+    let mut triples = Vec::new();
     for i in 0..n {
         for j in i + 1..n {
             for k in j + 1..n {
-                if a[i] + a[j] + a[k] == 0 {
-                    println!("{} {} {}", a[i], a[j], a[k]);
+                if a[i] as i64 + a[j] as i64 + a[k] as i64 == 0 {
+                    triples.push((a[i], a[j], a[k]));
                 }
             }
         }
     }
+    triples
 }
 
 /// O(n^3)
-pub fn count(a: &[i32]) -> i32 {
+pub fn count(a: &[i32]) -> usize {
     let n = a.len();
     let mut count = 0;
     for i in 0..n {
         for j in i + 1..n {
             for k in j + 1..n {
-                if a[i] + a[j] + a[k] == 0 {
+                if a[i] as i64 + a[j] as i64 + a[k] as i64 == 0 {
                     count += 1;
                 }
             }
@@ -32,3 +44,29 @@ pub fn count(a: &[i32]) -> i32 {
     }
     count
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_triples() {
+        let a = [30, -40, -20, -10, 40, 0, 10, 5];
+        assert_eq!(
+            triples(&a),
+            [(30, -40, 10), (30, -20, -10), (-40, 40, 0), (-10, 0, 10)]
+        );
+        assert_eq!(count(&a), 4);
+        assert!(triples(&[1, 2, 3, -1]).is_empty());
+        assert!(triples(&[0, 0]).is_empty());
+    }
+
+    #[test]
+    fn test_overflow() {
+        // i32::MAX + i32::MAX + 2 wraps around to 0
+        assert!(triples(&[i32::MAX, i32::MAX, 2]).is_empty());
+        // a genuine zero sum, whose partial sum i32::MAX + 1 is out of range
+        assert_eq!(triples(&[i32::MAX, 1, i32::MIN]), [(i32::MAX, 1, i32::MIN)]);
+        assert_eq!(count(&[i32::MIN, i32::MIN, i32::MIN, i32::MAX]), 0);
+    }
+}
@@ -1,59 +1,111 @@
 //! Take n integers and counts the number of triples that sum to exactly 0.
 //!
-//! ### Limitations
-//! - We ignore integer overflow
-
-use crate::error::InvalidArgument;
+//! ### Duplicates
+//! - The triples are counted by their positions in the slice, as in [`crate::threesum`], so a
+//!   value repeated m times is in m different triples with the matching values.  For example,
+//!   `[-1, -1, 2]` has one triple, `[-1, -1, 2, 2]` has two triples, and `[0, 0, 0, 0]` has four
+//!   triples.
+//!
+//! ### Overflow
+//! - The sums are computed in `i64`, so they never overflow, even for the extreme `i32` values.
+//! - The count is a `usize`, so it does not overflow when there are more than `i32::MAX` triples.
 
 /// O(n^2 log n)
-pub fn print_all(a: &mut [i32]) -> Result<(), InvalidArgument> {
-    let n = a.len();
-    a.sort_unstable();
-    if contains_duplicates(a) {
-        return Err(InvalidArgument(
-            "slice contains duplicate integers".to_string(),
-        ));
+pub fn print_all(a: &mut [i32]) {
+    for (x, y, z) in triples(a) {
+        println!("{} {} {}", x, y, z);
     }
+}
+
+/// Returns the triples that sum to exactly 0, each in ascending order, and sorted.
+///
+/// O(n^2 log n + m log m), where m is the number of triples
+pub fn triples(a: &mut [i32]) -> Vec<(i32, i32, i32)> {
+    a.sort_unstable();
+    let n = a.len();
+    let mut triples = Vec::new();
     for i in 0..n {
         for j in i + 1..n {
-            if let Ok(k) = a.binary_search(&-(a[i] + a[j]))
-                && k > j
-            {
-                println!("{} {} {}", a[i], a[j], a[k]);
-            }
+            let key = -(a[i] as i64 + a[j] as i64);
+            let m = count_matches(&a[j + 1..], key);
+            // a matched key is in the range of i32
+            triples.extend(std::iter::repeat_n((a[i], a[j], key as i32), m));
         }
     }
-    Ok(())
+    // equal values at different positions may interleave the triples
+    triples.sort_unstable();
+    triples
 }
 
 /// O(n^2 log n)
-pub fn count(a: &mut [i32]) -> Result<i32, InvalidArgument> {
-    let n = a.len();
+pub fn count(a: &mut [i32]) -> usize {
     a.sort_unstable();
-    if contains_duplicates(a) {
-        return Err(InvalidArgument(
-            "slice contains duplicate integers".to_string(),
-        ));
-    }
+    let n = a.len();
     let mut count = 0;
     for i in 0..n {
         for j in i + 1..n {
-            if let Ok(k) = a.binary_search(&-(a[i] + a[j]))
-                && k > j
-            {
-                count += 1;
-            }
+            count += count_matches(&a[j + 1..], -(a[i] as i64 + a[j] as i64));
         }
     }
-    Ok(count)
+    count
 }
 
+// Returns the number of occurrences of `key` in `a`.
+//
 // pre: `a` is sorted
-fn contains_duplicates(a: &[i32]) -> bool {
-    for i in 1..a.len() {
-        if a[i] == a[i - 1] {
-            return true;
+fn count_matches(a: &[i32], key: i64) -> usize {
+    let lo = a.partition_point(|&x| (x as i64) < key);
+    let hi = a.partition_point(|&x| (x as i64) <= key);
+    hi - lo
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_triples() {
+        let mut a = [30, -40, -20, -10, 40, 0, 10, 5];
+        assert_eq!(
+            triples(&mut a),
+            [(-40, 0, 40), (-40, 10, 30), (-20, -10, 30), (-10, 0, 10)]
+        );
+        assert_eq!(count(&mut a), 4);
+        assert!(triples(&mut [1, 2, 3, -1]).is_empty());
+        assert!(triples(&mut []).is_empty());
+    }
+
+    #[test]
+    fn test_overflow() {
+        // i32::MAX + i32::MAX + 2 wraps around to 0
+        assert!(triples(&mut [i32::MAX, i32::MAX, 2]).is_empty());
+        // a genuine zero sum, whose partial sum i32::MAX + 1 is out of range
+        assert_eq!(
+            triples(&mut [i32::MAX, 1, i32::MIN]),
+            [(i32::MIN, 1, i32::MAX)]
+        );
+        assert_eq!(count(&mut [i32::MIN, i32::MIN, i32::MIN, i32::MAX]), 0);
+    }
+
+    #[test]
+    fn test_duplicates() {
+        assert_eq!(triples(&mut [-1, -1, 2]), [(-1, -1, 2)]);
+        assert_eq!(triples(&mut [2, -1, 2, -1]), [(-1, -1, 2), (-1, -1, 2)]);
+        assert_eq!(count(&mut [0, 0, 0, 0]), 4);
+
+        for _ in 0..20 {
+            let a: Vec<i32> = (0..60).map(|_| rand::random_range(-10..10)).collect();
+            let mut expected: Vec<(i32, i32, i32)> = crate::threesum::triples(&a)
+                .into_iter()
+                .map(|(x, y, z)| {
+                    let mut t = [x, y, z];
+                    t.sort();
+                    (t[0], t[1], t[2])
+                })
+                .collect();
+            expected.sort();
+            assert_eq!(count(&mut a.clone()), crate::threesum::count(&a));
+            assert_eq!(triples(&mut a.clone()), expected);
         }
     }
-    false
 }
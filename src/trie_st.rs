@@ -0,0 +1,248 @@
+const R: usize = 256; // extended ASCII, or the bytes of UTF-8
+
+type Link<V> = Option<Box<Node<V>>>;
+
+struct Node<V> {
+    val: Option<V>,
+    next: Vec<Link<V>>, // empty until the first child is added, then of length R
+}
+
+impl<V> Node<V> {
+    fn new() -> Self {
+        Node {
+            val: None,
+            next: Vec::new(),
+        }
+    }
+
+    fn child(&self, c: u8) -> Option<&Node<V>> {
+        self.next.get(c as usize)?.as_deref()
+    }
+
+    fn has_children(&self) -> bool {
+        self.next.iter().any(Option::is_some)
+    }
+}
+
+/// A symbol table of key-value pairs, with string keys and generic values.
+///
+/// It supports the usual `put`, `get`, `contains`, `delete`, `size`, and `is_empty` methods.  It
+/// also provides character-based methods for finding the string in the symbol table that is the
+/// <em>longest prefix</em> of a given prefix, and finding all strings in the symbol table that
+/// <em>start with</em> a given prefix.
+///
+/// This implementation uses a 256-way trie over the bytes of the keys, so any UTF-8 string is a
+/// valid key.  The `put`, `contains`, `delete`, and `longest_prefix_of` operations take time
+/// proportional to the length of the key (in the worst case).  Construction takes constant time.
+/// The `size` and `is_empty` operations take constant time.
+///
+/// A node allocates its 256 links only when its first child is added, so the leaves, which are
+/// at least half of the nodes, take constant space.
+///
+/// For additional documentation, see <a href="https://algs4.cs.princeton.edu/52trie">Section
+/// 5.2</a> of <i>Algorithms, 4th Edition</i> by Robert Sedgewick and Kevin Wayne.
+pub struct TrieST<V> {
+    root: Link<V>, // root of trie
+    n: usize,      // number of keys in trie
+}
+
+impl<V> TrieST<V> {
+    /// Initializes an empty string symbol table.
+    pub fn new() -> Self {
+        TrieST { root: None, n: 0 }
+    }
+
+    /// Returns the value associated with the given key, or `None` if the key is not in the symbol
+    /// table.
+    pub fn get(&self, key: &str) -> Option<&V> {
+        let mut x = self.root.as_deref()?;
+        for &c in key.as_bytes() {
+            x = x.child(c)?;
+        }
+        x.val.as_ref()
+    }
+
+    /// Does this symbol table contain the given key?
+    pub fn contains(&self, key: &str) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Inserts the key-value pair into the symbol table, overwriting the old value with the new
+    /// value if the key is already in the symbol table.
+    pub fn put(&mut self, key: &str, val: V) {
+        let mut x = self.root.get_or_insert_with(|| Box::new(Node::new()));
+        for &c in key.as_bytes() {
+            if x.next.is_empty() {
+                x.next.resize_with(R, || None);
+            }
+            x = x.next[c as usize].get_or_insert_with(|| Box::new(Node::new()));
+        }
+        if x.val.replace(val).is_none() {
+            self.n += 1;
+        }
+    }
+
+    /// Returns the number of key-value pairs in this symbol table.
+    pub fn size(&self) -> usize {
+        self.n
+    }
+
+    /// Is this symbol table empty?
+    pub fn is_empty(&self) -> bool {
+        self.size() == 0
+    }
+
+    /// Removes the key from the symbol table if the key is present, and returns its value.
+    pub fn delete(&mut self, key: &str) -> Option<V> {
+        let val = delete(&mut self.root, key.as_bytes())?;
+        self.n -= 1;
+        Some(val)
+    }
+
+    /// Returns all keys in the symbol table, in ascending order of their bytes.
+    pub fn keys(&self) -> Vec<String> {
+        self.keys_with_prefix("")
+    }
+
+    /// Returns all of the keys in the symbol table that start with `prefix`, in ascending order
+    /// of their bytes.
+    pub fn keys_with_prefix(&self, prefix: &str) -> Vec<String> {
+        let mut results = Vec::new();
+        let mut x = self.root.as_deref();
+        for &c in prefix.as_bytes() {
+            x = x.and_then(|node| node.child(c));
+        }
+        if let Some(x) = x {
+            collect(x, &mut prefix.as_bytes().to_vec(), &mut results);
+        }
+        results
+    }
+
+    /// Returns the string in the symbol table that is the longest prefix of `query`, or `None` if
+    /// no such string.
+    pub fn longest_prefix_of<'a>(&self, query: &'a str) -> Option<&'a str> {
+        let mut x = self.root.as_deref();
+        let mut length = None;
+        for (d, &c) in query.as_bytes().iter().enumerate() {
+            let Some(node) = x else { break };
+            if node.val.is_some() {
+                length = Some(d);
+            }
+            x = node.child(c);
+        }
+        if x.is_some_and(|node| node.val.is_some()) {
+            length = Some(query.len());
+        }
+        // a key is valid UTF-8, so it ends at a char boundary of `query`
+        length.map(|length| &query[..length])
+    }
+}
+
+impl<V> Default for TrieST<V> {
+    fn default() -> Self {
+        TrieST::new()
+    }
+}
+
+// Deletes `key` from the subtrie rooted at `x`, and removes the nodes that are left without a
+// value and without children.
+fn delete<V>(x: &mut Link<V>, key: &[u8]) -> Option<V> {
+    let node = x.as_mut()?;
+    let val = match key.split_first() {
+        None => node.val.take(),
+        Some((&c, rest)) => delete(node.next.get_mut(c as usize)?, rest),
+    };
+    if val.is_some() && node.val.is_none() && !node.has_children() {
+        *x = None;
+    }
+    val
+}
+
+fn collect<V>(x: &Node<V>, prefix: &mut Vec<u8>, results: &mut Vec<String>) {
+    if x.val.is_some() {
+        let key = String::from_utf8(prefix.clone()).expect("a key should be valid UTF-8");
+        results.push(key);
+    }
+    for (c, child) in x.next.iter().enumerate() {
+        if let Some(child) = child {
+            prefix.push(c as u8);
+            collect(child, prefix, results);
+            prefix.pop();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shells() -> TrieST<usize> {
+        let mut st = TrieST::new();
+        for (i, key) in "she sells sea shells by the sea shore"
+            .split_whitespace()
+            .enumerate()
+        {
+            st.put(key, i);
+        }
+        st
+    }
+
+    #[test]
+    fn test_trie_st() {
+        let mut st = shells();
+        assert_eq!(st.size(), 7);
+        assert_eq!(
+            st.keys(),
+            ["by", "sea", "sells", "she", "shells", "shore", "the"]
+        );
+        assert_eq!(st.get("sea"), Some(&6));
+        assert_eq!(st.get("shell"), None);
+        assert_eq!(st.get("shellsort"), None);
+        assert!(st.contains("by"));
+        assert!(!st.contains(""));
+
+        assert_eq!(st.keys_with_prefix("se"), ["sea", "sells"]);
+        assert_eq!(st.keys_with_prefix("shor"), ["shore"]);
+        assert!(st.keys_with_prefix("x").is_empty());
+        assert_eq!(st.longest_prefix_of("shellsort"), Some("shells"));
+        assert_eq!(st.longest_prefix_of("shell"), Some("she"));
+        assert_eq!(st.longest_prefix_of("she"), Some("she"));
+        assert_eq!(st.longest_prefix_of("quicksort"), None);
+
+        st.put("", 100);
+        assert_eq!(st.longest_prefix_of("quicksort"), Some(""));
+        assert_eq!(st.size(), 8);
+    }
+
+    #[test]
+    fn test_trie_st_delete() {
+        let mut st = shells();
+        assert_eq!(st.delete("shells"), Some(3));
+        assert_eq!(st.delete("shells"), None);
+        assert_eq!(st.delete("shell"), None);
+        assert_eq!(st.delete("shellsort"), None);
+        assert_eq!(st.size(), 6);
+        assert_eq!(st.longest_prefix_of("shellsort"), Some("she"));
+        assert_eq!(st.keys_with_prefix("sh"), ["she", "shore"]);
+
+        for key in st.keys() {
+            assert!(st.delete(&key).is_some());
+        }
+        assert!(st.is_empty());
+        // the nodes are all removed
+        assert!(st.root.is_none());
+        assert!(st.keys().is_empty());
+    }
+
+    #[test]
+    fn test_trie_st_utf8() {
+        let mut st = TrieST::new();
+        st.put("café", 1);
+        st.put("cafés", 2);
+        st.put("caf", 3);
+        assert_eq!(st.get("café"), Some(&1));
+        assert_eq!(st.keys_with_prefix("café"), ["café", "cafés"]);
+        assert_eq!(st.longest_prefix_of("cafétéria"), Some("café"));
+        assert_eq!(st.longest_prefix_of("cafe"), Some("caf"));
+    }
+}
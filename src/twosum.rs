@@ -1,30 +1,67 @@
 //! Take n integers and counts the number of pairs that sum to exactly 0.
 //!
-//! ### Limitations
-//! - We ignore integer overflow
+//! ### Overflow
+//! - The sums are computed in `i64`, so they never overflow, even for the extreme `i32` values.
+//! - The count is a `usize`, so it does not overflow when there are more than `i32::MAX` pairs.
 
 /// O(n^2)
 pub fn print_all(a: &[i32]) {
+    for (x, y) in pairs(a) {
+        println!("{} {}", x, y);
+    }
+}
+
+/// Returns the pairs that sum to exactly 0, in the order they are found.
+///
+/// O(n^2)
+pub fn pairs(a: &[i32]) -> Vec<(i32, i32)> {
     let n = a.len();
+    let mut pairs = Vec::new();
     for i in 0..n {
         for j in i + 1..n {
-            if a[i] + a[j] == 0 {
-                println!("{} {}", a[i], a[j]);
+            if a[i] as i64 + a[j] as i64 == 0 {
+                pairs.push((a[i], a[j]));
             }
         }
     }
+    pairs
 }
 
 /// O(n^2)
-pub fn count(a: &[i32]) -> i32 {
+pub fn count(a: &[i32]) -> usize {
     let n = a.len();
     let mut count = 0;
     for i in 0..n {
         for j in i + 1..n {
-            if a[i] + a[j] == 0 {
+            if a[i] as i64 + a[j] as i64 == 0 {
                 count += 1;
             }
         }
     }
     count
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pairs() {
+        let a = [30, -40, -20, -10, 40, 0, 10, 5];
+        assert_eq!(pairs(&a), [(-40, 40), (-10, 10)]);
+        assert_eq!(count(&a), 2);
+        assert_eq!(pairs(&[0, 0, 1]), [(0, 0)]);
+        assert!(pairs(&[1, 2, 3]).is_empty());
+        assert!(pairs(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_overflow() {
+        // i32::MIN + i32::MIN wraps around to 0
+        assert!(pairs(&[i32::MIN, i32::MIN]).is_empty());
+        assert_eq!(
+            pairs(&[i32::MAX, i32::MIN, -i32::MAX]),
+            [(i32::MAX, -i32::MAX)]
+        );
+    }
+}
@@ -1,55 +1,95 @@
 //! Take n integers and counts the number of pairs that sum to exactly 0.
 //!
-//! ### Limitations
-//! - We ignore integer overflow
-
-use crate::error::InvalidArgument;
+//! ### Duplicates
+//! - The pairs are counted by their positions in the slice, as in [`crate::twosum`], so a value
+//!   repeated m times is in m different pairs with a matching value.  For example, `[-1, 1, 1]`
+//!   has two pairs, and `[0, 0, 0]` has three pairs.
+//!
+//! ### Overflow
+//! - The sums are computed in `i64`, so they never overflow, even for the extreme `i32` values.
+//! - The count is a `usize`, so it does not overflow when there are more than `i32::MAX` pairs.
 
 /// O(n log n)
-pub fn print_all(a: &mut [i32]) -> Result<(), InvalidArgument> {
-    let n = a.len();
-    a.sort_unstable();
-    if contains_duplicates(a) {
-        return Err(InvalidArgument(
-            "slice contains duplicate integers".to_string(),
-        ));
+pub fn print_all(a: &mut [i32]) {
+    for (x, y) in pairs(a) {
+        println!("{} {}", x, y);
     }
-    for i in 0..n {
-        if let Ok(j) = a.binary_search(&-a[i])
-            && j > i
-        {
-            println!("{} {}", a[i], a[j]);
-        }
+}
+
+/// Returns the pairs that sum to exactly 0, each in ascending order, and sorted.
+///
+/// O(n log n + m), where m is the number of pairs
+pub fn pairs(a: &mut [i32]) -> Vec<(i32, i32)> {
+    a.sort_unstable();
+    let mut pairs = Vec::new();
+    for i in 0..a.len() {
+        let key = -(a[i] as i64);
+        let m = count_matches(&a[i + 1..], key);
+        // a matched key is in the range of i32
+        pairs.extend(std::iter::repeat_n((a[i], key as i32), m));
     }
-    Ok(())
+    pairs
 }
 
 /// O(n log n)
-pub fn count(a: &mut [i32]) -> Result<i32, InvalidArgument> {
-    let n = a.len();
+pub fn count(a: &mut [i32]) -> usize {
     a.sort_unstable();
-    if contains_duplicates(a) {
-        return Err(InvalidArgument(
-            "slice contains duplicate integers".to_string(),
-        ));
-    }
     let mut count = 0;
-    for i in 0..n {
-        if let Ok(j) = a.binary_search(&-a[i])
-            && j > i
-        {
-            count += 1;
-        }
+    for i in 0..a.len() {
+        count += count_matches(&a[i + 1..], -(a[i] as i64));
     }
-    Ok(count)
+    count
 }
 
+// Returns the number of occurrences of `key` in `a`.
+//
 // pre: `a` is sorted
-fn contains_duplicates(a: &[i32]) -> bool {
-    for i in 1..a.len() {
-        if a[i] == a[i - 1] {
-            return true;
+fn count_matches(a: &[i32], key: i64) -> usize {
+    let lo = a.partition_point(|&x| (x as i64) < key);
+    let hi = a.partition_point(|&x| (x as i64) <= key);
+    hi - lo
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pairs() {
+        let mut a = [30, -40, -20, -10, 40, 0, 10, 5];
+        assert_eq!(pairs(&mut a), [(-40, 40), (-10, 10)]);
+        assert_eq!(count(&mut a), 2);
+        assert!(pairs(&mut [1, 2, 3]).is_empty());
+        assert!(pairs(&mut []).is_empty());
+    }
+
+    #[test]
+    fn test_overflow() {
+        // i32::MIN + i32::MIN wraps around to 0, and -i32::MIN overflows
+        assert!(pairs(&mut [i32::MIN, i32::MIN]).is_empty());
+        assert_eq!(
+            pairs(&mut [i32::MAX, i32::MIN, -i32::MAX]),
+            [(-i32::MAX, i32::MAX)]
+        );
+        assert_eq!(count(&mut [i32::MIN, 0, i32::MAX]), 0);
+    }
+
+    #[test]
+    fn test_duplicates() {
+        assert_eq!(pairs(&mut [1, -1, 1]), [(-1, 1), (-1, 1)]);
+        assert_eq!(pairs(&mut [0, 0, 0]), [(0, 0), (0, 0), (0, 0)]);
+
+        for _ in 0..20 {
+            let a: Vec<i32> = (0..200).map(|_| rand::random_range(-10..10)).collect();
+            let mut expected = crate::twosum::pairs(&a);
+            for (x, y) in expected.iter_mut() {
+                if x > y {
+                    std::mem::swap(x, y);
+                }
+            }
+            expected.sort();
+            assert_eq!(count(&mut a.clone()), crate::twosum::count(&a));
+            assert_eq!(pairs(&mut a.clone()), expected);
         }
     }
-    false
 }
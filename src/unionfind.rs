@@ -0,0 +1,193 @@
+//! Union-find data type, also known as the disjoint-sets data type.
+
+use crate::error::InvalidArgument;
+
+/// A union-find data type (also known as the <em>disjoint-sets data type</em>).
+///
+/// It supports the classic <em>union</em> and <em>find</em> operations, along with a <em>count</em>
+/// operation that returns the total number of sets.
+///
+/// The union-find data type models a collection of sets containing <em>n</em> elements, with each
+/// element in exactly one set.  The elements are named `0` through `n - 1`.  Initially, there are
+/// <em>n</em> sets, with each element in its own set.  The <em>canonical element</em> of a set
+/// (also known as the <em>root</em>, <em>identifier</em>, <em>leader</em>, or <em>set
+/// representative</em>) is one distinguished element in the set.
+///
+/// - `find(p)` returns the canonical element of the set containing `p`.  The `find` operation
+///   returns the same value for two elements if and only if they are in the same set.
+/// - `union(p, q)` merges the set containing element `p` with the set containing element `q`.
+///   That is, if `p` and `q` are in different sets, replace these two sets with a new set that is
+///   the union of the two.
+/// - `count()` returns the number of sets.
+///
+/// The canonical element of a set can change only when the set itself changes during a call to
+/// `union`; it cannot change during a call to either `find` or `count`.
+///
+/// This implementation uses <em>weighted quick union by size</em> with full <em>path
+/// compression</em>.  The constructor takes &Theta;(<em>n</em>) time, where <em>n</em> is the
+/// number of elements.  The `union` and `find` operations take &Theta;(log <em>n</em>) time in the
+/// worst case, and a sequence of <em>m</em> operations takes
+/// <em>O</em>(<em>m</em> &alpha;(<em>n</em>)) time, where &alpha; is the inverse Ackermann
+/// function, which is less than 5 for any practical <em>n</em>.  The `count` operation takes
+/// &Theta;(1) time.
+///
+/// Path compression changes the parent links in `find`, so `find` and `connected` take `&mut
+/// self`.
+///
+/// For additional documentation, see <a href="https://algs4.cs.princeton.edu/15uf">Section
+/// 1.5</a> of <i>Algorithms, 4th Edition</i> by Robert Sedgewick and Kevin Wayne.
+#[derive(Clone, Debug)]
+pub struct UF {
+    parent: Vec<usize>, // parent[i] = parent of i
+    size: Vec<usize>,   // size[i] = number of elements in subtree rooted at i
+    count: usize,       // number of components
+}
+
+impl UF {
+    /// Initializes an empty union-find data structure with `n` elements `0` through `n - 1`.
+    /// Initially, each element is in its own set.
+    pub fn new(n: usize) -> Self {
+        UF {
+            parent: (0..n).collect(),
+            size: vec![1; n],
+            count: n,
+        }
+    }
+
+    /// Returns the number of sets (between `1` and `n`, or `0` if `n` is `0`).
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the canonical element of the set containing element `p`.
+    pub fn find(&mut self, p: usize) -> Result<usize, InvalidArgument> {
+        self.validate(p)?;
+        let mut root = p;
+        while root != self.parent[root] {
+            root = self.parent[root];
+        }
+        // path compression: link every element on the path to the root
+        let mut p = p;
+        while p != root {
+            let next = self.parent[p];
+            self.parent[p] = root;
+            p = next;
+        }
+        Ok(root)
+    }
+
+    /// Returns true if the two elements are in the same set.
+    pub fn connected(&mut self, p: usize, q: usize) -> Result<bool, InvalidArgument> {
+        Ok(self.find(p)? == self.find(q)?)
+    }
+
+    /// Merges the set containing element `p` with the set containing element `q`.
+    pub fn union(&mut self, p: usize, q: usize) -> Result<(), InvalidArgument> {
+        let root_p = self.find(p)?;
+        let root_q = self.find(q)?;
+        if root_p == root_q {
+            return Ok(());
+        }
+
+        // make smaller root point to larger one
+        if self.size[root_p] < self.size[root_q] {
+            self.parent[root_p] = root_q;
+            self.size[root_q] += self.size[root_p];
+        } else {
+            self.parent[root_q] = root_p;
+            self.size[root_p] += self.size[root_q];
+        }
+        self.count -= 1;
+        Ok(())
+    }
+
+    // validate that p is a valid index
+    fn validate(&self, p: usize) -> Result<(), InvalidArgument> {
+        let n = self.parent.len();
+        if p >= n {
+            Err(InvalidArgument(format!(
+                "index {} is not between 0 and {}",
+                p,
+                n as i64 - 1
+            )))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_uf_tiny() {
+        // tinyUF.txt
+        let pairs = [
+            (4, 3),
+            (3, 8),
+            (6, 5),
+            (9, 4),
+            (2, 1),
+            (8, 9),
+            (5, 0),
+            (7, 2),
+            (6, 1),
+            (1, 0),
+            (6, 7),
+        ];
+        let mut uf = UF::new(10);
+        assert_eq!(uf.count(), 10);
+        let mut unions = Vec::new();
+        for (p, q) in pairs {
+            if uf.connected(p, q).unwrap() {
+                continue;
+            }
+            uf.union(p, q).unwrap();
+            unions.push((p, q));
+        }
+        assert_eq!(uf.count(), 2);
+        assert_eq!(unions.len(), 8);
+        assert!(!unions.contains(&(8, 9)));
+        assert!(!unions.contains(&(1, 0)));
+        assert!(!unions.contains(&(6, 7)));
+
+        for (p, q) in [(3, 9), (4, 8), (0, 7), (1, 6), (2, 5)] {
+            assert!(uf.connected(p, q).unwrap());
+        }
+        assert!(!uf.connected(0, 3).unwrap());
+        assert_eq!(uf.find(3).unwrap(), uf.find(9).unwrap());
+
+        // union of elements in the same set changes nothing
+        uf.union(3, 9).unwrap();
+        assert_eq!(uf.count(), 2);
+        uf.union(0, 3).unwrap();
+        assert_eq!(uf.count(), 1);
+    }
+
+    #[test]
+    fn test_uf_invalid_index() {
+        let mut uf = UF::new(3);
+        assert!(uf.find(3).is_err());
+        assert!(uf.union(0, 3).is_err());
+        assert!(uf.connected(3, 0).is_err());
+        assert_eq!(uf.count(), 3);
+        assert!(UF::new(0).find(0).is_err());
+    }
+
+    #[test]
+    fn test_uf_long_chain() {
+        // chains of unions stay shallow thanks to weighting and path compression
+        let n = 1_000_000;
+        let mut uf = UF::new(n);
+        for i in 1..n {
+            uf.union(i - 1, i).unwrap();
+        }
+        assert_eq!(uf.count(), 1);
+        for i in (0..n).step_by(1000) {
+            assert!(uf.connected(0, i).unwrap());
+        }
+        let root = uf.find(n - 1).unwrap();
+        assert_eq!(uf.parent[n - 1], root);
+    }
+}
@@ -2,8 +2,10 @@ use raw_vec::{RawValIter, RawVec};
 use std::fmt;
 use std::marker::PhantomData;
 use std::mem;
+use std::mem::ManuallyDrop;
 use std::ops::{Deref, DerefMut};
 use std::ptr;
+use std::ptr::NonNull;
 
 pub(crate) mod raw_vec;
 
@@ -51,6 +53,32 @@ impl<T> SVec<T> {
         self.buf.cap
     }
 
+    /// Returns the number of elements this `SVec` can hold without reallocating.
+    ///
+    /// For `SVec<T>` where T is a zero-sized type, it is always `usize::MAX`.
+    pub fn capacity(&self) -> usize {
+        self.cap()
+    }
+
+    /// Reserves capacity for at least `additional` more elements, which takes at most one
+    /// allocation.  The new capacity is at least double of the old one, so that repeated calls of
+    /// `reserve` take amortized constant time per element.  Does nothing if the capacity is
+    /// already sufficient.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX` *bytes*.
+    pub fn reserve(&mut self, additional: usize) {
+        let required = self.len.checked_add(additional).expect("capacity overflow");
+        self.buf.grow_amortized(required);
+    }
+
+    /// Shrinks the capacity to the length, which takes at most one reallocation.  If the vector is
+    /// empty, the buffer is deallocated.
+    pub fn shrink_to_fit(&mut self) {
+        self.buf.shrink_to(self.len);
+    }
+
     /// Appends an element to the back of a collection.  The value of variable `elem` is moved
     /// into this `SVec` so that this `SVec` owns it.
     ///
@@ -74,6 +102,28 @@ impl<T> SVec<T> {
         self.len += 1;
     }
 
+    /// Moves all the elements of `other` to the back of this `SVec`, leaving `other` empty.  The
+    /// elements are moved in bulk, without being cloned or dropped, after reserving the capacity
+    /// once.  The capacity of `other` is not changed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX` *bytes*.
+    ///
+    /// # Time complexity
+    ///
+    /// Takes *O*(*n* + *m*) time in the worst case, and amortized *O*(*m*) time, where *n* is the
+    /// length of this `SVec` and *m* is the length of `other`.
+    pub fn append(&mut self, other: &mut SVec<T>) {
+        let count = other.len;
+        self.reserve(count);
+        unsafe {
+            ptr::copy_nonoverlapping(other.ptr(), self.ptr().add(self.len), count);
+        }
+        other.len = 0;
+        self.len += count;
+    }
+
     /// Removes and returns the element most recently added to this `SVec`, or `None` if this `SVec`
     /// is empty.
     ///
@@ -151,6 +201,232 @@ impl<T> SVec<T> {
         }
     }
 
+    /// Shortens the vector, keeping the first `len` elements and dropping the rest.  It has no
+    /// effect if `len` is greater than or equal to the vector's current length.  The capacity is
+    /// not changed.
+    ///
+    /// # Time complexity
+    ///
+    /// Takes *O*(*n* &minus; `len`) time to drop the elements.
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.len {
+            return;
+        }
+        unsafe {
+            let tail = ptr::slice_from_raw_parts_mut(self.ptr().add(len), self.len - len);
+            // Shorten first, so that the tail is not dropped again if a destructor panics.
+            self.len = len;
+            ptr::drop_in_place(tail);
+        }
+    }
+
+    /// Resizes the vector so that its length is `new_len`.  If `new_len` is greater than the
+    /// length, the vector is extended with clones of `value` (the last one is `value` itself);
+    /// otherwise, the vector is truncated, dropping the tail.
+    ///
+    /// The slice methods `get` and `get_mut` are the checked alternatives of indexing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX` *bytes*.
+    pub fn resize(&mut self, new_len: usize, value: T)
+    where
+        T: Clone,
+    {
+        if new_len <= self.len {
+            self.truncate(new_len);
+            return;
+        }
+        self.reserve(new_len - self.len);
+        while self.len + 1 < new_len {
+            self.push(value.clone());
+        }
+        self.push(value);
+    }
+
+    /// Removes all the elements, which is equivalent to `truncate(0)`.  The capacity is not
+    /// changed.
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// Retains only the elements for which `f` returns `true`, removing and dropping the other
+    /// elements in place, and preserving the order of the retained elements.  The capacity is not
+    /// changed.
+    ///
+    /// If `f` panics, the element being tested and the elements after it are retained, and the
+    /// removed elements have been dropped exactly once.
+    ///
+    /// # Time complexity
+    ///
+    /// Takes &Theta;(*n*) time, and calls `f` exactly once for each element in the original order.
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        // The elements in [0, processed - deleted) are retained, the elements in
+        // [processed - deleted, processed) are dropped or moved, and the elements in
+        // [processed, original_len) are not tested yet.  If `f` panics, the guard moves the
+        // untested elements to fill the hole, and fixes the length.
+        struct Guard<'a, T> {
+            v: &'a mut SVec<T>,
+            processed: usize,
+            deleted: usize,
+            original_len: usize,
+        }
+
+        impl<T> Drop for Guard<'_, T> {
+            fn drop(&mut self) {
+                if self.deleted > 0 {
+                    unsafe {
+                        ptr::copy(
+                            self.v.ptr().add(self.processed),
+                            self.v.ptr().add(self.processed - self.deleted),
+                            self.original_len - self.processed,
+                        );
+                    }
+                }
+                self.v.len = self.original_len - self.deleted;
+            }
+        }
+
+        let original_len = self.len;
+        // If the guard is forgotten, the elements are leaked, but not dropped twice.
+        self.len = 0;
+        let mut g = Guard {
+            v: self,
+            processed: 0,
+            deleted: 0,
+            original_len,
+        };
+        while g.processed < original_len {
+            let cur = unsafe { g.v.ptr().add(g.processed) };
+            if !f(unsafe { &*cur }) {
+                // Advance first in case `drop_in_place` panics.
+                g.processed += 1;
+                g.deleted += 1;
+                unsafe { ptr::drop_in_place(cur) };
+            } else {
+                if g.deleted > 0 {
+                    unsafe {
+                        let hole = g.v.ptr().add(g.processed - g.deleted);
+                        ptr::copy_nonoverlapping(cur, hole, 1);
+                    }
+                }
+                g.processed += 1;
+            }
+        }
+    }
+
+    /// Removes and returns the element at position `index` within the vector, replacing it with the
+    /// last element, so the order of the elements is not preserved.
+    ///
+    /// Like `pop`, it halves the capacity when the vector becomes one-quarter full.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    ///
+    /// # Time complexity
+    ///
+    /// Takes amortized *O*(1) time.
+    pub fn swap_remove(&mut self, index: usize) -> T {
+        assert!(index < self.len, "index out of bounds");
+        let last = self.len - 1;
+        self.swap(index, last);
+        self.pop().expect("the vector should not be empty")
+    }
+
+    /// Removes the consecutive repeated elements, keeping the first one of each run of equal
+    /// elements.  If the vector is sorted, this removes all duplicates.  The capacity is not
+    /// changed.
+    ///
+    /// # Time complexity
+    ///
+    /// Takes &Theta;(*n*) time.
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.dedup_by(|a, b| a == b)
+    }
+
+    /// Removes the consecutive elements that resolve to the same key, keeping the first one of
+    /// each run.
+    pub fn dedup_by_key<K, F>(&mut self, mut key: F)
+    where
+        F: FnMut(&mut T) -> K,
+        K: PartialEq,
+    {
+        self.dedup_by(|a, b| key(a) == key(b))
+    }
+
+    /// Removes the consecutive elements for which `same_bucket(current, previous)` returns `true`,
+    /// where `previous` is the last retained element before `current`.  The removed elements are
+    /// dropped exactly once.
+    ///
+    /// If `same_bucket` panics, the element being tested and the elements after it are retained.
+    pub fn dedup_by<F>(&mut self, mut same_bucket: F)
+    where
+        F: FnMut(&mut T, &mut T) -> bool,
+    {
+        // The elements in [0, write) are retained, the elements in [write, read) are dropped or
+        // moved, and the elements in [read, original_len) are not tested yet.  If `same_bucket`
+        // panics, the guard moves the untested elements to fill the hole, and fixes the length.
+        struct Guard<'a, T> {
+            v: &'a mut SVec<T>,
+            read: usize,
+            write: usize,
+            original_len: usize,
+        }
+
+        impl<T> Drop for Guard<'_, T> {
+            fn drop(&mut self) {
+                let remaining = self.original_len - self.read;
+                if self.read > self.write {
+                    unsafe {
+                        ptr::copy(
+                            self.v.ptr().add(self.read),
+                            self.v.ptr().add(self.write),
+                            remaining,
+                        );
+                    }
+                }
+                self.v.len = self.write + remaining;
+            }
+        }
+
+        let original_len = self.len;
+        if original_len <= 1 {
+            return;
+        }
+        // If the guard is forgotten, the elements are leaked, but not dropped twice.
+        self.len = 0;
+        let mut g = Guard {
+            v: self,
+            read: 1,
+            write: 1,
+            original_len,
+        };
+        while g.read < original_len {
+            unsafe {
+                let cur = g.v.ptr().add(g.read);
+                let prev = g.v.ptr().add(g.write - 1);
+                if same_bucket(&mut *cur, &mut *prev) {
+                    // Advance first in case `drop_in_place` panics.
+                    g.read += 1;
+                    ptr::drop_in_place(cur);
+                } else {
+                    if g.read > g.write {
+                        ptr::copy_nonoverlapping(cur, g.v.ptr().add(g.write), 1);
+                    }
+                    g.write += 1;
+                    g.read += 1;
+                }
+            }
+        }
+    }
+
     /// Removes the whole slice of the whole vector, returning a double-ended iterator over the
     /// removed slice.
     ///
@@ -179,6 +455,24 @@ impl<T> SVec<T> {
     }
 }
 
+impl<T: Copy> SVec<T> {
+    /// Create an `SVec` by copying all the elements of the slice `s`.
+    ///
+    /// It takes only one allocation, and copies the elements in bulk.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX` *bytes*.
+    pub fn from_slice(s: &[T]) -> Self {
+        let mut v = SVec::with_capacity(s.len());
+        unsafe {
+            ptr::copy_nonoverlapping(s.as_ptr(), v.ptr(), s.len());
+        }
+        v.len = s.len();
+        v
+    }
+}
+
 impl<T> Drop for SVec<T> {
     fn drop(&mut self) {
         while self.pop().is_some() {}
@@ -273,14 +567,16 @@ impl<'a, T> Drop for Drain<'a, T> {
     }
 }
 
+/// The clone has the capacity of exactly `len` elements, which takes only one allocation.
 impl<T: Clone> Clone for SVec<T> {
     fn clone(&self) -> Self {
-        let mut v = SVec {
-            buf: self.buf.clone(),
-            len: 0,
-        };
-        for elem in self.iter() {
-            v.push(elem.clone());
+        let mut v: SVec<T> = SVec::with_capacity(self.len);
+        for (i, elem) in self.iter().enumerate() {
+            unsafe {
+                ptr::write(v.ptr().add(i), elem.clone());
+            }
+            // If `clone` panics, only the cloned elements are dropped.
+            v.len = i + 1;
         }
         v
     }
@@ -309,6 +605,98 @@ impl<T> std::iter::FromIterator<T> for SVec<T> {
     }
 }
 
+/// Pushes each item of the iterator, reserving the capacity for the lower bound of its size hint
+/// first.
+impl<T> Extend<T> for SVec<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        for x in iter {
+            self.push(x);
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a SVec<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut SVec<T> {
+    type Item = &'a mut T;
+    type IntoIter = std::slice::IterMut<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+// Implements `PartialEq<$rhs> for $lhs` by comparing them as slices, like what the internal macro
+// `__impl_slice_eq1` does for `std::vec::Vec`.
+macro_rules! impl_slice_eq {
+    ([$($vars:tt)*] $lhs:ty, $rhs:ty) => {
+        impl<T, U, $($vars)*> PartialEq<$rhs> for $lhs
+        where
+            T: PartialEq<U>,
+        {
+            fn eq(&self, other: &$rhs) -> bool {
+                self[..] == other[..]
+            }
+        }
+    };
+}
+
+impl_slice_eq! { [] SVec<T>, SVec<U> }
+impl_slice_eq! { [] SVec<T>, [U] }
+impl_slice_eq! { [] SVec<T>, &[U] }
+impl_slice_eq! { [] SVec<T>, &mut [U] }
+impl_slice_eq! { [] SVec<T>, Vec<U> }
+impl_slice_eq! { [const N: usize] SVec<T>, [U; N] }
+impl_slice_eq! { [const N: usize] SVec<T>, &[U; N] }
+impl_slice_eq! { [] [T], SVec<U> }
+impl_slice_eq! { [] &[T], SVec<U> }
+impl_slice_eq! { [] &mut [T], SVec<U> }
+impl_slice_eq! { [] Vec<T>, SVec<U> }
+impl_slice_eq! { [const N: usize] [T; N], SVec<U> }
+
+impl<T: Eq> Eq for SVec<T> {}
+
+/// Takes over the buffer of the `Vec` without copying the elements, which takes &Theta;(1) time.
+///
+/// It is sound because both `Vec` and `SVec` allocate the buffer of capacity `cap` by the global
+/// allocator with the layout `Layout::array::<T>(cap)`.
+impl<T> From<Vec<T>> for SVec<T> {
+    fn from(v: Vec<T>) -> Self {
+        let mut v = ManuallyDrop::new(v);
+        let cap = if mem::size_of::<T>() == 0 {
+            usize::MAX
+        } else {
+            v.capacity()
+        };
+        SVec {
+            buf: RawVec {
+                ptr: NonNull::new(v.as_mut_ptr()).expect("the pointer of a Vec is not null"),
+                cap,
+            },
+            len: v.len(),
+        }
+    }
+}
+
+/// Hands over the buffer of the `SVec` to a `Vec` without copying the elements, which takes
+/// &Theta;(1) time.
+///
+/// It is sound because both `Vec` and `SVec` allocate the buffer of capacity `cap` by the global
+/// allocator with the layout `Layout::array::<T>(cap)`.
+impl<T> From<SVec<T>> for Vec<T> {
+    fn from(v: SVec<T>) -> Self {
+        let v = ManuallyDrop::new(v);
+        unsafe { Vec::from_raw_parts(v.ptr(), v.len, v.cap()) }
+    }
+}
+
 impl<T> Default for SVec<T> {
     fn default() -> Self {
         SVec::new()
@@ -326,6 +714,7 @@ macro_rules! svec {
     );
     ($elem:expr; $n:expr) => ({
         let mut v = $crate::vec::SVec::new();
+        #[allow(clippy::reversed_empty_ranges)]
         for _i in 0..$n {
             v.push($elem.clone());
         }
@@ -383,7 +772,8 @@ mod tests {
     /// ```
     fn _doc_test() {}
 
-    #[derive(Debug, Eq, PartialEq)]
+    #[allow(clippy::upper_case_acronyms)]
+    #[derive(Clone, Debug, Eq, PartialEq)]
     struct ZST;
 
     impl std::fmt::Display for ZST {
@@ -428,6 +818,416 @@ mod tests {
         assert_eq!(u[1], v[1]);
         assert_eq!(u[2], v[2]);
         assert_eq!(u.len(), v.len());
+        assert_eq!(u.cap(), 3);
+        assert_eq!(v.cap(), 4);
+    }
+
+    #[test]
+    fn test_vec_clone_one_allocation() {
+        let mut v: SVec<i32> = SVec::new();
+        for i in 0..100_000 {
+            v.push(i);
+        }
+        assert_eq!(v.cap(), 131_072);
+        let u = v.clone();
+        assert_eq!(u.len(), 100_000);
+        assert_eq!(u.cap(), 100_000);
+        assert_eq!(u[..], v[..]);
+
+        let w = SVec::from_slice(&v);
+        assert_eq!(w.cap(), 100_000);
+        assert_eq!(w[..], v[..]);
+
+        let empty: SVec<String> = SVec::new();
+        let u = empty.clone();
+        assert_eq!(u.len(), 0);
+        assert_eq!(u.cap(), 0);
+        let w: SVec<i32> = SVec::from_slice(&[]);
+        assert_eq!(w.cap(), 0);
+
+        let mut v: SVec<ZST> = SVec::new();
+        v.push(ZST);
+        v.push(ZST);
+        let u = v.clone();
+        assert_eq!(u.len(), 2);
+        assert_eq!(u.cap(), usize::MAX);
+    }
+
+    #[test]
+    fn test_vec_with_capacity_and_reserve() {
+        let mut v: SVec<u64> = SVec::with_capacity(16);
+        assert_eq!(v.capacity(), 16);
+        let p = v.ptr();
+        for i in 0..16 {
+            v.push(i);
+        }
+        assert_eq!(v.capacity(), 16);
+        assert_eq!(v.ptr(), p);
+        v.push(16);
+        assert_eq!(v.capacity(), 32);
+
+        // enough capacity already
+        v.reserve(15);
+        assert_eq!(v.capacity(), 32);
+        // double the capacity
+        v.reserve(16);
+        assert_eq!(v.capacity(), 64);
+        // exactly the required capacity
+        v.reserve(200);
+        assert_eq!(v.capacity(), 217);
+        assert_eq!(v[..], (0..17).collect::<Vec<_>>()[..]);
+        let p = v.ptr();
+        for i in 17..217 {
+            v.push(i);
+        }
+        assert_eq!(v.ptr(), p);
+        assert_eq!(v.len(), 217);
+
+        let v: SVec<String> = SVec::with_capacity(0);
+        assert_eq!(v.capacity(), 0);
+        let mut v: SVec<String> = SVec::new();
+        v.reserve(0);
+        assert_eq!(v.capacity(), 0);
+        v.reserve(3);
+        assert_eq!(v.capacity(), 3);
+
+        let mut v: SVec<ZST> = SVec::with_capacity(16);
+        assert_eq!(v.capacity(), usize::MAX);
+        v.reserve(100);
+        assert_eq!(v.capacity(), usize::MAX);
+    }
+
+    #[test]
+    #[should_panic(expected = "capacity overflow")]
+    fn test_vec_reserve_overflow() {
+        let mut v: SVec<u8> = SVec::new();
+        v.push(1);
+        v.reserve(usize::MAX);
+    }
+
+    #[test]
+    fn test_vec_truncate_and_clear() {
+        let mut v: SVec<String> = SVec::new();
+        for s in ["alpha", "beta", "gamma", "delta"] {
+            v.push(s.to_string());
+        }
+        v.truncate(4);
+        assert_eq!(v.len(), 4);
+        v.truncate(1);
+        assert_eq!(v.len(), 1);
+        assert_eq!(v[..], ["alpha"]);
+        assert_eq!(v.cap(), 4);
+        v.push("epsilon".to_string());
+        assert_eq!(v[..], ["alpha", "epsilon"]);
+        v.clear();
+        assert!(v.is_empty());
+        assert_eq!(v.cap(), 4);
+
+        use std::rc::Rc;
+        let counter = Rc::new(());
+        let mut v: SVec<Rc<()>> = SVec::new();
+        for _ in 0..4 {
+            v.push(Rc::clone(&counter));
+        }
+        v.truncate(10);
+        assert_eq!(Rc::strong_count(&counter), 5);
+        v.truncate(1);
+        assert_eq!(Rc::strong_count(&counter), 2);
+        v.clear();
+        assert_eq!(Rc::strong_count(&counter), 1);
+        drop(v);
+        assert_eq!(Rc::strong_count(&counter), 1);
+    }
+
+    #[test]
+    fn test_vec_dedup() {
+        let mut v: SVec<i32> = svec![1, 1, 2, 3, 3, 3, 4];
+        v.dedup();
+        assert_eq!(v, [1, 2, 3, 4]);
+        assert_eq!(v.cap(), 8);
+
+        let mut v: SVec<i32> = svec![3, 1, 1, 3, 3];
+        v.dedup();
+        assert_eq!(v, [3, 1, 3]);
+
+        let mut v: SVec<i32> = SVec::new();
+        v.dedup();
+        assert!(v.is_empty());
+
+        let mut v: SVec<String> = ["a", "A", "b", "B", "b", "c"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        v.dedup_by_key(|s| s.to_lowercase());
+        assert_eq!(v, ["a", "b", "c"]);
+
+        // `previous` is the last retained element
+        let mut v: SVec<i32> = svec![10, 11, 12, 13, 20, 21];
+        v.dedup_by(|cur, prev| *cur - *prev < 3);
+        assert_eq!(v, [10, 13, 20]);
+
+        use std::rc::Rc;
+        let counter = Rc::new(());
+        let mut v: SVec<Rc<()>> = SVec::new();
+        for _ in 0..5 {
+            v.push(Rc::clone(&counter));
+        }
+        v.dedup_by(|_, _| true);
+        assert_eq!(v.len(), 1);
+        assert_eq!(Rc::strong_count(&counter), 2);
+    }
+
+    #[test]
+    fn test_vec_swap_remove() {
+        let mut v: SVec<String> = SVec::new();
+        for s in ["a", "b", "c", "d", "e"] {
+            v.push(s.to_string());
+        }
+        assert_eq!(v.swap_remove(1), "b");
+        assert_eq!(v[..], ["a", "e", "c", "d"]);
+        assert_eq!(v.swap_remove(3), "d");
+        assert_eq!(v[..], ["a", "e", "c"]);
+        assert_eq!(v.swap_remove(0), "a");
+        // shrinks like pop
+        assert_eq!(v[..], ["c", "e"]);
+        assert_eq!(v.cap(), 4);
+        assert_eq!(v.swap_remove(0), "c");
+        assert_eq!(v[..], ["e"]);
+        assert_eq!(v.cap(), 2);
+        assert_eq!(v.swap_remove(0), "e");
+        assert!(v.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn test_vec_swap_remove_out_of_bounds() {
+        let mut v: SVec<i32> = SVec::new();
+        v.push(1);
+        v.swap_remove(1);
+    }
+
+    #[test]
+    fn test_vec_resize_and_get() {
+        let mut v: SVec<i32> = svec![1, 2];
+        v.resize(5, 7);
+        assert_eq!(v, [1, 2, 7, 7, 7]);
+        assert_eq!(v.capacity(), 5);
+        v.resize(3, 0);
+        assert_eq!(v, [1, 2, 7]);
+        v.resize(3, 0);
+        assert_eq!(v, [1, 2, 7]);
+        v.resize(0, 0);
+        assert!(v.is_empty());
+
+        // a 2 x 3 grid
+        let (rows, cols) = (2, 3);
+        let mut grid: SVec<i32> = SVec::new();
+        grid.resize(rows * cols, 0);
+        grid[cols + 2] = 9;
+        assert_eq!(grid.get(cols + 2), Some(&9));
+        assert_eq!(grid.get(rows * cols), None);
+        if let Some(x) = grid.get_mut(1) {
+            *x = 4;
+        }
+        assert_eq!(grid, [0, 4, 0, 0, 0, 9]);
+        assert!(grid.get_mut(6).is_none());
+
+        use std::rc::Rc;
+        let counter = Rc::new(());
+        let mut v: SVec<Rc<()>> = SVec::new();
+        v.resize(4, Rc::clone(&counter));
+        assert_eq!(Rc::strong_count(&counter), 5);
+        v.resize(1, Rc::clone(&counter));
+        assert_eq!(Rc::strong_count(&counter), 2);
+    }
+
+    #[test]
+    fn test_vec_retain() {
+        let mut v: SVec<i32> = SVec::new();
+        for i in 0..10 {
+            v.push(i);
+        }
+        v.retain(|x| x % 2 == 0);
+        assert_eq!(v.len(), 5);
+        assert_eq!(v[..], [0, 2, 4, 6, 8]);
+        assert_eq!(v.cap(), 16);
+        v.retain(|_| true);
+        assert_eq!(v[..], [0, 2, 4, 6, 8]);
+        v.retain(|_| false);
+        assert!(v.is_empty());
+
+        let mut v: SVec<String> = svec!["a", "bb", "c", "dd", "ee", "f"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let mut tested = Vec::new();
+        v.retain(|s| {
+            tested.push(s.clone());
+            s.len() == 2
+        });
+        assert_eq!(tested, ["a", "bb", "c", "dd", "ee", "f"]);
+        assert_eq!(v[..], ["bb", "dd", "ee"]);
+    }
+
+    #[test]
+    fn test_vec_retain_panic() {
+        use std::panic::{AssertUnwindSafe, catch_unwind};
+        use std::rc::Rc;
+
+        let counter = Rc::new(());
+        let mut v: SVec<Rc<()>> = SVec::new();
+        for _ in 0..6 {
+            v.push(Rc::clone(&counter));
+        }
+        let mut i = 0;
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            v.retain(|_| {
+                i += 1;
+                if i == 4 {
+                    panic!("retain");
+                }
+                i != 2
+            })
+        }));
+        assert!(result.is_err());
+        // the second is removed, the fourth (being tested) and the rest are retained
+        assert_eq!(v.len(), 5);
+        assert_eq!(Rc::strong_count(&counter), 6);
+        drop(v);
+        assert_eq!(Rc::strong_count(&counter), 1);
+    }
+
+    #[test]
+    fn test_vec_shrink_to_fit() {
+        let mut v: SVec<usize> = SVec::new();
+        for i in 0..100 {
+            v.push(i);
+        }
+        assert_eq!(v.capacity(), 128);
+        v.shrink_to_fit();
+        assert_eq!(v.capacity(), 100);
+        for _ in 0..99 {
+            v.pop();
+        }
+        assert_eq!(v.capacity(), 3);
+        v.shrink_to_fit();
+        assert_eq!(v.capacity(), 1);
+        assert_eq!(v, [0]);
+        v.pop();
+        assert_eq!(v.capacity(), 0);
+        v.shrink_to_fit();
+        assert_eq!(v.capacity(), 0);
+
+        let mut v: SVec<String> = SVec::with_capacity(10);
+        v.shrink_to_fit();
+        assert_eq!(v.capacity(), 0);
+        v.push("a".to_string());
+        assert_eq!(v.capacity(), 1);
+        v.reserve(10);
+        v.shrink_to_fit();
+        assert_eq!(v.capacity(), 1);
+        assert_eq!(v, ["a"]);
+
+        let mut v: SVec<ZST> = svec![ZST; 3];
+        v.shrink_to_fit();
+        assert_eq!(v.capacity(), usize::MAX);
+    }
+
+    #[test]
+    fn test_vec_append() {
+        let mut a: SVec<String> = svec!["a".to_string(), "b".to_string()];
+        let mut b: SVec<String> = svec!["c".to_string(), "d".to_string(), "e".to_string()];
+        a.append(&mut b);
+        assert_eq!(a.len(), 5);
+        assert_eq!(b.len(), 0);
+        assert_eq!(a, ["a", "b", "c", "d", "e"]);
+        assert_eq!(b.capacity(), 4);
+
+        // `b` is still usable
+        b.push("f".to_string());
+        a.append(&mut b);
+        assert_eq!(a, ["a", "b", "c", "d", "e", "f"]);
+        assert!(b.is_empty());
+        a.append(&mut b);
+        assert_eq!(a.len(), 6);
+
+        use std::rc::Rc;
+        let counter = Rc::new(());
+        let mut a: SVec<Rc<()>> = SVec::new();
+        let mut b: SVec<Rc<()>> = svec![Rc::clone(&counter); 3];
+        a.append(&mut b);
+        assert_eq!(Rc::strong_count(&counter), 4);
+        drop(b);
+        assert_eq!(Rc::strong_count(&counter), 4);
+        drop(a);
+        assert_eq!(Rc::strong_count(&counter), 1);
+    }
+
+    #[test]
+    fn test_vec_grow_amortized() {
+        let mut v: SVec<u8> = SVec::new();
+        v.buf.grow_amortized(3);
+        assert_eq!(v.cap(), 3);
+        v.buf.grow_amortized(2);
+        assert_eq!(v.cap(), 3);
+        v.buf.grow_amortized(4);
+        assert_eq!(v.cap(), 6);
+        v.buf.grow_amortized(20);
+        assert_eq!(v.cap(), 20);
+        v.buf.grow();
+        assert_eq!(v.cap(), 40);
+    }
+
+    #[test]
+    fn test_vec_zst_many() {
+        use crate::queue::svecque::SVecQue;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        static DROPS: AtomicUsize = AtomicUsize::new(0);
+
+        #[derive(Clone)]
+        struct Token;
+
+        impl Drop for Token {
+            fn drop(&mut self) {
+                DROPS.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+
+        let mut v: SVec<Token> = SVec::new();
+        for _ in 0..10_000 {
+            v.push(Token);
+        }
+        assert_eq!(v.len(), 10_000);
+        assert_eq!(v.cap(), usize::MAX);
+        v.insert(5_000, Token);
+        assert_eq!(v.len(), 10_001);
+        assert_eq!(v.iter().count(), 10_001);
+
+        let u = v.clone();
+        assert_eq!(u.len(), 10_001);
+        assert_eq!(u.cap(), usize::MAX);
+        drop(u);
+        assert_eq!(DROPS.load(Ordering::Relaxed), 10_001);
+
+        for i in 0..6_001 {
+            assert!(v.pop().is_some(), "pop {}", i);
+        }
+        assert_eq!(v.len(), 4_000);
+        assert_eq!(v.cap(), usize::MAX);
+        assert_eq!(DROPS.load(Ordering::Relaxed), 16_002);
+
+        assert_eq!(v.into_iter().count(), 4_000);
+        assert_eq!(DROPS.load(Ordering::Relaxed), 20_002);
+
+        let mut q: SVecQue<ZST> = SVecQue::new();
+        for _ in 0..1_000 {
+            q.enqueue(ZST);
+        }
+        let mut r = q.clone();
+        assert_eq!(r.len(), 1_000);
+        while r.dequeue().is_some() {}
+        assert!(r.is_empty());
     }
 
     #[test]
@@ -472,15 +1272,14 @@ mod tests {
         let x: SVec<&str> = v.iter().cloned().collect();
 
         //----------------------------------------------------------------
-        // The following two lines do not compile.
-        // Because `assert_eq` macro does `match (&$left, &$right)` and `(*left_val == *right_val)`.
+        // `assert_eq` macro does `match (&$left, &$right)` and `(*left_val == *right_val)`.
         // So `==` is applied on the left `$lhs` and the right `$rhs`.
         //
-        // `std::vec::Vec` implements a lot of `PartialEq<$rhs> for $lhs` using an internal
-        // macro `__impl_slice_eq1` to achieve the ergonomic.
+        // Like `std::vec::Vec`, `SVec` implements a lot of `PartialEq<$rhs> for $lhs` using an
+        // internal macro `impl_slice_eq` to achieve the ergonomic.
         //----------------------------------------------------------------
-        // assert_eq!(&x, &["aaa", "bbb"]);  // &SVec<&str> == &[&str; 2]
-        // assert_eq!(x, ["aaa", "bbb"]);    // SVec<&str> == [&str; 2]
+        assert_eq!(&x, &["aaa", "bbb"]); // &SVec<&str> == &[&str; 2]
+        assert_eq!(x, ["aaa", "bbb"]); // SVec<&str> == [&str; 2]
 
         assert_eq!(x[..], ["aaa", "bbb"][..]); // [&str] == [&str]
 
@@ -493,6 +1292,92 @@ mod tests {
         assert_eq!(y, ["aaa", "bbb"],); // Vec<&str> == [&str; 2]
     }
 
+    #[test]
+    fn test_vec_extend_and_iterate_by_reference() {
+        let mut v: SVec<i32> = SVec::new();
+        v.push(-1);
+        v.extend(0..10);
+        assert_eq!(v.len(), 11);
+        assert_eq!(v.cap(), 11);
+        v.extend((10..13).filter(|x| x % 2 == 0));
+        assert_eq!(v.len(), 13);
+
+        let mut sum = 0;
+        for x in &v {
+            sum += x;
+        }
+        assert_eq!(sum, 66);
+        for x in &mut v {
+            *x *= 2;
+        }
+        assert_eq!(v[..], [-2, 0, 2, 4, 6, 8, 10, 12, 14, 16, 18, 20, 24]);
+        assert_eq!((&v).into_iter().next_back(), Some(&24));
+    }
+
+    #[test]
+    fn test_vec_partial_eq() {
+        let v: SVec<&str> = svec!["a", "b", "c"];
+        assert_eq!(v, ["a", "b", "c"]);
+        assert_eq!(["a", "b", "c"], v);
+        assert_eq!(v, &["a", "b", "c"]);
+        assert_eq!(v, vec!["a", "b", "c"]);
+        assert_eq!(vec!["a", "b", "c"], v);
+        assert_eq!(v, ["a", "b", "c"][..]);
+        assert_eq!(v, &["a", "b", "c"][..]);
+        assert_eq!(&["a", "b", "c"][..], v);
+        assert_ne!(v, ["a", "b"]);
+        assert_ne!(v, vec!["a", "b", "d"]);
+
+        // different element types
+        let s: SVec<String> = v.iter().map(|x| x.to_string()).collect();
+        assert_eq!(s, v);
+        assert_eq!(s, ["a", "b", "c"]);
+        let empty: SVec<String> = SVec::new();
+        assert_eq!(empty, SVec::<&str>::new());
+        assert_eq!(empty, Vec::<&str>::new());
+        assert_ne!(empty, s);
+    }
+
+    #[test]
+    fn test_vec_from_and_into_vec() {
+        let mut a: Vec<String> = Vec::with_capacity(10);
+        for s in ["x", "y", "z"] {
+            a.push(s.to_string());
+        }
+        let p = a.as_ptr();
+        let mut v = SVec::from(a);
+        assert_eq!(v, ["x", "y", "z"]);
+        assert_eq!(v.len(), 3);
+        assert_eq!(v.cap(), 10);
+        assert_eq!(v.ptr() as *const String, p);
+        v.push("w".to_string());
+
+        let b: Vec<String> = v.into();
+        assert_eq!(b, ["x", "y", "z", "w"]);
+        assert_eq!(b.len(), 4);
+        assert_eq!(b.capacity(), 10);
+        assert_eq!(b.as_ptr(), p);
+
+        // grow and shrink after the conversion
+        let mut v: SVec<i32> = (0..100).collect::<Vec<_>>().into();
+        v.extend(100..1000);
+        while v.len() > 1 {
+            v.pop();
+        }
+        let b = Vec::from(v);
+        assert_eq!(b, [0]);
+
+        let empty: SVec<i32> = Vec::new().into();
+        assert_eq!(empty.cap(), 0);
+        assert!(Vec::from(empty).is_empty());
+
+        let v: SVec<ZST> = vec![ZST, ZST].into();
+        assert_eq!(v.len(), 2);
+        assert_eq!(v.cap(), usize::MAX);
+        let b = Vec::from(v);
+        assert_eq!(b.len(), 2);
+    }
+
     #[test]
     fn test_vec_with_capacity() {
         let mut v: SVec<&str> = SVec::with_capacity(10);
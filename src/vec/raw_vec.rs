@@ -4,6 +4,11 @@ use std::mem;
 use std::ptr;
 use std::ptr::NonNull;
 
+/// The buffer of a vector, which manages the allocation, but not the elements.
+///
+/// For a zero-sized `T`, the capacity is always `usize::MAX`, and it never allocates, reallocates,
+/// or deallocates, and `ptr` is always dangling.  Reading and writing zero-sized values through a
+/// dangling pointer are fine.
 pub(crate) struct RawVec<T> {
     pub(crate) ptr: NonNull<T>,
     pub(crate) cap: usize,
@@ -29,31 +34,54 @@ impl<T> RawVec<T> {
     ///
     /// Panics if the new capacity exceeds `isize::MAX` *bytes*.
     pub(crate) fn with_capacity(capacity: usize) -> Self {
-        let cap = if mem::size_of::<T>() == 0 {
-            usize::MAX
-        } else {
-            capacity
-        };
+        if capacity == 0 || mem::size_of::<T>() == 0 {
+            return RawVec::new();
+        }
+        let cap = capacity;
         let layout = Layout::array::<T>(cap).unwrap();
         let ptr = unsafe { alloc::alloc(layout) };
         let non_null = match NonNull::new(ptr as *mut T) {
             Some(p) => p,
             None => alloc::handle_alloc_error(layout),
         };
-        RawVec {
-            ptr: non_null,
-            cap,
-        }
+        RawVec { ptr: non_null, cap }
     }
 
     /// Double the size.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX` *bytes*, or if `T` is zero-sized, because
+    /// its capacity `usize::MAX` is full.
     pub(crate) fn grow(&mut self) {
         // Since we set the capacity to usize::MAX when T has size 0,
         // getting to here necessarily means the Vec is overfull.
         assert!(mem::size_of::<T>() != 0, "capacity overflow");
 
+        self.grow_amortized(self.cap + 1);
+    }
+
+    /// Grows the capacity to hold at least `required` elements.
+    ///
+    /// The capacity policy: the new capacity is the larger one of `required` and double of the old
+    /// capacity, so that growing one element at a time takes amortized constant time.  If
+    /// `required` does not exceed the current capacity, nothing happens, which is always the case
+    /// when `T` is zero-sized.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX` *bytes*.
+    pub(crate) fn grow_amortized(&mut self, required: usize) {
+        if required <= self.cap {
+            return;
+        }
+
+        // Since we set the capacity to usize::MAX when T has size 0,
+        // getting to here necessarily means the Vec is overfull.
+        assert!(mem::size_of::<T>() != 0, "capacity overflow");
+
         // This can't overflow because we ensure self.cap <= isize::MAX.
-        let new_cap = if self.cap == 0 { 1 } else { 2 * self.cap };
+        let new_cap = required.max(2 * self.cap);
 
         // Layout::array checks that the number of byte is <= usize::MAX,
         // but this is redundant since old_layout.size() <= isize::MAX,
@@ -82,13 +110,18 @@ impl<T> RawVec<T> {
         self.cap = new_cap;
     }
 
-    /// Half the size.
+    /// Half the size.  It is a no-op if `T` is zero-sized.
     pub(crate) fn shrink(&mut self) {
-        if mem::size_of::<T>() == 0 {
+        self.shrink_to(self.cap / 2);
+    }
+
+    /// Shrinks the capacity to `new_cap`, deallocating the buffer if `new_cap` is zero.  It is a
+    /// no-op if `new_cap` is not less than the current capacity, or `T` is zero-sized.
+    pub(crate) fn shrink_to(&mut self, new_cap: usize) {
+        if mem::size_of::<T>() == 0 || new_cap >= self.cap {
             return;
         }
 
-        let new_cap = self.cap / 2;
         if new_cap == 0 {
             let layout = Layout::array::<T>(self.cap).unwrap();
             unsafe {
@@ -196,7 +229,7 @@ impl<T> DoubleEndedIterator for RawValIter<T> {
 
 impl<T> Clone for RawVec<T> {
     fn clone(&self) -> Self {
-        if self.cap == 0 {
+        if self.cap == 0 || mem::size_of::<T>() == 0 {
             RawVec {
                 ptr: NonNull::dangling(),
                 cap: self.cap,
@@ -0,0 +1,44 @@
+//! A small seeded pseudo-random number generator, to stay free of dependencies.
+
+use std::cell::Cell;
+
+// xorshift64* pseudo-random number generator, which is fast but not cryptographically secure.
+// The state lives in a `Cell` so that random numbers can be drawn through a shared reference.
+pub(crate) struct XorShift {
+    state: Cell<u64>,
+}
+
+impl XorShift {
+    pub(crate) fn new(seed: u64) -> Self {
+        // the state must never be zero
+        let state = if seed == 0 {
+            0x9E37_79B9_7F4A_7C15
+        } else {
+            seed
+        };
+        XorShift {
+            state: Cell::new(state),
+        }
+    }
+
+    pub(crate) fn next_u64(&self) -> u64 {
+        let mut x = self.state.get();
+        x ^= x >> 12;
+        x ^= x << 25;
+        x ^= x >> 27;
+        self.state.set(x);
+        x.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    // Returns a random integer uniformly in [0, n), `n` must be positive.
+    pub(crate) fn uniform(&self, n: usize) -> usize {
+        // Lemire's multiply-shift reduction, whose bias is negligible for a 64-bit source
+        ((self.next_u64() as u128 * n as u128) >> 64) as usize
+    }
+
+    // Returns a random real number uniformly in [0, 1).
+    pub(crate) fn uniform_f64(&self) -> f64 {
+        // the 53 high bits fill the significand of an f64
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}